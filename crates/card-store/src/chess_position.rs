@@ -1,2 +1,3 @@
 //! Compatibility re-exports for chess position types.
-// ChessPosition is no longer available in review_domain. This module is deprecated.
+
+pub use review_domain::{ChessPosition, Position, PositionError};
@@ -1,7 +1,15 @@
 //! Configuration for card-store implementations.
 
+use std::num::NonZeroU8;
+
+use chrono::NaiveDate;
+use review_domain::{DEFAULT_INITIAL_EASE_FACTOR, EaseBounds, NewCardDue, StoredCardState};
+use scheduler_core::SchedulerConfig;
+
+use crate::store::StoreError;
+
 /// Runtime configuration for a [`ReviewCardStore`](crate::store::ReviewCardStore) implementation.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct StorageConfig {
     /// Database connection string when using a SQL-backed store.
     pub dsn: Option<String>,
@@ -11,6 +19,66 @@ pub struct StorageConfig {
     pub batch_size: usize,
     /// How many times to retry transient failures.
     pub retry_attempts: u8,
+    /// Whether newly created cards are due on their creation date or the day after.
+    pub new_card_due: NewCardDue,
+    /// Interval, in days, that [`initial_state`](Self::initial_state) gives new cards.
+    pub initial_interval: NonZeroU8,
+    /// Ease factor that [`initial_state`](Self::initial_state) gives new cards. Defaults to
+    /// [`DEFAULT_INITIAL_EASE_FACTOR`], like the scheduler's `SchedulerConfig::initial_ease_factor`.
+    pub initial_ease_factor: f32,
+    /// Whether [`normalize_owner`](Self::normalize_owner) lowercases owner ids, so `"Andy"` and
+    /// `"andy"` address the same cards. Disable for case-sensitive deployments.
+    pub fold_owner_case: bool,
+    /// Floor and ceiling applied to the ease factor when the store grades a review.
+    /// [`from_scheduler`](Self::from_scheduler) takes it from the scheduler's configuration so
+    /// both review paths clamp the same way.
+    pub ease_bounds: EaseBounds,
+}
+
+impl StorageConfig {
+    /// Default storage settings whose review clamp is the scheduler's
+    /// [`ease_bounds`](SchedulerConfig::ease_bounds), so the store and the scheduler read
+    /// their bounds from one place.
+    #[must_use]
+    pub fn from_scheduler(scheduler: &SchedulerConfig) -> Self {
+        Self {
+            ease_bounds: scheduler.ease_bounds,
+            ..Self::default()
+        }
+    }
+
+    /// Canonical form of `owner_id`: surrounding whitespace is trimmed and, when
+    /// [`fold_owner_case`](Self::fold_owner_case) is set, the id is lowercased.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StoreError::InvalidOwner`] when the id is empty or only whitespace.
+    pub fn normalize_owner(&self, owner_id: &str) -> Result<String, StoreError> {
+        let trimmed = owner_id.trim();
+        if trimmed.is_empty() {
+            return Err(StoreError::InvalidOwner {
+                owner: owner_id.to_string(),
+            });
+        }
+        Ok(if self.fold_owner_case {
+            trimmed.to_lowercase()
+        } else {
+            trimmed.to_string()
+        })
+    }
+
+    /// Starting state for a card created on `today`: due that day, with the configured interval
+    /// and ease and no review history.
+    #[must_use]
+    pub fn initial_state(&self, today: NaiveDate) -> StoredCardState {
+        StoredCardState {
+            due_on: today,
+            interval: self.initial_interval,
+            ease_factor: self.initial_ease_factor,
+            consecutive_correct: 0,
+            last_reviewed_on: None,
+        }
+    }
 }
 
 impl Default for StorageConfig {
@@ -20,13 +88,18 @@ impl Default for StorageConfig {
             max_connections: 10,
             batch_size: 5_000,
             retry_attempts: 3,
+            new_card_due: NewCardDue::Today,
+            initial_interval: NonZeroU8::MIN,
+            initial_ease_factor: DEFAULT_INITIAL_EASE_FACTOR,
+            fold_owner_case: true,
+            ease_bounds: EaseBounds::default(),
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::StorageConfig;
+    use super::{EaseBounds, NaiveDate, NewCardDue, NonZeroU8, StorageConfig, StoreError};
 
     #[test]
     fn default_config() {
@@ -35,6 +108,11 @@ mod tests {
         assert_eq!(config.max_connections, 10);
         assert_eq!(config.batch_size, 5_000);
         assert_eq!(config.retry_attempts, 3);
+        assert_eq!(config.new_card_due, NewCardDue::Today);
+        assert_eq!(config.initial_interval, NonZeroU8::MIN);
+        assert!((config.initial_ease_factor - 2.5).abs() < f32::EPSILON);
+        assert!(config.fold_owner_case);
+        assert_eq!(config.ease_bounds, EaseBounds::default());
     }
 
     #[test]
@@ -44,6 +122,11 @@ mod tests {
             max_connections: 20,
             batch_size: 10_000,
             retry_attempts: 5,
+            new_card_due: NewCardDue::Tomorrow,
+            initial_interval: NonZeroU8::new(3).expect("non-zero"),
+            initial_ease_factor: 2.1,
+            fold_owner_case: false,
+            ease_bounds: EaseBounds::default(),
         };
         assert_eq!(
             config.dsn,
@@ -52,5 +135,44 @@ mod tests {
         assert_eq!(config.max_connections, 20);
         assert_eq!(config.batch_size, 10_000);
         assert_eq!(config.retry_attempts, 5);
+        assert_eq!(config.new_card_due, NewCardDue::Tomorrow);
+    }
+
+    #[test]
+    fn normalize_owner_trims_folds_case_and_rejects_blank_ids() {
+        let folding = StorageConfig::default();
+        let exact = StorageConfig {
+            fold_owner_case: false,
+            ..StorageConfig::default()
+        };
+
+        assert_eq!(folding.normalize_owner(" Andy "), Ok("andy".to_string()));
+        assert_eq!(exact.normalize_owner(" Andy "), Ok("Andy".to_string()));
+        for blank in ["", "   "] {
+            assert_eq!(
+                folding.normalize_owner(blank),
+                Err(StoreError::InvalidOwner {
+                    owner: blank.to_string()
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn initial_state_uses_configured_interval_and_ease() {
+        let config = StorageConfig {
+            initial_interval: NonZeroU8::new(3).expect("non-zero"),
+            initial_ease_factor: 2.1,
+            ..StorageConfig::default()
+        };
+        let today = NaiveDate::from_ymd_opt(2024, 5, 1).expect("valid date");
+
+        let state = config.initial_state(today);
+
+        assert_eq!(state.due_on, today);
+        assert_eq!(state.interval.get(), 3);
+        assert!((state.ease_factor - 2.1).abs() < f32::EPSILON);
+        assert_eq!(state.consecutive_correct, 0);
+        assert_eq!(state.last_reviewed_on, None);
     }
 }
@@ -11,6 +11,8 @@ pub mod errors;
 pub mod memory;
 /// Domain model types tailored to storage needs.
 pub mod model;
+/// Conversions between stored cards and scheduler cards.
+pub mod scheduling;
 /// Persistence trait definitions used by services.
 pub mod store;
 
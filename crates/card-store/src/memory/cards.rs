@@ -34,7 +34,7 @@ pub(super) fn collect_due_cards_for_owner(
 ) -> Vec<Card> {
     let mut result: Vec<Card> = cards
         .values()
-        .filter(|card| card.owner_id == owner_id && card.state.due_on <= as_of)
+        .filter(|card| card.owner_id == owner_id && card.state.is_due(as_of))
         .cloned()
         .collect();
     result.sort_by_key(|card| (card.state.due_on, card.id));
@@ -204,6 +204,7 @@ mod tests {
             card_id: card.id,
             reviewed_on: naive_date(2023, 1, 1),
             grade: 2,
+            idempotency_key: None,
         };
 
         let borrowed = borrow_card_for_review(&mut cards, &review).expect("card exists");
@@ -221,6 +222,7 @@ mod tests {
             card_id: 999,
             reviewed_on: naive_date(2023, 1, 1),
             grade: 2,
+            idempotency_key: None,
         };
         let err = borrow_card_for_review(&mut cards, &review).unwrap_err();
         assert!(matches!(err, StoreError::MissingCard { id } if id == 999));
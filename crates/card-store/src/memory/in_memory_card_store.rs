@@ -1,5 +1,5 @@
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeSet, HashMap, HashSet},
     sync::{RwLock, RwLockReadGuard, RwLockWriteGuard},
 };
 
@@ -11,12 +11,12 @@ use crate::{
     // chess_position::ChessPosition, // No longer available
     config::StorageConfig,
     memory::{
-        apply_review, borrow_card_for_review, collect_due_cards_for_owner, insert_unlock_or_error,
-        store_canonical_edge, store_opening_card,
+        apply_review_within, borrow_card_for_review, collect_due_cards_for_owner,
+        insert_unlock_or_error, store_canonical_edge, store_opening_card,
     },
     model::{
-        Card, Edge, EdgeInput, EdgeMap, ReviewRequest, StoredCardState, UnlockRecord, UnlockSet,
-        build_opening_card_id,
+        AppliedReviewKeys, Card, Edge, EdgeInput, EdgeMap, ReviewHistory, ReviewRequest,
+        StoredCardState, UnlockRecord, UnlockSet, build_opening_card_id,
     },
 };
 // fn upsert_position(&self, _position: ChessPosition) -> Result<ChessPosition, StoreError> {
@@ -26,10 +26,12 @@ use crate::{
 /// Thread-safe in-memory reference implementation of the storage trait.
 #[derive(Debug)]
 pub struct InMemoryCardStore {
-    _config: StorageConfig,
+    config: StorageConfig,
     edges: RwLock<EdgeMap>,
     cards: RwLock<HashMap<u64, Card>>,
     unlocks: RwLock<UnlockSet>,
+    history: RwLock<ReviewHistory>,
+    applied_keys: RwLock<AppliedReviewKeys>,
 }
 
 impl InMemoryCardStore {
@@ -37,10 +39,12 @@ impl InMemoryCardStore {
     #[must_use]
     pub fn new(config: StorageConfig) -> Self {
         Self {
-            _config: config,
+            config,
             edges: RwLock::new(HashMap::new()),
             cards: RwLock::new(HashMap::new()),
             unlocks: RwLock::new(HashSet::new()),
+            history: RwLock::new(HashMap::new()),
+            applied_keys: RwLock::new(HashMap::new()),
         }
     }
 
@@ -54,6 +58,51 @@ impl InMemoryCardStore {
         Ok(0) // positions are removed, returning 0
     }
 
+    /// Create or fetch an opening card whose initial state comes from
+    /// [`StorageConfig::initial_state`], so it is due `today` unless
+    /// [`StorageConfig::new_card_due`] defers it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`ReviewCardStore::create_opening_card`].
+    pub fn create_opening_card_default(
+        &self,
+        owner_id: &str,
+        edge: &Edge,
+        today: NaiveDate,
+    ) -> Result<Card, StoreError> {
+        self.create_opening_card(owner_id, edge, self.config.initial_state(today))
+    }
+
+    /// The card's `(reviewed_on, interval, ease_factor)` after each recorded review, oldest
+    /// first. Cards that were never reviewed, or are unknown, yield an empty series.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StoreError::PoisonedLock`] when the review history lock is poisoned.
+    pub fn card_timeseries(&self, card_id: u64) -> Result<Vec<(NaiveDate, u8, f32)>, StoreError> {
+        Ok(self
+            .history_read()?
+            .get(&card_id)
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    /// Removes every edge, card, unlock, recorded review, and applied idempotency key, leaving
+    /// the store as [`InMemoryCardStore::new`] built it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StoreError::PoisonedLock`] when any of the underlying locks is poisoned.
+    pub fn clear(&self) -> Result<(), StoreError> {
+        self.edges_write()?.clear();
+        self.cards_write()?.clear();
+        self.unlocks_write()?.clear();
+        self.history_write()?.clear();
+        self.applied_keys_write()?.clear();
+        Ok(())
+    }
+
     fn edges_read(&self) -> Result<RwLockReadGuard<'_, EdgeMap>, StoreError> {
         self.edges
             .read()
@@ -84,6 +133,26 @@ impl InMemoryCardStore {
         })
     }
 
+    fn history_read(&self) -> Result<RwLockReadGuard<'_, ReviewHistory>, StoreError> {
+        self.history.read().map_err(|_| StoreError::PoisonedLock {
+            resource: "history",
+        })
+    }
+
+    fn history_write(&self) -> Result<RwLockWriteGuard<'_, ReviewHistory>, StoreError> {
+        self.history.write().map_err(|_| StoreError::PoisonedLock {
+            resource: "history",
+        })
+    }
+
+    fn applied_keys_write(&self) -> Result<RwLockWriteGuard<'_, AppliedReviewKeys>, StoreError> {
+        self.applied_keys
+            .write()
+            .map_err(|_| StoreError::PoisonedLock {
+                resource: "applied_keys",
+            })
+    }
+
     fn ensure_edge_exists(&self, id: u64) -> Result<(), StoreError> {
         if !self.edges_read()?.contains_key(&id) {
             return Err(StoreError::MissingEdge { id });
@@ -111,25 +180,69 @@ impl ReviewCardStore for InMemoryCardStore {
         edge: &Edge,
         state: StoredCardState,
     ) -> Result<Card, StoreError> {
+        let owner_id = &self.config.normalize_owner(owner_id)?;
         self.ensure_edge_exists(edge.id)?;
+        let state = StoredCardState {
+            due_on: self.config.new_card_due.first_due(state.due_on),
+            ..state
+        };
         let card_id = build_opening_card_id(owner_id, edge.id);
         let mut cards = self.cards_write()?;
         store_opening_card(&mut cards, owner_id, edge, state, card_id)
     }
 
     fn fetch_due_cards(&self, owner_id: &str, as_of: NaiveDate) -> Result<Vec<Card>, StoreError> {
+        let owner_id = self.config.normalize_owner(owner_id)?;
         let cards = self.cards_read()?;
-        Ok(collect_due_cards_for_owner(&cards, owner_id, as_of))
+        Ok(collect_due_cards_for_owner(&cards, &owner_id, as_of))
+    }
+
+    fn get_card(&self, owner_id: &str, card_id: u64) -> Result<Option<Card>, StoreError> {
+        let owner_id = self.config.normalize_owner(owner_id)?;
+        Ok(self
+            .cards_read()?
+            .get(&card_id)
+            .filter(|card| card.owner_id == owner_id)
+            .cloned())
+    }
+
+    fn owners(&self) -> Result<Vec<String>, StoreError> {
+        let cards = self.cards_read()?;
+        let owners: BTreeSet<&str> = cards.values().map(|card| card.owner_id.as_str()).collect();
+        Ok(owners.into_iter().map(str::to_owned).collect())
     }
 
     fn record_review(&self, review: ReviewRequest) -> Result<Card, StoreError> {
+        // Hold the write guard across the lookup, update, and clone so concurrent reviews of
+        // the same card are applied one after another instead of overwriting each other.
         let mut cards = self.cards_write()?;
+        let mut history = self.history_write()?;
+        let mut applied_keys = self.applied_keys_write()?;
+        let applied_key = review
+            .idempotency_key
+            .clone()
+            .map(|key| (review.card_id, key));
+        if let Some(prior) = applied_key.as_ref().and_then(|key| applied_keys.get(key)) {
+            return Ok(prior.clone());
+        }
         let card = borrow_card_for_review(&mut cards, &review)?;
-        apply_review(&mut card.state, &review)?;
+        apply_review_within(&mut card.state, &review, self.config.ease_bounds)?;
+        history.entry(card.id).or_default().push((
+            review.reviewed_on,
+            card.state.interval.get(),
+            card.state.ease_factor,
+        ));
+        if let Some(key) = applied_key {
+            applied_keys.insert(key, card.clone());
+        }
         Ok(card.clone())
     }
 
     fn record_unlock(&self, unlock: UnlockRecord) -> Result<(), StoreError> {
+        let unlock = UnlockRecord {
+            owner_id: self.config.normalize_owner(&unlock.owner_id)?,
+            ..unlock
+        };
         let mut unlocks = self.unlocks_write()?;
         insert_unlock_or_error(&mut unlocks, &unlock)
     }
@@ -149,7 +262,204 @@ impl InMemoryCardStore {
         &self.unlocks
     }
 
+    pub(crate) fn history_lock(&self) -> &RwLock<ReviewHistory> {
+        &self.history
+    }
+
+    pub(crate) fn applied_keys_lock(&self) -> &RwLock<AppliedReviewKeys> {
+        &self.applied_keys
+    }
+
     pub(crate) fn ensure_edge_exists_for_test(&self, id: u64) -> Result<(), StoreError> {
         self.ensure_edge_exists(id)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::UnlockDetail;
+    use review_domain::EdgeId;
+    use std::num::NonZeroU8;
+    use std::panic::{AssertUnwindSafe, catch_unwind};
+
+    fn naive_date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).expect("valid date")
+    }
+
+    fn poison<T>(lock: &RwLock<T>) {
+        let _ = catch_unwind(AssertUnwindSafe(|| {
+            let _guard = lock.write().expect("lock is healthy");
+            panic!("poisoning {}", std::any::type_name::<T>());
+        }));
+        assert!(lock.is_poisoned());
+    }
+
+    fn sample_edge_input() -> EdgeInput {
+        EdgeInput {
+            parent_id: 1,
+            move_uci: "e2e4".into(),
+            move_san: "e4".into(),
+            child_id: 2,
+        }
+    }
+
+    fn sample_state() -> StoredCardState {
+        StoredCardState {
+            due_on: naive_date(2024, 1, 1),
+            interval: NonZeroU8::MIN,
+            ease_factor: 2.5,
+            consecutive_correct: 0,
+            last_reviewed_on: None,
+        }
+    }
+
+    #[test]
+    fn poisoned_card_lock_is_reported_by_card_operations() {
+        let store = InMemoryCardStore::new(StorageConfig::default());
+        let edge = store.upsert_edge(sample_edge_input()).expect("edge stored");
+        let card = store
+            .create_opening_card("learner", &edge, sample_state())
+            .expect("card created");
+        poison(store.cards_lock());
+
+        let poisoned = || StoreError::PoisonedLock { resource: "cards" };
+        assert_eq!(
+            store.create_opening_card("learner", &edge, sample_state()),
+            Err(poisoned())
+        );
+        assert_eq!(
+            store.fetch_due_cards("learner", naive_date(2024, 1, 1)),
+            Err(poisoned())
+        );
+        assert_eq!(store.get_card("learner", card.id), Err(poisoned()));
+        assert_eq!(
+            store.record_review(ReviewRequest {
+                card_id: card.id,
+                reviewed_on: naive_date(2024, 1, 1),
+                grade: 3,
+                idempotency_key: None,
+            }),
+            Err(poisoned())
+        );
+    }
+
+    #[test]
+    fn poisoned_edge_and_unlock_locks_are_reported() {
+        let store = InMemoryCardStore::new(StorageConfig::default());
+        poison(store.edges_lock());
+        assert_eq!(
+            store.upsert_edge(sample_edge_input()),
+            Err(StoreError::PoisonedLock { resource: "edges" })
+        );
+        assert_eq!(
+            store.ensure_edge_exists_for_test(7),
+            Err(StoreError::PoisonedLock { resource: "edges" })
+        );
+
+        poison(store.unlocks_lock());
+        assert_eq!(
+            store.record_unlock(UnlockRecord {
+                owner_id: "learner".into(),
+                detail: UnlockDetail::new(EdgeId::new(7)),
+                unlocked_on: naive_date(2024, 1, 1),
+            }),
+            Err(StoreError::PoisonedLock {
+                resource: "unlocks"
+            })
+        );
+    }
+
+    #[test]
+    fn poisoned_history_lock_is_reported() {
+        let store = InMemoryCardStore::new(StorageConfig::default());
+        let edge = store.upsert_edge(sample_edge_input()).expect("edge stored");
+        let card = store
+            .create_opening_card("learner", &edge, sample_state())
+            .expect("card created");
+        poison(store.history_lock());
+
+        let poisoned = || StoreError::PoisonedLock {
+            resource: "history",
+        };
+        assert_eq!(store.card_timeseries(card.id), Err(poisoned()));
+        assert_eq!(
+            store.record_review(ReviewRequest {
+                card_id: card.id,
+                reviewed_on: naive_date(2024, 1, 1),
+                grade: 3,
+                idempotency_key: None,
+            }),
+            Err(poisoned())
+        );
+        let stored = store
+            .get_card("learner", card.id)
+            .expect("cards lock healthy");
+        assert_eq!(stored.map(|card| card.state), Some(sample_state()));
+    }
+
+    #[test]
+    fn clear_forgets_applied_keys_and_reports_poisoned_lock() {
+        let store = InMemoryCardStore::new(StorageConfig::default());
+        let edge = store.upsert_edge(sample_edge_input()).expect("edge stored");
+        let card = store
+            .create_opening_card("learner", &edge, sample_state())
+            .expect("card created");
+        let keyed = ReviewRequest {
+            card_id: card.id,
+            reviewed_on: naive_date(2024, 1, 1),
+            grade: 3,
+            idempotency_key: Some("attempt-1".into()),
+        };
+        store.record_review(keyed.clone()).expect("review recorded");
+
+        store.clear().expect("locks healthy");
+        assert!(store.applied_keys_lock().read().unwrap().is_empty());
+        assert_eq!(store.card_timeseries(card.id), Ok(Vec::new()));
+        assert_eq!(store.get_card("learner", card.id), Ok(None));
+        assert_eq!(
+            store.record_review(keyed),
+            Err(StoreError::MissingCard { id: card.id })
+        );
+
+        poison(store.applied_keys_lock());
+        assert_eq!(
+            store.clear(),
+            Err(StoreError::PoisonedLock {
+                resource: "applied_keys"
+            })
+        );
+    }
+
+    #[test]
+    fn missing_edges_and_cards_are_rejected() {
+        let store = InMemoryCardStore::new(StorageConfig::default());
+        let edge = sample_edge_input().into_edge();
+        assert_eq!(
+            store.create_opening_card("learner", &edge, sample_state()),
+            Err(StoreError::MissingEdge { id: edge.id })
+        );
+        assert_eq!(
+            store.record_review(ReviewRequest {
+                card_id: 42,
+                reviewed_on: naive_date(2024, 1, 1),
+                grade: 3,
+                idempotency_key: None,
+            }),
+            Err(StoreError::MissingCard { id: 42 })
+        );
+    }
+
+    #[test]
+    fn get_card_returns_owned_cards_and_none_for_unknown_or_foreign_ids() {
+        let store = InMemoryCardStore::new(StorageConfig::default());
+        let edge = store.upsert_edge(sample_edge_input()).expect("edge stored");
+        let card = store
+            .create_opening_card("learner", &edge, sample_state())
+            .expect("card created");
+
+        assert_eq!(store.get_card("learner", card.id), Ok(Some(card.clone())));
+        assert_eq!(store.get_card("learner", card.id.wrapping_add(1)), Ok(None));
+        assert_eq!(store.get_card("intruder", card.id), Ok(None));
+    }
+}
@@ -1,25 +1,15 @@
 //! In-memory implementation of the [`ReviewCardStore`](crate::store::ReviewCardStore) trait organized by
 //! storage concern for readability.
 
-// mod tests {
-//     ...entire test module commented out due to broken legacy references...
-// }
-// start_position is not available. Test skipped or refactor needed.
-// store.upsert_position(position.clone()).unwrap();
-// let edge = store
-//     .upsert_edge(EdgeInput {
-//         parent_id: position.id,
-//         move_uci: "e2e4".into(),
-//         move_san: "e4".into(),
-//         child_id: position.id,
-//     })
-//     .unwrap();
-// store.upsert_position(position.clone()).unwrap();
-// let edge = store
-//     .upsert_edge(EdgeInput {
-//         parent_id: position.id,
-//         move_uci: "e2e4".into(),
-//         move_san: "e4".into(),
-//         child_id: position.id,
-//     })
-//     .unwrap();
+mod cards;
+mod edges;
+mod in_memory_card_store;
+pub mod reviews;
+mod unlocks;
+
+use cards::{borrow_card_for_review, collect_due_cards_for_owner, store_opening_card};
+use edges::store_canonical_edge;
+use reviews::apply_review_within;
+use unlocks::insert_unlock_or_error;
+
+pub use in_memory_card_store::InMemoryCardStore;
@@ -1,10 +1,12 @@
 use crate::model::{
-    CardStateBridgeError, ReviewRequest, Sm2Runtime, StoredCardState, StoredSnapshot,
-    hydrate_sm2_state, persist_sm2_state,
+    BridgeError, ReviewRequest, Sm2Runtime, StoredCardState, StoredSnapshot, hydrate_sm2_state,
+    persist_sm2_state,
 };
 use crate::store::StoreError;
-use review_domain::GradeError;
+use chrono::Days;
+use review_domain::{EaseBounds, Grade, GradeError, ReviewGrade};
 use scheduler_core::domain::Sm2State;
+use std::num::NonZeroU8;
 
 /// Applies a review to a stored card state, updating its interval, due date, and review history.
 ///
@@ -12,17 +14,54 @@ use scheduler_core::domain::Sm2State;
 /// This function is the core entry point for updating a card's spaced repetition state after a user review.
 /// It validates the grade, applies the review logic, and updates the state in-place.
 ///
-/// # Errors
-/// Returns a [`StoreError::InvalidGrade`] if the review grade is not valid.
+/// Correct grades (3 and 4) double the interval and extend the streak; any other grade resets the
+/// interval to one day and clears the streak. The ease factor follows
+/// [`review_domain::next_ease_factor`] for the matching [`ReviewGrade`] within the default
+/// [`EaseBounds`], and the card becomes due `interval` days after the review.
+/// The state is left untouched when an error is returned.
 ///
-// ...existing code...
+/// # Errors
+/// Returns a [`StoreError::InvalidGrade`] if the review grade is not valid, or
+/// [`StoreError::InvalidSchedulerState`] if the next due date cannot be represented.
 pub fn apply_review(state: &mut StoredCardState, review: &ReviewRequest) -> Result<(), StoreError> {
-    // The review logic is not implemented on StoredCardState directly. Use the aggregate or domain logic instead.
-    let _ = state;
-    let _ = review;
-    Err(StoreError::InvalidSchedulerState {
-        reason: "apply_review not implemented for StoredCardState".to_string(),
-    })
+    apply_review_within(state, review, EaseBounds::default())
+}
+
+/// Applies a review like [`apply_review`], clamping the ease factor to `bounds` instead of the
+/// defaults. [`InMemoryCardStore`](crate::memory::InMemoryCardStore) passes
+/// `StorageConfig::ease_bounds`.
+///
+/// # Errors
+/// Same as [`apply_review`].
+pub fn apply_review_within(
+    state: &mut StoredCardState,
+    review: &ReviewRequest,
+    bounds: EaseBounds,
+) -> Result<(), StoreError> {
+    let grade = Grade::from_u8(review.grade)?;
+    let (interval, consecutive_correct) = if grade.is_correct() {
+        (
+            state
+                .interval
+                .saturating_mul(NonZeroU8::MIN.saturating_add(1)),
+            state.consecutive_correct.saturating_add(1),
+        )
+    } else {
+        (NonZeroU8::MIN, 0)
+    };
+    let due_on = review
+        .reviewed_on
+        .checked_add_days(Days::new(u64::from(interval.get())))
+        .ok_or_else(|| StoreError::InvalidSchedulerState {
+            reason: format!("due date after {} is out of range", review.reviewed_on),
+        })?;
+
+    state.interval = interval;
+    state.consecutive_correct = consecutive_correct;
+    state.ease_factor = bounds.next_ease_factor(state.ease_factor, ReviewGrade::from(grade));
+    state.due_on = due_on;
+    state.last_reviewed_on = Some(review.reviewed_on);
+    Ok(())
 }
 
 /// Applies a review to a card and returns the updated SM2 state and snapshot.
@@ -40,12 +79,14 @@ pub fn apply_review(state: &mut StoredCardState, review: &ReviewRequest) -> Resu
 /// use card_store::model::{StoredCardState, ReviewRequest, Sm2Runtime};
 /// use chrono::NaiveDate;
 /// use std::num::NonZeroU8;
-/// let mut state = StoredCardState::new(
-///     NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
-///     NonZeroU8::new(1).unwrap(),
-///     2.5,
-/// );
-/// let review = ReviewRequest { card_id: 1, reviewed_on: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(), grade: 4 };
+/// let mut state = StoredCardState {
+///     due_on: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+///     interval: NonZeroU8::new(1).unwrap(),
+///     ease_factor: 2.5,
+///     consecutive_correct: 0,
+///     last_reviewed_on: None,
+/// };
+/// let review = ReviewRequest { card_id: 1, reviewed_on: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(), grade: 4, idempotency_key: None };
 /// let runtime = Sm2Runtime { stage: scheduler_core::domain::CardState::Review, lapses: 0, reviews: 0 };
 /// let (sm2, snapshot) = apply_review_and_hydrate(&mut state, &review, runtime).unwrap();
 /// assert_eq!(sm2.stage, scheduler_core::domain::CardState::Review);
@@ -71,23 +112,26 @@ pub fn apply_review_and_hydrate(
 /// It converts the SM2 state and snapshot into a `StoredCardState`, handling any conversion errors.
 ///
 /// # Errors
-/// Returns a [`StoreError::InvalidSchedulerState`] if the SM2 state cannot be converted (e.g., invalid interval).
+/// Returns a [`StoreError::SchedulerBridge`] if the SM2 state cannot be converted (e.g., invalid interval).
 ///
 /// # Examples
 /// ```
 /// use card_store::memory::reviews::persist_scheduler_update;
-/// use card_store::model::StoredCardState;
+/// use card_store::model::{StoredCardState, StoredSnapshot};
 /// use scheduler_core::domain::Sm2State;
 /// use chrono::NaiveDate;
 /// use std::num::NonZeroU8;
-/// let mut state = StoredCardState::new(
-///     NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
-///     NonZeroU8::new(1).unwrap(),
-///     2.5,
-/// );
+/// let mut state = StoredCardState {
+///     due_on: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+///     interval: NonZeroU8::new(1).unwrap(),
+///     ease_factor: 2.5,
+///     consecutive_correct: 0,
+///     last_reviewed_on: None,
+/// };
 /// let sm2 = Sm2State { stage: scheduler_core::domain::CardState::Review, ease_factor: 2.5, interval_days: 1, due: NaiveDate::from_ymd_opt(2025, 1, 2).unwrap(), lapses: 0, reviews: 1 };
-/// // let snapshot = state.to_snapshot(); // Not public API, so we skip this part in doctest.
-/// // persist_scheduler_update(&mut state, &sm2, snapshot).unwrap();
+/// let snapshot = StoredSnapshot { consecutive_correct: 1, last_reviewed_on: Some(sm2.due) };
+/// persist_scheduler_update(&mut state, &sm2, snapshot).unwrap();
+/// assert_eq!(state.due_on, sm2.due);
 /// ```
 pub fn persist_scheduler_update(
     state: &mut StoredCardState,
@@ -103,6 +147,7 @@ pub fn persist_scheduler_update(
 ///
 /// # Role
 /// This function adapts errors from the review domain (invalid or out-of-range grades) into the store's error type.
+/// It is equivalent to the `From<GradeError>` impl on [`StoreError`], which lets `?` do the same.
 ///
 /// # Examples
 /// ```
@@ -113,11 +158,7 @@ pub fn persist_scheduler_update(
 /// ```
 #[must_use]
 pub fn map_grade_error(error: GradeError) -> StoreError {
-    let inv_grade = match error {
-        GradeError::GradeOutsideRangeError { grade: inv_grade }
-        | GradeError::InvalidGradeError { grade: inv_grade } => inv_grade,
-    };
-    StoreError::InvalidGrade { grade: inv_grade }
+    StoreError::from(error)
 }
 
 /// Converts a bridge error from the state bridge module into a store error for unified error handling.
@@ -126,12 +167,16 @@ pub fn map_grade_error(error: GradeError) -> StoreError {
 /// This function adapts errors from the state bridge (such as hydration or conversion errors) into the store's error type.
 ///
 /// # Examples
-/// _No example available: `CardStateBridgeError` variants are not public in doctest context._
+/// ```
+/// use card_store::memory::reviews::map_bridge_error;
+/// use card_store::model::BridgeError;
+/// use card_store::store::StoreError;
+/// let err = map_bridge_error(&BridgeError::IntervalTooSmall);
+/// assert_eq!(err, StoreError::SchedulerBridge(BridgeError::IntervalTooSmall));
+/// ```
 #[must_use]
-pub fn map_bridge_error(error: &CardStateBridgeError) -> StoreError {
-    StoreError::InvalidSchedulerState {
-        reason: format!("{:?}", error),
-    }
+pub fn map_bridge_error(error: &BridgeError) -> StoreError {
+    StoreError::from(error.clone())
 }
 
 #[cfg(test)]
@@ -160,6 +205,7 @@ mod tests {
             card_id: 1,
             reviewed_on: naive_date(2023, 1, 5),
             grade,
+            idempotency_key: None,
         }
     }
 
@@ -239,10 +285,13 @@ mod tests {
         };
 
         let err = persist_scheduler_update(&mut state, &sm2, snapshot).unwrap_err();
-        assert!(matches!(err, StoreError::InvalidSchedulerState { .. }));
-        if let StoreError::InvalidSchedulerState { reason } = err {
-            assert!(reason.contains("interval"));
-        }
+        assert_eq!(
+            err,
+            StoreError::SchedulerBridge(BridgeError::IntervalOverflow {
+                interval_days: 500,
+                max: u8::MAX,
+            })
+        );
     }
 
     #[test]
@@ -262,11 +311,10 @@ mod tests {
             last_reviewed_on: Some(naive_date(2023, 1, 9)),
         };
         let err = persist_scheduler_update(&mut state, &sm2, snapshot).unwrap_err();
-        if let StoreError::InvalidSchedulerState { reason } = err {
-            assert!(reason.contains("IntervalTooSmall") || reason.contains("interval"));
-        } else {
-            panic!("Expected InvalidSchedulerState error");
-        }
+        assert_eq!(
+            err,
+            StoreError::SchedulerBridge(BridgeError::IntervalTooSmall)
+        );
     }
 
     #[test]
@@ -280,4 +328,19 @@ mod tests {
         let err = map_grade_error(GradeError::GradeOutsideRangeError { grade: 11 });
         assert_eq!(err, StoreError::InvalidGrade { grade: 11 });
     }
+
+    #[test]
+    fn grade_errors_convert_into_store_errors_like_map_grade_error() {
+        for error in [
+            GradeError::GradeOutsideRangeError { grade: 5 },
+            GradeError::InvalidGradeError { grade: 8 },
+            GradeError::UnrecognizedGradeError,
+        ] {
+            assert_eq!(StoreError::from(error), map_grade_error(error));
+        }
+        assert_eq!(
+            StoreError::from(GradeError::UnrecognizedGradeError),
+            StoreError::UnrecognizedGrade
+        );
+    }
 }
@@ -2,6 +2,8 @@
 
 use std::collections::{HashMap, HashSet};
 
+use chrono::NaiveDate;
+
 // Use canonical card types from review-domain
 pub use review_domain::{
     CardKind as GenericCardKind, EdgeInput, OpeningCard, OpeningEdge, ReviewRequest,
@@ -16,7 +18,7 @@ pub type Card = review_domain::Card<u64, String, CardKind, StoredCardState>;
 pub type UnlockRecord = review_domain::UnlockRecord<String, UnlockDetail>;
 
 pub use scheduler_core::domain::{
-    CardStateBridgeError, Sm2Runtime, StoredSnapshot, hydrate_sm2_state, persist_sm2_state,
+    BridgeError, Sm2Runtime, StoredSnapshot, hydrate_sm2_state, persist_sm2_state,
 };
 
 use review_domain::hash_with_seed;
@@ -33,6 +35,13 @@ pub type EdgeMap = HashMap<u64, Edge>;
 /// Set of unlock records.
 pub type UnlockSet = HashSet<UnlockRecord>;
 
+/// Per-card `(reviewed_on, interval, ease_factor)` entries captured after each review, oldest
+/// first.
+pub type ReviewHistory = HashMap<u64, Vec<(NaiveDate, u8, f32)>>;
+
+/// Idempotency keys already applied, keyed by `(card_id, key)`, with the card each produced.
+pub type AppliedReviewKeys = HashMap<(u64, String), Card>;
+
 /// Deterministically compute a card identifier for an opening edge.
 #[must_use]
 pub fn build_opening_card_id(owner_id: &str, edge_id: u64) -> u64 {
@@ -0,0 +1,310 @@
+//! Conversions between stored cards and the scheduler's card model.
+//!
+//! The two models do not carry the same information, so the conversion keeps the
+//! store-only fields next to the scheduler card in [`SchedulableCard`]:
+//!
+//! * Card ids are embedded in the low 64 bits of a [`Uuid`] (see [`card_id_to_uuid`]).
+//! * Owners that are not UUID strings are mapped to a deterministic UUID derived from
+//!   [`hash_with_seed`]; the original owner string is kept for the return trip.
+//! * Opening cards become [`SchedulerOpeningCard`]s whose `parent_prefix` is the decimal
+//!   `edge_id`, so prefix throttling treats every edge as its own line. Tactic ids have no
+//!   scheduler counterpart. Both are restored from the retained store kind.
+//! * `consecutive_correct` and `last_reviewed_on` are not tracked by the scheduler and are
+//!   carried in a [`StoredSnapshot`].
+//!
+//! [`ease_after_grade`] applies the scheduler's ease rule to a stored card so both crates clamp
+//! to the same [`SchedulerConfig`] bounds.
+
+use scheduler_core::SchedulerConfig;
+use scheduler_core::domain::{
+    Card as SchedulerCard, CardKind as SchedulerCardKind, CardState, SchedulerOpeningCard,
+    SchedulerTacticCard, Sm2WithSnapshot,
+};
+use uuid::Uuid;
+
+use crate::model::{
+    Card, CardKind, Sm2Runtime, StoredCardState, StoredSnapshot, hydrate_sm2_state,
+};
+use crate::store::StoreError;
+use review_domain::{ReviewGrade, hash_with_seed};
+
+/// Embeds a store card id into a scheduler [`Uuid`].
+#[must_use]
+pub fn card_id_to_uuid(id: u64) -> Uuid {
+    Uuid::from_u64_pair(0, id)
+}
+
+/// Recovers a store card id from a [`Uuid`] produced by [`card_id_to_uuid`].
+///
+/// Returns `None` when the high 64 bits are set, i.e. the id was not minted by the store.
+#[must_use]
+pub fn uuid_to_card_id(id: Uuid) -> Option<u64> {
+    match id.as_u64_pair() {
+        (0, low) => Some(low),
+        _ => None,
+    }
+}
+
+/// Maps a store owner string onto the scheduler's [`Uuid`] owner ids.
+#[must_use]
+pub fn owner_to_uuid(owner_id: &str) -> Uuid {
+    Uuid::parse_str(owner_id).unwrap_or_else(|_| Uuid::from_u64_pair(hash_with_seed(owner_id), 0))
+}
+
+/// Returns the ease factor a stored card will have after `grade`, using the scheduler's
+/// [`ease_bounds`](SchedulerConfig::ease_bounds).
+#[must_use]
+pub fn ease_after_grade(
+    state: &StoredCardState,
+    grade: ReviewGrade,
+    config: &SchedulerConfig,
+) -> f32 {
+    config
+        .ease_bounds
+        .next_ease_factor(state.ease_factor, grade)
+}
+
+/// A stored card converted for the scheduler, plus the fields the scheduler cannot hold.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchedulableCard {
+    /// Card in the scheduler's representation.
+    pub card: SchedulerCard,
+    /// Owner string used by the store.
+    pub owner_id: String,
+    /// Store kind carrying the edge or tactic id.
+    pub kind: CardKind,
+    /// Review history fields persisted only by the store.
+    pub snapshot: StoredSnapshot,
+}
+
+impl From<(Card, Sm2Runtime)> for SchedulableCard {
+    fn from((card, runtime): (Card, Sm2Runtime)) -> Self {
+        let snapshot = StoredSnapshot {
+            consecutive_correct: card.state.consecutive_correct,
+            last_reviewed_on: card.state.last_reviewed_on,
+        };
+        let scheduler_kind = match &card.kind {
+            CardKind::Opening(opening) => SchedulerCardKind::Opening(SchedulerOpeningCard::new(
+                opening.edge_id.get().to_string(),
+            )),
+            CardKind::Tactic(_) => SchedulerCardKind::Tactic(SchedulerTacticCard::new()),
+        };
+        Self {
+            card: SchedulerCard {
+                id: card_id_to_uuid(card.id),
+                owner_id: owner_to_uuid(&card.owner_id),
+                kind: scheduler_kind,
+                state: hydrate_sm2_state(card.state, runtime),
+            },
+            owner_id: card.owner_id,
+            kind: card.kind,
+            snapshot,
+        }
+    }
+}
+
+/// Converts a stored card, inferring the runtime counters the store does not persist.
+///
+/// Cards that were never reviewed start in [`CardState::New`]; all others are treated as
+/// [`CardState::Review`] with no lapses and `consecutive_correct` prior reviews. Use the
+/// `(Card, Sm2Runtime)` conversion when the exact counters are known.
+impl From<Card> for SchedulableCard {
+    fn from(card: Card) -> Self {
+        let runtime = Sm2Runtime {
+            stage: if card.state.last_reviewed_on.is_some() {
+                CardState::Review
+            } else {
+                CardState::New
+            },
+            lapses: 0,
+            reviews: card.state.consecutive_correct,
+        };
+        Self::from((card, runtime))
+    }
+}
+
+impl TryFrom<SchedulableCard> for Card {
+    type Error = StoreError;
+
+    /// Writes the scheduler's state back into the store representation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StoreError::InvalidSchedulerState`] when the scheduler card id was not
+    /// produced by [`card_id_to_uuid`], and [`StoreError::SchedulerBridge`] when its interval
+    /// cannot be stored (for example a zero-day interval on a card still in learning).
+    fn try_from(value: SchedulableCard) -> Result<Self, Self::Error> {
+        let SchedulableCard {
+            card,
+            owner_id,
+            kind,
+            snapshot,
+        } = value;
+        let id = uuid_to_card_id(card.id).ok_or_else(|| StoreError::InvalidSchedulerState {
+            reason: format!("card id {} does not map to a stored card", card.id),
+        })?;
+        let state = StoredCardState::try_from(Sm2WithSnapshot(&card.state, &snapshot))?;
+        Ok(Card {
+            id,
+            owner_id,
+            kind,
+            state,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{BridgeError, OpeningCard, TacticCard};
+    use chrono::NaiveDate;
+    use review_domain::{EaseBounds, EdgeId, ReviewGrade, TacticId};
+    use scheduler_core::{InMemoryStore, Scheduler, SchedulerConfig, SchedulerStore};
+    use std::num::NonZeroU8;
+
+    fn naive_date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).expect("valid date")
+    }
+
+    fn stored_opening() -> Card {
+        Card {
+            id: 0xDEAD_BEEF,
+            owner_id: "learner".into(),
+            kind: CardKind::Opening(OpeningCard::new(EdgeId::new(42))),
+            state: StoredCardState {
+                due_on: naive_date(2024, 3, 1),
+                interval: NonZeroU8::new(4).expect("non-zero"),
+                ease_factor: 2.3,
+                consecutive_correct: 2,
+                last_reviewed_on: Some(naive_date(2024, 2, 26)),
+            },
+        }
+    }
+
+    #[test]
+    fn id_helpers_round_trip_and_reject_foreign_uuids() {
+        assert_eq!(uuid_to_card_id(card_id_to_uuid(u64::MAX)), Some(u64::MAX));
+        assert_eq!(uuid_to_card_id(Uuid::from_u64_pair(1, 7)), None);
+        let owner = Uuid::from_u64_pair(3, 4);
+        assert_eq!(owner_to_uuid(&owner.to_string()), owner);
+        assert_eq!(owner_to_uuid("learner"), owner_to_uuid("learner"));
+        assert_ne!(owner_to_uuid("learner"), owner_to_uuid("other"));
+    }
+
+    #[test]
+    fn conversion_maps_kind_and_infers_runtime() {
+        let bridged = SchedulableCard::from(stored_opening());
+
+        assert_eq!(bridged.card.id, card_id_to_uuid(0xDEAD_BEEF));
+        assert_eq!(
+            bridged.card.kind,
+            SchedulerCardKind::Opening(SchedulerOpeningCard::new("42"))
+        );
+        assert_eq!(bridged.card.state.stage, CardState::Review);
+        assert_eq!(bridged.card.state.interval_days, 4);
+        assert_eq!(bridged.card.state.reviews, 2);
+
+        let mut fresh = stored_opening();
+        fresh.kind = CardKind::Tactic(TacticCard::new(TacticId::new(9)));
+        fresh.state.last_reviewed_on = None;
+        let bridged = SchedulableCard::from(fresh);
+        assert_eq!(bridged.card.state.stage, CardState::New);
+        assert_eq!(
+            bridged.kind,
+            CardKind::Tactic(TacticCard::new(TacticId::new(9)))
+        );
+    }
+
+    #[test]
+    fn round_trip_through_scheduler_preserves_identity_and_updates_schedule() {
+        let original = stored_opening();
+        let bridged = SchedulableCard::from(original.clone());
+        assert_eq!(
+            Card::try_from(bridged.clone()).expect("unchanged card converts back"),
+            original
+        );
+
+        let mut scheduler = Scheduler::new(
+            InMemoryStore::from_cards([bridged.card.clone()]),
+            SchedulerConfig::default(),
+        );
+        scheduler
+            .review(bridged.card.id, ReviewGrade::Good, naive_date(2024, 3, 1))
+            .expect("card is scheduled");
+        let reviewed = scheduler
+            .into_store()
+            .get_card(bridged.card.id)
+            .expect("card stored");
+        let written_back = Card::try_from(SchedulableCard {
+            card: reviewed.clone(),
+            ..bridged
+        })
+        .expect("reviewed card converts back");
+
+        assert_eq!(written_back.id, original.id);
+        assert_eq!(written_back.owner_id, original.owner_id);
+        assert_eq!(written_back.kind, original.kind);
+        assert_eq!(written_back.state.due_on, reviewed.state.due);
+        assert_eq!(
+            u32::from(written_back.state.interval.get()),
+            reviewed.state.interval_days
+        );
+        assert!(written_back.state.due_on > original.state.due_on);
+    }
+
+    #[test]
+    fn ease_after_grade_matches_the_scheduler_review_path() {
+        let narrow = SchedulerConfig {
+            ease_bounds: EaseBounds {
+                minimum: 1.9,
+                maximum: 2.4,
+            },
+            ..SchedulerConfig::default()
+        };
+        let grades = [
+            ReviewGrade::Again,
+            ReviewGrade::Hard,
+            ReviewGrade::Good,
+            ReviewGrade::Easy,
+        ];
+        for config in [SchedulerConfig::default(), narrow] {
+            for starting_ease in [1.3, 1.8, 2.5, 2.8] {
+                for grade in grades {
+                    let mut stored = stored_opening();
+                    stored.state.ease_factor = starting_ease;
+                    let expected = ease_after_grade(&stored.state, grade, &config);
+
+                    let bridged = SchedulableCard::from(stored);
+                    let mut scheduler = Scheduler::new(
+                        InMemoryStore::from_cards([bridged.card.clone()]),
+                        config.clone(),
+                    );
+                    let outcome = scheduler
+                        .review(bridged.card.id, grade, naive_date(2024, 3, 1))
+                        .expect("card is scheduled");
+
+                    assert_eq!(outcome.card.state.ease_factor.to_bits(), expected.to_bits());
+                    let bounds = config.ease_bounds;
+                    assert!((bounds.minimum..=bounds.maximum).contains(&expected));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn write_back_rejects_foreign_ids_and_unstorable_intervals() {
+        let mut bridged = SchedulableCard::from(stored_opening());
+        bridged.card.state.interval_days = 0;
+        assert_eq!(
+            Card::try_from(bridged.clone()),
+            Err(StoreError::SchedulerBridge(BridgeError::IntervalTooSmall))
+        );
+
+        bridged.card.state.interval_days = 1;
+        bridged.card.id = Uuid::from_u64_pair(1, 1);
+        assert!(matches!(
+            Card::try_from(bridged),
+            Err(StoreError::InvalidSchedulerState { reason }) if reason.contains("does not map")
+        ));
+    }
+}
@@ -8,8 +8,11 @@ use thiserror::Error;
 // use crate::chess_position::ChessPosition; // No longer available
 // use crate::chess_position::ChessPosition; // No longer available
 use crate::errors::PositionError;
-use crate::model::{Card, Edge, EdgeInput, ReviewRequest, StoredCardState, UnlockRecord};
+use crate::model::{
+    BridgeError, Card, Edge, EdgeInput, ReviewRequest, StoredCardState, UnlockRecord,
+};
 use review_domain::EdgeId;
+use review_domain::GradeError;
 
 /// Unified error type returned by [`ReviewCardStore`] implementations.
 #[derive(Debug, Error, PartialEq)]
@@ -26,6 +29,15 @@ pub enum StoreError {
     /// The provided grade was outside the supported range.
     #[error("invalid grade {grade}; expected 0-4")]
     InvalidGrade { grade: u8 },
+    /// The provided grade text was neither a digit nor a known grade word.
+    #[error("unrecognised grade; expected 0-4 or again/hard/good/easy")]
+    UnrecognizedGrade,
+    /// A confidence value could not be mapped to a grade.
+    #[error("invalid confidence; expected a value within 0.0-1.0")]
+    InvalidConfidence,
+    /// The owner id was empty or only whitespace.
+    #[error("invalid owner id {owner:?}; expected a non-blank identifier")]
+    InvalidOwner { owner: String },
     /// Unlock record already exists for the day.
     #[error("duplicate unlock for edge {edge} on {day}")]
     DuplicateUnlock { edge: EdgeId, day: NaiveDate },
@@ -36,11 +48,67 @@ pub enum StoreError {
     #[error("hash collision detected for {entity}")]
     HashCollision { entity: &'static str },
     /// Invalid position provided during an upsert operation.
+    ///
+    /// Converted from [`PositionError`], so position validation can use `?`:
+    ///
+    /// ```
+    /// use card_store::store::StoreError;
+    /// use review_domain::{Position, PositionError};
+    ///
+    /// fn flipped(fen: &str) -> Result<Position, StoreError> {
+    ///     Ok(Position::new(fen).flip()?)
+    /// }
+    ///
+    /// assert!(matches!(
+    ///     flipped("not a fen"),
+    ///     Err(StoreError::InvalidPosition(PositionError::MalformedFen))
+    /// ));
+    /// ```
     #[error(transparent)]
     InvalidPosition(#[from] PositionError),
     /// Scheduler state could not be persisted because the interval was invalid.
     #[error("scheduler state cannot be persisted: {reason}")]
     InvalidSchedulerState { reason: String },
+    /// Scheduler state was rejected by the state bridge.
+    ///
+    /// ```
+    /// use card_store::model::BridgeError;
+    /// use card_store::store::StoreError;
+    ///
+    /// let err = StoreError::from(BridgeError::IntervalTooSmall);
+    /// assert!(matches!(err, StoreError::SchedulerBridge(BridgeError::IntervalTooSmall)));
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "scheduler state cannot be persisted: interval must be at least one day"
+    /// );
+    /// ```
+    #[error("scheduler state cannot be persisted: {0}")]
+    SchedulerBridge(#[from] BridgeError),
+}
+
+/// Maps out-of-range grades to [`StoreError::InvalidGrade`], unparseable grade text to
+/// [`StoreError::UnrecognizedGrade`], and unusable confidences to
+/// [`StoreError::InvalidConfidence`].
+///
+/// ```
+/// use card_store::store::StoreError;
+/// use review_domain::Grade;
+///
+/// fn parse(grade: u8) -> Result<Grade, StoreError> {
+///     Ok(Grade::from_u8(grade)?)
+/// }
+///
+/// assert_eq!(parse(9), Err(StoreError::InvalidGrade { grade: 9 }));
+/// ```
+impl From<GradeError> for StoreError {
+    fn from(error: GradeError) -> Self {
+        match error {
+            GradeError::GradeOutsideRangeError { grade }
+            | GradeError::InvalidGradeError { grade } => Self::InvalidGrade { grade },
+            GradeError::UnrecognizedGradeError => Self::UnrecognizedGrade,
+            GradeError::InvalidConfidenceError => Self::InvalidConfidence,
+        }
+    }
 }
 
 /// Persistence abstraction used across review services.
@@ -69,6 +137,41 @@ pub trait ReviewCardStore: Send + Sync + fmt::Debug {
     ///
     /// Returns [`StoreError`] when the store cannot query the due cards.
     fn fetch_due_cards(&self, owner_id: &str, as_of: NaiveDate) -> Result<Vec<Card>, StoreError>;
+    /// Fetch one of `owner_id`'s cards by id, returning `Ok(None)` when the owner has no card
+    /// with that id, including when the id belongs to another owner.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StoreError`] when the store cannot be queried.
+    fn get_card(&self, owner_id: &str, card_id: u64) -> Result<Option<Card>, StoreError>;
+    /// Distinct (normalized) ids of every owner holding at least one card, sorted ascending.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StoreError`] when the store cannot be queried.
+    fn owners(&self) -> Result<Vec<String>, StoreError>;
+    /// Fetch an owner's cards whose due date lies in `start..=end`, sorted by due date and id.
+    ///
+    /// An empty range yields no cards. The default filters [`fetch_due_cards`](Self::fetch_due_cards)
+    /// as of `end`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StoreError`] when the store cannot query the due cards.
+    fn fetch_cards_due_between(
+        &self,
+        owner_id: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<Card>, StoreError> {
+        let mut cards: Vec<Card> = self
+            .fetch_due_cards(owner_id, end)?
+            .into_iter()
+            .filter(|card| card.state.due_on >= start)
+            .collect();
+        cards.sort_by_key(|card| (card.state.due_on, card.id));
+        Ok(cards)
+    }
     /// Record a review and return the updated card state.
     ///
     /// # Errors
@@ -1,4 +1,7 @@
+use std::num::NonZeroU8;
+
 use card_store::config::StorageConfig;
+use review_domain::{EaseBounds, NewCardDue};
 
 #[test]
 fn storage_config_defaults_match_documented_values() {
@@ -7,6 +10,11 @@ fn storage_config_defaults_match_documented_values() {
     assert_eq!(config.max_connections, 10);
     assert_eq!(config.batch_size, 5_000);
     assert_eq!(config.retry_attempts, 3);
+    assert_eq!(config.new_card_due, NewCardDue::Today);
+    assert_eq!(config.initial_interval, NonZeroU8::MIN);
+    assert!((config.initial_ease_factor - 2.5).abs() < f32::EPSILON);
+    assert!(config.fold_owner_case);
+    assert_eq!(config.ease_bounds, EaseBounds::default());
 }
 
 #[test]
@@ -16,10 +24,19 @@ fn storage_config_can_be_customized() {
         max_connections: 42,
         batch_size: 1_024,
         retry_attempts: 5,
+        new_card_due: NewCardDue::Tomorrow,
+        initial_interval: NonZeroU8::new(2).expect("non-zero"),
+        initial_ease_factor: 2.3,
+        fold_owner_case: false,
+        ease_bounds: EaseBounds {
+            minimum: 1.7,
+            maximum: 2.6,
+        },
     };
 
     assert_eq!(config.dsn.as_deref(), Some("postgres://example"));
     assert_eq!(config.max_connections, 42);
     assert_eq!(config.batch_size, 1_024);
     assert_eq!(config.retry_attempts, 5);
+    assert_eq!(config.initial_interval.get(), 2);
 }
@@ -0,0 +1,117 @@
+use std::num::NonZeroU8;
+
+use card_store::ReviewCardStore;
+use card_store::config::StorageConfig;
+use card_store::memory::InMemoryCardStore;
+use card_store::model::{Edge, EdgeInput, ReviewRequest, StoredCardState};
+use card_store::scheduling::ease_after_grade;
+use chrono::NaiveDate;
+use review_domain::{
+    DEFAULT_INITIAL_EASE_FACTOR, EaseBounds, Grade, ReviewGrade, next_ease_factor,
+};
+use scheduler_core::SchedulerConfig;
+
+const GRADES: [ReviewGrade; 4] = [
+    ReviewGrade::Again,
+    ReviewGrade::Hard,
+    ReviewGrade::Good,
+    ReviewGrade::Easy,
+];
+
+fn naive_date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).expect("valid date")
+}
+
+fn e4_edge(store: &InMemoryCardStore) -> Edge {
+    store
+        .upsert_edge(EdgeInput {
+            parent_id: 1,
+            move_uci: "e2e4".into(),
+            move_san: "e4".into(),
+            child_id: 2,
+        })
+        .expect("edge stored")
+}
+
+fn state_with_ease(ease_factor: f32) -> StoredCardState {
+    StoredCardState {
+        due_on: naive_date(2024, 1, 1),
+        interval: NonZeroU8::MIN,
+        ease_factor,
+        consecutive_correct: 0,
+        last_reviewed_on: None,
+    }
+}
+
+fn stored_ease_after(config: StorageConfig, ease_factor: f32, grade: ReviewGrade) -> f32 {
+    let store = InMemoryCardStore::new(config);
+    let edge = e4_edge(&store);
+    let card = store
+        .create_opening_card("learner", &edge, state_with_ease(ease_factor))
+        .expect("card created");
+    store
+        .record_review(ReviewRequest {
+            card_id: card.id,
+            reviewed_on: naive_date(2024, 1, 1),
+            grade: Grade::from(grade).to_u8(),
+            idempotency_key: None,
+        })
+        .expect("review recorded")
+        .state
+        .ease_factor
+}
+
+#[test]
+fn store_and_scheduler_agree_on_ease_at_custom_bounds() {
+    let scheduler = SchedulerConfig {
+        ease_bounds: EaseBounds {
+            minimum: 1.8,
+            maximum: 2.4,
+        },
+        ..SchedulerConfig::default()
+    };
+    let storage = StorageConfig::from_scheduler(&scheduler);
+    assert_eq!(storage.ease_bounds, scheduler.ease_bounds);
+
+    for ease_factor in [1.3, 1.8, 1.9, 2.4, 2.8] {
+        for grade in GRADES {
+            let expected = next_ease_factor(ease_factor, grade, 1.8, 2.4);
+            let bridged = ease_after_grade(&state_with_ease(ease_factor), grade, &scheduler);
+            let stored = stored_ease_after(storage.clone(), ease_factor, grade);
+
+            assert_eq!(
+                bridged.to_bits(),
+                expected.to_bits(),
+                "{ease_factor} {grade:?}"
+            );
+            assert_eq!(
+                stored.to_bits(),
+                expected.to_bits(),
+                "{ease_factor} {grade:?}"
+            );
+            assert!((1.8..=2.4).contains(&stored));
+        }
+    }
+}
+
+#[test]
+fn default_storage_config_keeps_the_supermemo_floor() {
+    let stored = stored_ease_after(StorageConfig::default(), 1.4, ReviewGrade::Again);
+    let scheduled = ease_after_grade(
+        &state_with_ease(1.4),
+        ReviewGrade::Again,
+        &SchedulerConfig::default(),
+    );
+
+    assert_eq!(stored.to_bits(), scheduled.to_bits());
+    assert!((stored - 1.3).abs() < f32::EPSILON);
+}
+
+#[test]
+fn default_configs_share_the_initial_ease_factor() {
+    let stored = StorageConfig::default().initial_ease_factor;
+    let scheduled = SchedulerConfig::default().initial_ease_factor;
+
+    assert_eq!(stored.to_bits(), scheduled.to_bits());
+    assert_eq!(stored.to_bits(), DEFAULT_INITIAL_EASE_FACTOR.to_bits());
+}
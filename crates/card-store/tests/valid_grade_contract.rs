@@ -1,4 +1,9 @@
-use review_domain::{Grade, GradeError};
+use std::num::NonZeroU8;
+
+use card_store::memory::reviews::apply_review;
+use card_store::model::{ReviewRequest, StoredCardState};
+use chrono::NaiveDate;
+use review_domain::{Grade, GradeError, ReviewGrade};
 
 #[test]
 fn valid_grades_round_trip_between_enum_and_u8() {
@@ -29,6 +34,35 @@ fn valid_grades_round_trip_between_enum_and_u8() {
     }
 }
 
+#[test]
+fn every_grade_entry_point_accepts_and_rejects_the_same_values() {
+    let reviewed_on = NaiveDate::from_ymd_opt(2024, 1, 1).expect("valid date");
+    for value in 0..=5 {
+        let mut state = StoredCardState {
+            due_on: reviewed_on,
+            interval: NonZeroU8::MIN,
+            ease_factor: 2.5,
+            consecutive_correct: 0,
+            last_reviewed_on: None,
+        };
+        let review = ReviewRequest {
+            card_id: 1,
+            reviewed_on,
+            grade: value,
+            idempotency_key: None,
+        };
+        let domain = Grade::from_u8(value);
+        let scheduler = ReviewGrade::from_u8(value);
+        let store = apply_review(&mut state, &review);
+
+        let accepted = value <= 4;
+        assert_eq!(domain.is_ok(), accepted, "Grade::from_u8({value})");
+        assert_eq!(scheduler.is_ok(), accepted, "ReviewGrade::from_u8({value})");
+        assert_eq!(store.is_ok(), accepted, "apply_review with grade {value}");
+        assert_eq!(scheduler, domain.map(ReviewGrade::from));
+    }
+}
+
 #[test]
 fn invalid_grades_surface_distinct_errors() {
     for value in [5, 6, u8::MAX] {
@@ -95,5 +129,7 @@ fn err_label(error: GradeError) -> &'static str {
     match error {
         GradeError::GradeOutsideRangeError { .. } => "GradeOutsideRangeError",
         GradeError::InvalidGradeError { .. } => "InvalidGradeError",
+        GradeError::UnrecognizedGradeError => "UnrecognizedGradeError",
+        GradeError::InvalidConfidenceError => "InvalidConfidenceError",
     }
 }
@@ -21,9 +21,24 @@ pub const DEFAULT_INCLUDE_FEN_IN_TRIE: bool = false;
 pub const DEFAULT_REQUIRE_SETUP_FOR_FEN: bool = false;
 /// Default toggle to skip (instead of error on) malformed FEN headers.
 pub const DEFAULT_SKIP_MALFORMED_FEN: bool = false;
+/// Default toggle to skip (instead of error on) edges that contradict a stored edge.
+pub const DEFAULT_SKIP_CONFLICTING_EDGES: bool = false;
+/// Default toggle for attaching PGN provenance (game index, ply, original SAN) to edges.
+pub const DEFAULT_RECORD_EDGE_PROVENANCE: bool = false;
+/// Default toggle for counting edges that return to a position already reached on their line.
+pub const DEFAULT_DETECT_TRANSPOSITION_LOOPS: bool = false;
+/// Default toggle for starting a new game at move tokens that follow a result token.
+pub const DEFAULT_SPLIT_ON_RESULT_TOKENS: bool = false;
+/// Default toggle for keeping `!`/`?` move-quality glyphs on imported edges.
+pub const DEFAULT_RETAIN_QUALITY_GLYPHS: bool = false;
 /// Default maximum depth for parsing recursive annotation variations.
 pub const DEFAULT_MAX_RAV_DEPTH: u32 = 8;
+/// Default cap on the plies replayed per game; `None` imports every move.
+pub const DEFAULT_MAX_PLY: Option<u32> = None;
+/// Default PGN tag whose value becomes each edge's `source_hint`.
+pub const DEFAULT_SOURCE_HINT_TAG: &str = "Event";
 
+use std::collections::HashMap;
 use std::ffi::OsString;
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -48,8 +63,29 @@ pub struct IngestConfig {
     pub require_setup_for_fen: bool,
     /// Skip games with malformed FEN headers instead of failing-fast.
     pub skip_malformed_fen: bool,
+    /// Skip edges whose position and move already lead to a different child instead of failing.
+    pub skip_conflicting_edges: bool,
+    /// Attach game index, ply, and original SAN provenance to each opening edge.
+    pub record_edge_provenance: bool,
+    /// Count edges that revisit an earlier position of their line in `ImportMetrics`.
+    pub detect_transposition_loops: bool,
+    /// Start a new game when move tokens follow a result token, for exports without headers.
+    pub split_on_result_tokens: bool,
+    /// Keep `!`/`?` move-quality glyphs on each opening edge instead of discarding them.
+    pub retain_quality_glyphs: bool,
     /// Maximum recursive annotation variation depth to traverse.
     pub max_rav_depth: u32,
+    /// Stop replaying a game after this many plies from its starting position; `None` imports
+    /// the whole game.
+    pub max_ply: Option<u32>,
+    /// Labeled FEN strings that games can start from via a `[StartPosition "name"]` tag.
+    pub named_positions: HashMap<String, String>,
+    /// PGN tag (matched case-insensitively) whose value is stored as each edge's
+    /// `source_hint`.
+    pub source_hint_tag: String,
+    /// PGN tag, such as `Site` or `Source`, whose value is stored as each edge's
+    /// `source_url`; `None` records no URL.
+    pub source_url_tag: Option<String>,
 }
 
 impl Default for IngestConfig {
@@ -59,13 +95,52 @@ impl Default for IngestConfig {
             include_fen_in_trie: DEFAULT_INCLUDE_FEN_IN_TRIE,
             require_setup_for_fen: DEFAULT_REQUIRE_SETUP_FOR_FEN,
             skip_malformed_fen: DEFAULT_SKIP_MALFORMED_FEN,
+            skip_conflicting_edges: DEFAULT_SKIP_CONFLICTING_EDGES,
+            record_edge_provenance: DEFAULT_RECORD_EDGE_PROVENANCE,
+            detect_transposition_loops: DEFAULT_DETECT_TRANSPOSITION_LOOPS,
+            split_on_result_tokens: DEFAULT_SPLIT_ON_RESULT_TOKENS,
+            retain_quality_glyphs: DEFAULT_RETAIN_QUALITY_GLYPHS,
             max_rav_depth: DEFAULT_MAX_RAV_DEPTH,
+            max_ply: DEFAULT_MAX_PLY,
+            named_positions: HashMap::new(),
+            source_hint_tag: DEFAULT_SOURCE_HINT_TAG.to_string(),
+            source_url_tag: None,
         }
     }
 }
 
+impl IngestConfig {
+    /// Loads the configuration from a TOML file using the same schema as `--config-file`.
+    ///
+    /// Keys that are absent keep their defaults. An `inputs` list is accepted but ignored;
+    /// input selection, and the [`ConfigError::NoInputs`] check, remain the CLI's concern.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::Io`] when the file cannot be read and [`ConfigError::Parse`]
+    /// when it is not valid TOML for this schema.
+    pub fn from_toml_path(path: impl AsRef<Path>) -> ConfigResult<Self> {
+        let file_config = FileConfig::from_path(path.as_ref())?;
+        Ok(file_config.apply_to(Self::default()))
+    }
+
+    /// Parses the configuration from TOML text using the same schema as `--config-file`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::Parse`] when the text is not valid TOML for this schema. The
+    /// error reports the placeholder path `<inline>`.
+    pub fn from_toml_str(contents: &str) -> ConfigResult<Self> {
+        let file_config = FileConfig::from_toml_str(contents, Path::new(INLINE_CONFIG_PATH))?;
+        Ok(file_config.apply_to(Self::default()))
+    }
+}
+
 type ConfigResult<T> = Result<T, ConfigError>;
 
+/// Placeholder path reported by parse errors for configuration that did not come from a file.
+const INLINE_CONFIG_PATH: &str = "<inline>";
+
 #[derive(Debug, Default, Deserialize)]
 #[serde(default)]
 struct FileConfig {
@@ -74,7 +149,16 @@ struct FileConfig {
     include_fen_in_trie: Option<bool>,
     require_setup_for_fen: Option<bool>,
     skip_malformed_fen: Option<bool>,
+    skip_conflicting_edges: Option<bool>,
+    record_edge_provenance: Option<bool>,
+    detect_transposition_loops: Option<bool>,
+    split_on_result_tokens: Option<bool>,
+    retain_quality_glyphs: Option<bool>,
     max_rav_depth: Option<u32>,
+    max_ply: Option<u32>,
+    named_positions: Option<HashMap<String, String>>,
+    source_hint_tag: Option<String>,
+    source_url_tag: Option<String>,
 }
 
 impl FileConfig {
@@ -86,13 +170,68 @@ impl FileConfig {
             })
         })?;
 
-        toml::from_str(&contents).map_err(|source| {
+        Self::from_toml_str(&contents, path)
+    }
+
+    fn from_toml_str(contents: &str, path: &Path) -> ConfigResult<Self> {
+        toml::from_str(contents).map_err(|source| {
             ConfigError::Parse(ParseError {
                 path: path.to_path_buf(),
                 source,
             })
         })
     }
+
+    /// Overlays the values present in the file onto `config`.
+    fn apply_to(&self, mut config: IngestConfig) -> IngestConfig {
+        if let Some(value) = self.tactic_from_fen {
+            config.tactic_from_fen = value;
+        }
+        if let Some(value) = self.include_fen_in_trie {
+            config.include_fen_in_trie = value;
+        }
+        if let Some(value) = self.require_setup_for_fen {
+            config.require_setup_for_fen = value;
+        }
+        if let Some(value) = self.skip_malformed_fen {
+            config.skip_malformed_fen = value;
+        }
+        if let Some(value) = self.skip_conflicting_edges {
+            config.skip_conflicting_edges = value;
+        }
+        if let Some(value) = self.record_edge_provenance {
+            config.record_edge_provenance = value;
+        }
+        if let Some(value) = self.detect_transposition_loops {
+            config.detect_transposition_loops = value;
+        }
+        if let Some(value) = self.split_on_result_tokens {
+            config.split_on_result_tokens = value;
+        }
+        if let Some(value) = self.retain_quality_glyphs {
+            config.retain_quality_glyphs = value;
+        }
+        if let Some(value) = self.max_rav_depth {
+            config.max_rav_depth = value;
+        }
+        if let Some(value) = self.max_ply {
+            config.max_ply = Some(value);
+        }
+        if let Some(positions) = &self.named_positions {
+            config.named_positions.extend(
+                positions
+                    .iter()
+                    .map(|(name, fen)| (name.clone(), fen.clone())),
+            );
+        }
+        if let Some(tag) = &self.source_hint_tag {
+            config.source_hint_tag.clone_from(tag);
+        }
+        if let Some(tag) = &self.source_url_tag {
+            config.source_url_tag = Some(tag.clone());
+        }
+        config
+    }
 }
 
 /// Command-line arguments supported by the importer.
@@ -114,11 +253,35 @@ pub struct CliArgs {
     /// When set, skip malformed FEN headers instead of failing-fast.
     skip_malformed_fen: bool,
 
+    /// When set, skip conflicting edges instead of failing-fast.
+    skip_conflicting_edges: bool,
+
+    /// When set, attach PGN provenance to each opening edge.
+    record_edge_provenance: bool,
+
+    /// When set, count edges that loop back to an earlier position of their line.
+    detect_transposition_loops: bool,
+
+    /// When set, start a new game at move tokens that follow a result token.
+    split_on_result_tokens: bool,
+
+    /// When set, keep `!`/`?` move-quality glyphs on imported edges.
+    retain_quality_glyphs: bool,
+
     /// Disable tactic extraction from `[FEN]` tagged games.
     disable_tactic_from_fen: bool,
 
     /// Limit how deep recursive annotation variations are processed.
     max_rav_depth: Option<u32>,
+
+    /// Stop replaying each game after this many plies.
+    max_ply: Option<u32>,
+
+    /// PGN tag supplying each edge's source hint.
+    source_hint_tag: Option<String>,
+
+    /// PGN tag supplying each edge's source URL.
+    source_url_tag: Option<String>,
 }
 
 impl CliArgs {
@@ -126,8 +289,16 @@ impl CliArgs {
     const ARG_INCLUDE_FEN_IN_TRIE: &'static str = "include-fen-in-trie";
     const ARG_REQUIRE_SETUP_FOR_FEN: &'static str = "require-setup-for-fen";
     const ARG_SKIP_MALFORMED_FEN: &'static str = "skip-malformed-fen";
+    const ARG_SKIP_CONFLICTING_EDGES: &'static str = "skip-conflicting-edges";
+    const ARG_RECORD_EDGE_PROVENANCE: &'static str = "record-edge-provenance";
+    const ARG_DETECT_TRANSPOSITION_LOOPS: &'static str = "detect-transposition-loops";
+    const ARG_SPLIT_ON_RESULT_TOKENS: &'static str = "split-on-result-tokens";
+    const ARG_RETAIN_QUALITY_GLYPHS: &'static str = "retain-quality-glyphs";
     const ARG_DISABLE_TACTIC_FROM_FEN: &'static str = "disable-tactic-from-fen";
     const ARG_MAX_RAV_DEPTH: &'static str = "max-rav-depth";
+    const ARG_MAX_PLY: &'static str = "max-ply";
+    const ARG_SOURCE_HINT_TAG: &'static str = "source-hint-tag";
+    const ARG_SOURCE_URL_TAG: &'static str = "source-url-tag";
     const ARG_CONFIG_FILE: &'static str = "config-file";
 
     /// Builds the clap command definition for parsing CLI arguments.
@@ -164,6 +335,31 @@ impl CliArgs {
                     .long("skip-malformed-fen")
                     .action(ArgAction::SetTrue),
             )
+            .arg(
+                Arg::new(Self::ARG_SKIP_CONFLICTING_EDGES)
+                    .long("skip-conflicting-edges")
+                    .action(ArgAction::SetTrue),
+            )
+            .arg(
+                Arg::new(Self::ARG_RECORD_EDGE_PROVENANCE)
+                    .long("record-edge-provenance")
+                    .action(ArgAction::SetTrue),
+            )
+            .arg(
+                Arg::new(Self::ARG_DETECT_TRANSPOSITION_LOOPS)
+                    .long("detect-transposition-loops")
+                    .action(ArgAction::SetTrue),
+            )
+            .arg(
+                Arg::new(Self::ARG_SPLIT_ON_RESULT_TOKENS)
+                    .long("split-on-result-tokens")
+                    .action(ArgAction::SetTrue),
+            )
+            .arg(
+                Arg::new(Self::ARG_RETAIN_QUALITY_GLYPHS)
+                    .long("retain-quality-glyphs")
+                    .action(ArgAction::SetTrue),
+            )
             .arg(
                 Arg::new(Self::ARG_DISABLE_TACTIC_FROM_FEN)
                     .long("disable-tactic-from-fen")
@@ -175,6 +371,25 @@ impl CliArgs {
                     .value_name("DEPTH")
                     .value_parser(value_parser!(u32)),
             )
+            .arg(
+                Arg::new(Self::ARG_MAX_PLY)
+                    .long("max-ply")
+                    .value_name("PLIES")
+                    .value_parser(value_parser!(u32))
+                    .help("Stop importing each game after this many plies from its start."),
+            )
+            .arg(
+                Arg::new(Self::ARG_SOURCE_HINT_TAG)
+                    .long("source-hint-tag")
+                    .value_name("TAG")
+                    .help("Store this PGN tag's value as each edge's source hint (default Event)."),
+            )
+            .arg(
+                Arg::new(Self::ARG_SOURCE_URL_TAG)
+                    .long("source-url-tag")
+                    .value_name("TAG")
+                    .help("Store this PGN tag's value, e.g. Site, as each edge's source URL."),
+            )
     }
 
     fn from_matches(matches: &ArgMatches) -> Self {
@@ -188,8 +403,18 @@ impl CliArgs {
         let include_fen_in_trie = matches.get_flag(Self::ARG_INCLUDE_FEN_IN_TRIE);
         let require_setup_for_fen = matches.get_flag(Self::ARG_REQUIRE_SETUP_FOR_FEN);
         let skip_malformed_fen = matches.get_flag(Self::ARG_SKIP_MALFORMED_FEN);
+        let skip_conflicting_edges = matches.get_flag(Self::ARG_SKIP_CONFLICTING_EDGES);
+        let record_edge_provenance = matches.get_flag(Self::ARG_RECORD_EDGE_PROVENANCE);
+        let detect_transposition_loops = matches.get_flag(Self::ARG_DETECT_TRANSPOSITION_LOOPS);
+        let split_on_result_tokens = matches.get_flag(Self::ARG_SPLIT_ON_RESULT_TOKENS);
+        let retain_quality_glyphs = matches.get_flag(Self::ARG_RETAIN_QUALITY_GLYPHS);
         let disable_tactic_from_fen = matches.get_flag(Self::ARG_DISABLE_TACTIC_FROM_FEN);
         let max_rav_depth = matches.get_one::<u32>(Self::ARG_MAX_RAV_DEPTH).copied();
+        let max_ply = matches.get_one::<u32>(Self::ARG_MAX_PLY).copied();
+        let source_hint_tag = matches
+            .get_one::<String>(Self::ARG_SOURCE_HINT_TAG)
+            .cloned();
+        let source_url_tag = matches.get_one::<String>(Self::ARG_SOURCE_URL_TAG).cloned();
 
         Self {
             inputs,
@@ -197,8 +422,16 @@ impl CliArgs {
             include_fen_in_trie,
             require_setup_for_fen,
             skip_malformed_fen,
+            skip_conflicting_edges,
+            record_edge_provenance,
+            detect_transposition_loops,
+            split_on_result_tokens,
+            retain_quality_glyphs,
             disable_tactic_from_fen,
             max_rav_depth,
+            max_ply,
+            source_hint_tag,
+            source_url_tag,
         }
     }
 
@@ -230,8 +463,16 @@ impl CliArgs {
             include_fen_in_trie,
             require_setup_for_fen,
             skip_malformed_fen,
+            skip_conflicting_edges,
+            record_edge_provenance,
+            detect_transposition_loops,
+            split_on_result_tokens,
+            retain_quality_glyphs,
             disable_tactic_from_fen,
             max_rav_depth,
+            max_ply,
+            source_hint_tag,
+            source_url_tag,
         } = self;
 
         let mut config = IngestConfig::default();
@@ -239,24 +480,10 @@ impl CliArgs {
 
         if let Some(path) = config_file {
             let file_config = FileConfig::from_path(&path)?;
+            config = file_config.apply_to(config);
             if let Some(file_inputs) = file_config.inputs {
                 merged_inputs.extend(file_inputs);
             }
-            if let Some(value) = file_config.tactic_from_fen {
-                config.tactic_from_fen = value;
-            }
-            if let Some(value) = file_config.include_fen_in_trie {
-                config.include_fen_in_trie = value;
-            }
-            if let Some(value) = file_config.require_setup_for_fen {
-                config.require_setup_for_fen = value;
-            }
-            if let Some(value) = file_config.skip_malformed_fen {
-                config.skip_malformed_fen = value;
-            }
-            if let Some(value) = file_config.max_rav_depth {
-                config.max_rav_depth = value;
-            }
         }
 
         merged_inputs.extend(inputs);
@@ -270,12 +497,36 @@ impl CliArgs {
         if skip_malformed_fen {
             config.skip_malformed_fen = true;
         }
+        if skip_conflicting_edges {
+            config.skip_conflicting_edges = true;
+        }
+        if record_edge_provenance {
+            config.record_edge_provenance = true;
+        }
+        if detect_transposition_loops {
+            config.detect_transposition_loops = true;
+        }
+        if split_on_result_tokens {
+            config.split_on_result_tokens = true;
+        }
+        if retain_quality_glyphs {
+            config.retain_quality_glyphs = true;
+        }
         if disable_tactic_from_fen {
             config.tactic_from_fen = false;
         }
         if let Some(depth) = max_rav_depth {
             config.max_rav_depth = depth;
         }
+        if let Some(plies) = max_ply {
+            config.max_ply = Some(plies);
+        }
+        if let Some(tag) = source_hint_tag {
+            config.source_hint_tag = tag;
+        }
+        if source_url_tag.is_some() {
+            config.source_url_tag = source_url_tag;
+        }
 
         if merged_inputs.is_empty() {
             return Err(ConfigError::NoInputs);
@@ -3,14 +3,18 @@ use shakmaty::san::San;
 use shakmaty::{CastlingMode, Chess, Color, EnPassantMode, Move, Position as ShakmatyPosition};
 
 use crate::config::IngestConfig;
-use crate::model::{OpeningEdgeRecord, RepertoireEdge};
-use crate::normalization::{RawGame, parse_games};
+use crate::model::{EdgeProvenance, OpeningEdgeRecord, RepertoireEdge, Tactic};
+use crate::normalization::{RawGame, parse_games_with, quality_glyph};
 use crate::storage::{InMemoryImportStore, Storage, UpsertOutcome};
-use review_domain::Position;
+#[cfg(feature = "parallel")]
+use review_domain::EdgeId;
+use review_domain::{Position, PositionId};
+use std::collections::{BTreeMap, HashSet};
+use std::ops::AddAssign;
 
-/// Tracks various metrics during the import process.
+/// Import counters, kept once for the whole import and once per repertoire.
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
-pub struct ImportMetrics {
+pub struct RepertoireMetrics {
     /// Total number of games processed.
     pub games_total: usize,
     /// Number of unique opening positions inserted.
@@ -21,9 +25,33 @@ pub struct ImportMetrics {
     pub repertoire_edges: usize,
     /// Number of tactic entries inserted.
     pub tactics: usize,
+    /// Number of conflicting opening edges skipped.
+    pub conflicting_edges: usize,
+    /// Number of stored edges leading back to a position already reached on the same line.
+    pub transposition_edges: usize,
 }
 
-impl ImportMetrics {
+impl AddAssign<&RepertoireMetrics> for RepertoireMetrics {
+    fn add_assign(&mut self, other: &RepertoireMetrics) {
+        self.games_total += other.games_total;
+        self.opening_positions += other.opening_positions;
+        self.opening_edges += other.opening_edges;
+        self.repertoire_edges += other.repertoire_edges;
+        self.tactics += other.tactics;
+        self.conflicting_edges += other.conflicting_edges;
+        self.transposition_edges += other.transposition_edges;
+    }
+}
+
+impl RepertoireMetrics {
+    /// Counters for a single game before any of its records are stored.
+    fn for_one_game() -> Self {
+        Self {
+            games_total: 1,
+            ..Self::default()
+        }
+    }
+
     fn note_position(&mut self, outcome: UpsertOutcome) {
         if outcome.is_inserted() {
             self.opening_positions += 1;
@@ -46,6 +74,27 @@ impl ImportMetrics {
     }
 }
 
+/// Tracks various metrics during the import process.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ImportMetrics {
+    /// Counters summed over every game.
+    pub totals: RepertoireMetrics,
+    /// The same counters split by the `repertoire` each game was imported under; `totals`
+    /// always equals the sum of these entries.
+    pub by_repertoire: BTreeMap<String, RepertoireMetrics>,
+}
+
+impl ImportMetrics {
+    /// Adds one game's counters to the totals and to `repertoire`'s entry.
+    fn record_game(&mut self, repertoire: &str, game: &RepertoireMetrics) {
+        self.totals += game;
+        *self
+            .by_repertoire
+            .entry(repertoire.to_string())
+            .or_default() += game;
+    }
+}
+
 /// Errors raised when parsing PGN files or deriving review data.
 #[derive(Debug, thiserror::Error, PartialEq, Eq)]
 pub enum ImportError {
@@ -55,12 +104,25 @@ pub enum ImportError {
     /// An embedded FEN string was invalid.
     #[error("invalid FEN {fen}")]
     InvalidFen { fen: String },
+    /// A `[StartPosition]` tag named a position missing from `IngestConfig::named_positions`.
+    #[error("unknown named start position `{name}`")]
+    UnknownStartPosition { name: String },
     /// A `[FEN]` tag was present without the required `[SetUp "1"]` guard.
     #[error("missing SetUp header for FEN-tagged game {fen}")]
     MissingSetup { fen: String },
     /// A SAN move was illegal in the current game context.
     #[error("illegal SAN `{san}` in game #{game}`")]
     IllegalSan { san: String, game: usize },
+    /// A position and move already lead to a different child position in the store.
+    #[error(
+        "move `{move_uci}` in game #{game} leads to {conflicting_child_fen} but is stored as leading to {existing_child_fen}"
+    )]
+    ConflictingEdge {
+        move_uci: String,
+        game: usize,
+        existing_child_fen: String,
+        conflicting_child_fen: String,
+    },
 }
 
 /// Imports PGN data into a storage backend.
@@ -115,21 +177,114 @@ impl<S: Storage> Importer<S> {
         repertoire: &str,
         pgn: &str,
     ) -> Result<(), ImportError> {
-        for (game_index, game) in parse_games(pgn).into_iter().enumerate() {
-            self.metrics.games_total += 1;
+        let games = parse_games_with(pgn, self.config.split_on_result_tokens);
+        self.ingest_games(owner, repertoire, &games)
+    }
+
+    /// Ingests games that were already parsed, by [`parse_games`](crate::parse_games) or by
+    /// any other tokenizer, applying the same replay and storage rules as
+    /// [`Importer::ingest_pgn_str`].
+    ///
+    /// Game indices in errors and provenance count from the start of `games`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any game violates the configured import constraints or contains an
+    /// illegal move; games before it stay imported.
+    pub fn ingest_games(
+        &mut self,
+        owner: &str,
+        repertoire: &str,
+        games: &[RawGame],
+    ) -> Result<(), ImportError> {
+        for (game_index, game) in games.iter().enumerate() {
             process_game(
                 &self.config,
                 &mut self.store,
                 &mut self.metrics,
                 owner,
                 repertoire,
-                &game,
+                game,
                 game_index,
             )?;
         }
         Ok(())
     }
 
+    /// Ingests PGN games like [`Importer::ingest_pgn_str`], replaying boards on all cores.
+    ///
+    /// Each game is replayed in parallel into a private write log. The logs are then applied
+    /// to the store one game at a time in input order, so the stored records and
+    /// [`ImportMetrics`] match the sequential path regardless of thread count. On error, the
+    /// games preceding the failing one (and the failing game's partial writes) are applied
+    /// before the error is returned, exactly as the sequential path would leave them.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error, in game order, that the sequential path would have raised.
+    #[cfg(feature = "parallel")]
+    pub fn ingest_pgn_str_parallel(
+        &mut self,
+        owner: &str,
+        repertoire: &str,
+        pgn: &str,
+    ) -> Result<(), ImportError> {
+        use rayon::prelude::*;
+
+        let config = &self.config;
+        let games = parse_games_with(pgn, config.split_on_result_tokens);
+        let replays: Vec<GameReplay> = games
+            .par_iter()
+            .enumerate()
+            .map(|(game_index, game)| {
+                GameReplay::record(config, owner, repertoire, game, game_index)
+            })
+            .collect();
+
+        for replay in replays {
+            let mut game_metrics = RepertoireMetrics::for_one_game();
+            let result = replay.apply(&mut self.store, &mut game_metrics);
+            self.metrics.record_game(repertoire, &game_metrics);
+            result?;
+        }
+        Ok(())
+    }
+
+    /// Replays PGN games like [`Importer::ingest_pgn_str`] without writing to the store.
+    ///
+    /// The returned [`ImportMetrics`] count the records the import would insert if it ran now,
+    /// judged against the current store contents through [`Storage::find_position`],
+    /// [`Storage::find_edge`], [`Storage::contains_repertoire_edge`], and
+    /// [`Storage::contains_tactic`]. Neither the store nor the importer's own metrics change.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same error, for the same game, that [`Importer::ingest_pgn_str`] would.
+    pub fn analyze_pgn_str(
+        &self,
+        owner: &str,
+        repertoire: &str,
+        pgn: &str,
+    ) -> Result<ImportMetrics, ImportError> {
+        let mut store = DryRunStore::new(&self.store);
+        let mut metrics = ImportMetrics::default();
+        for (game_index, game) in parse_games_with(pgn, self.config.split_on_result_tokens)
+            .into_iter()
+            .enumerate()
+        {
+            process_game(
+                &self.config,
+                &mut store,
+                &mut metrics,
+                owner,
+                repertoire,
+                &game,
+                game_index,
+            )?;
+        }
+        Ok(metrics)
+    }
+
     /// Consume the importer and return the storage backend along with collected metrics.
     #[must_use]
     pub fn finalize(self) -> (S, ImportMetrics) {
@@ -145,6 +300,223 @@ impl Importer<InMemoryImportStore> {
     }
 }
 
+/// [`Storage`] overlay that keeps writes in a scratch store and leaves the base untouched.
+///
+/// Upserts report [`UpsertOutcome::Replaced`] when the record already exists in either the
+/// base store or the scratch store, so metrics match a real import against the base.
+struct DryRunStore<'a, S: Storage> {
+    base: &'a S,
+    pending: InMemoryImportStore,
+}
+
+impl<'a, S: Storage> DryRunStore<'a, S> {
+    fn new(base: &'a S) -> Self {
+        Self {
+            base,
+            pending: InMemoryImportStore::default(),
+        }
+    }
+}
+
+impl<S: Storage> Storage for DryRunStore<'_, S> {
+    fn upsert_position(&mut self, position: Position) -> UpsertOutcome {
+        if self.base.find_position(position.id).is_some() {
+            return UpsertOutcome::Replaced;
+        }
+        self.pending.upsert_position(position)
+    }
+
+    fn upsert_edge(&mut self, edge: OpeningEdgeRecord) -> UpsertOutcome {
+        let entry = &edge.move_entry;
+        if self
+            .base
+            .find_edge(entry.parent_id, &entry.move_uci)
+            .is_some_and(|existing| existing.move_entry.edge_id == entry.edge_id)
+        {
+            return UpsertOutcome::Replaced;
+        }
+        self.pending.upsert_edge(edge)
+    }
+
+    fn upsert_repertoire_edge(&mut self, record: RepertoireEdge) -> UpsertOutcome {
+        if self.base.contains_repertoire_edge(&record) {
+            return UpsertOutcome::Replaced;
+        }
+        self.pending.upsert_repertoire_edge(record)
+    }
+
+    fn upsert_tactic(&mut self, tactic: Tactic) -> UpsertOutcome {
+        if self.base.contains_tactic(tactic.id) {
+            return UpsertOutcome::Replaced;
+        }
+        self.pending.upsert_tactic(tactic)
+    }
+
+    fn find_edge(&self, parent_id: PositionId, move_uci: &str) -> Option<OpeningEdgeRecord> {
+        self.pending
+            .find_edge(parent_id, move_uci)
+            .or_else(|| self.base.find_edge(parent_id, move_uci))
+    }
+
+    fn find_position(&self, position_id: PositionId) -> Option<Position> {
+        self.pending
+            .find_position(position_id)
+            .or_else(|| self.base.find_position(position_id))
+    }
+
+    fn contains_repertoire_edge(&self, record: &RepertoireEdge) -> bool {
+        self.pending.contains_repertoire_edge(record) || self.base.contains_repertoire_edge(record)
+    }
+
+    fn contains_tactic(&self, tactic_id: u64) -> bool {
+        self.pending.contains_tactic(tactic_id) || self.base.contains_tactic(tactic_id)
+    }
+}
+
+/// Storage writes captured while replaying a single game off the main store.
+#[cfg(feature = "parallel")]
+enum RecordedWrite {
+    Position(Position),
+    Edge(OpeningEdgeRecord),
+    Repertoire(RepertoireEdge),
+    Tactic(Tactic),
+}
+
+/// [`Storage`] implementation that logs writes instead of persisting them.
+#[cfg(feature = "parallel")]
+#[derive(Default)]
+struct WriteLog {
+    writes: Vec<RecordedWrite>,
+}
+
+#[cfg(feature = "parallel")]
+impl Storage for WriteLog {
+    fn upsert_position(&mut self, position: Position) -> UpsertOutcome {
+        self.writes.push(RecordedWrite::Position(position));
+        UpsertOutcome::Inserted
+    }
+
+    fn upsert_edge(&mut self, edge: OpeningEdgeRecord) -> UpsertOutcome {
+        self.writes.push(RecordedWrite::Edge(edge));
+        UpsertOutcome::Inserted
+    }
+
+    fn upsert_repertoire_edge(&mut self, record: RepertoireEdge) -> UpsertOutcome {
+        self.writes.push(RecordedWrite::Repertoire(record));
+        UpsertOutcome::Inserted
+    }
+
+    fn upsert_tactic(&mut self, tactic: Tactic) -> UpsertOutcome {
+        self.writes.push(RecordedWrite::Tactic(tactic));
+        UpsertOutcome::Inserted
+    }
+
+    /// The log never answers lookups: conflicts are detected against the real store when
+    /// [`GameReplay::apply`] replays the logged edges.
+    fn find_edge(&self, _parent_id: PositionId, _move_uci: &str) -> Option<OpeningEdgeRecord> {
+        None
+    }
+
+    /// Replays never run as dry runs, so the log has nothing to report.
+    fn contains_repertoire_edge(&self, _record: &RepertoireEdge) -> bool {
+        false
+    }
+
+    /// Replays never run as dry runs, so the log has nothing to report.
+    fn contains_tactic(&self, _tactic_id: u64) -> bool {
+        false
+    }
+}
+
+/// Outcome of replaying one game in isolation, ready to be merged into the real store.
+#[cfg(feature = "parallel")]
+struct GameReplay {
+    log: WriteLog,
+    index: usize,
+    record_tactic_moves: bool,
+    skip_conflicting_edges: bool,
+    history: Option<LineHistory>,
+    result: Result<(), ImportError>,
+}
+
+#[cfg(feature = "parallel")]
+impl GameReplay {
+    fn record(
+        config: &IngestConfig,
+        owner: &str,
+        repertoire: &str,
+        game: &RawGame,
+        index: usize,
+    ) -> Self {
+        let mut log = WriteLog::default();
+        let mut scratch = RepertoireMetrics::default();
+        let result = replay_game(
+            config,
+            &mut log,
+            &mut scratch,
+            owner,
+            repertoire,
+            game,
+            index,
+        );
+        Self {
+            log,
+            index,
+            record_tactic_moves: starts_from_custom_position(game) && config.tactic_from_fen,
+            skip_conflicting_edges: config.skip_conflicting_edges,
+            history: config.detect_transposition_loops.then(LineHistory::default),
+            result,
+        }
+    }
+
+    fn apply<S: Storage>(
+        mut self,
+        store: &mut S,
+        metrics: &mut RepertoireMetrics,
+    ) -> Result<(), ImportError> {
+        let mut skipped_edge: Option<EdgeId> = None;
+        // The log lists the line's positions in play order, each child right before its
+        // edge, so replaying them through a fresh history flags the same edges.
+        let mut child_revisits = false;
+        for write in self.log.writes {
+            match write {
+                RecordedWrite::Position(position) => {
+                    child_revisits = self
+                        .history
+                        .as_mut()
+                        .is_some_and(|history| history.revisits(&position));
+                    metrics.note_position(store.upsert_position(position));
+                }
+                RecordedWrite::Edge(edge) => {
+                    let edge_id = edge.move_entry.edge_id;
+                    let stored = record_edge(
+                        store,
+                        metrics,
+                        self.skip_conflicting_edges,
+                        edge,
+                        self.index,
+                    )?;
+                    if stored && child_revisits {
+                        metrics.transposition_edges += 1;
+                    }
+                    skipped_edge = (!stored).then_some(edge_id);
+                }
+                RecordedWrite::Repertoire(record) if skipped_edge == Some(record.edge_id) => {}
+                RecordedWrite::Repertoire(record) => metrics.note_repertoire(
+                    store.upsert_repertoire_edge(record),
+                    self.record_tactic_moves,
+                ),
+                RecordedWrite::Tactic(tactic) => {
+                    let _ = store.upsert_tactic(tactic);
+                }
+            }
+        }
+        self.result
+    }
+}
+
+/// Replays one game into `store` and adds its counters, including those of writes made
+/// before an error, to `metrics` under `repertoire`.
 fn process_game<S: Storage>(
     config: &IngestConfig,
     store: &mut S,
@@ -153,15 +525,59 @@ fn process_game<S: Storage>(
     repertoire: &str,
     game: &RawGame,
     index: usize,
+) -> Result<(), ImportError> {
+    let mut game_metrics = RepertoireMetrics::for_one_game();
+    let result = replay_game(
+        config,
+        store,
+        &mut game_metrics,
+        owner,
+        repertoire,
+        game,
+        index,
+    );
+    metrics.record_game(repertoire, &game_metrics);
+    result
+}
+
+fn replay_game<S: Storage>(
+    config: &IngestConfig,
+    store: &mut S,
+    metrics: &mut RepertoireMetrics,
+    owner: &str,
+    repertoire: &str,
+    game: &RawGame,
+    index: usize,
 ) -> Result<(), ImportError> {
     let fen_tag = game.tag("FEN");
     ensure_setup_requirement_for_fen_games(config, game, fen_tag)?;
-    let source_hint = game.tag("Event").map(str::to_string);
-    let context = initialize_game_context(config, store, metrics, fen_tag, source_hint.clone())?;
+    let source_hint = game.tag(&config.source_hint_tag).map(str::to_string);
+    let source_url = config
+        .source_url_tag
+        .as_deref()
+        .and_then(|tag| game.tag(tag))
+        .map(str::to_string);
+    let context = initialize_game_context(
+        config,
+        store,
+        metrics,
+        fen_tag,
+        game.tag(START_POSITION_TAG),
+        source_hint,
+    )?
+    .map(|context| context.with_source_url(source_url));
     play_moves_and_finalize(store, metrics, owner, repertoire, game, index, context)?;
     Ok(())
 }
 
+/// Tag naming an entry of [`IngestConfig::named_positions`] to start the game from.
+const START_POSITION_TAG: &str = "StartPosition";
+
+#[cfg(feature = "parallel")]
+fn starts_from_custom_position(game: &RawGame) -> bool {
+    game.tag("FEN").is_some() || game.tag(START_POSITION_TAG).is_some()
+}
+
 fn ensure_setup_requirement_for_fen_games(
     config: &IngestConfig,
     game: &RawGame,
@@ -183,8 +599,14 @@ struct GameContext {
     ply: u32,
     include_in_trie: bool,
     record_tactic_moves: bool,
+    skip_conflicting_edges: bool,
+    record_provenance: bool,
+    retain_quality_glyphs: bool,
+    stop_at_ply: Option<u32>,
+    history: Option<LineHistory>,
     pv_moves: Vec<String>,
     source_hint: Option<String>,
+    source_url: Option<String>,
 }
 
 impl GameContext {
@@ -193,6 +615,8 @@ impl GameContext {
         ply: u32,
         include_in_trie: bool,
         record_tactic_moves: bool,
+        skip_conflicting_edges: bool,
+        record_provenance: bool,
         source_hint: Option<String>,
     ) -> Self {
         Self {
@@ -200,18 +624,66 @@ impl GameContext {
             ply,
             include_in_trie,
             record_tactic_moves,
+            skip_conflicting_edges,
+            record_provenance,
+            retain_quality_glyphs: false,
+            stop_at_ply: None,
+            history: None,
             pv_moves: Vec::new(),
             source_hint,
+            source_url: None,
         }
     }
 
-    fn record_starting_position<S: Storage>(&self, store: &mut S, metrics: &mut ImportMetrics) {
+    /// Stores `source_url` on every edge the game produces.
+    fn with_source_url(mut self, source_url: Option<String>) -> Self {
+        self.source_url = source_url;
+        self
+    }
+
+    /// Keeps the `!`/`?` glyph written after each move on the edge it produces.
+    fn with_quality_glyphs(mut self, enabled: bool) -> Self {
+        self.retain_quality_glyphs = enabled;
+        self
+    }
+
+    /// Stops the line `max_ply` plies after the current (starting) ply.
+    fn with_max_ply(mut self, max_ply: Option<u32>) -> Self {
+        self.stop_at_ply = max_ply.map(|plies| self.ply.saturating_add(plies));
+        self
+    }
+
+    /// Returns true once the line has reached its configured ply cap.
+    fn reached_ply_limit(&self) -> bool {
+        self.stop_at_ply.is_some_and(|limit| self.ply >= limit)
+    }
+
+    /// Enables tracking of the positions reached on this line to flag loops back to them.
+    fn with_loop_detection(mut self, enabled: bool) -> Self {
+        self.history = enabled.then(LineHistory::default);
+        self
+    }
+
+    fn record_starting_position<S: Storage>(
+        &mut self,
+        store: &mut S,
+        metrics: &mut RepertoireMetrics,
+    ) {
         if self.include_in_trie {
-            metrics
-                .note_position(store.upsert_position(position_from_board(&self.board, self.ply)));
+            let start = position_from_board(&self.board, self.ply);
+            self.revisits(&start);
+            metrics.note_position(store.upsert_position(start));
         }
     }
 
+    /// Notes `position` on the current line, returning true when loop detection is enabled
+    /// and the line already reached it.
+    fn revisits(&mut self, position: &Position) -> bool {
+        self.history
+            .as_mut()
+            .is_some_and(|history| history.revisits(position))
+    }
+
     fn advance(&mut self, movement: MoveContext) {
         if self.record_tactic_moves {
             self.pv_moves.push(movement.uci.clone());
@@ -221,10 +693,31 @@ impl GameContext {
     }
 }
 
+/// Positions reached so far on one line, compared without their move clocks.
+#[derive(Clone, Default)]
+struct LineHistory {
+    seen: HashSet<String>,
+}
+
+impl LineHistory {
+    /// Records `position`, returning true when the line had already reached it.
+    fn revisits(&mut self, position: &Position) -> bool {
+        let key = position
+            .fen
+            .split_whitespace()
+            .take(4)
+            .collect::<Vec<_>>()
+            .join(" ");
+        !self.seen.insert(key)
+    }
+}
+
 struct MoveContext {
     uci: String,
     next_board: Chess,
     child_ply: u32,
+    provenance: Option<EdgeProvenance>,
+    quality_glyph: Option<String>,
 }
 
 impl MoveContext {
@@ -237,6 +730,8 @@ impl MoveContext {
             uci,
             next_board,
             child_ply,
+            provenance: None,
+            quality_glyph: None,
         }
     }
 }
@@ -244,22 +739,31 @@ impl MoveContext {
 fn initialize_game_context<S: Storage>(
     config: &IngestConfig,
     store: &mut S,
-    metrics: &mut ImportMetrics,
+    metrics: &mut RepertoireMetrics,
     fen_tag: Option<&str>,
+    start_position_tag: Option<&str>,
     source_hint: Option<String>,
 ) -> Result<Option<GameContext>, ImportError> {
-    match load_initial_board_from_optional_fen(fen_tag, config)? {
+    let start_fen = resolve_start_fen(config, fen_tag, start_position_tag)?;
+    match load_initial_board_from_optional_fen(start_fen, config)? {
         Some(board) => {
+            // Named start positions are opening roots like the standard start; only inline
+            // `[FEN]` games are kept out of the trie by default.
             let include_in_trie = fen_tag.is_none() || config.include_fen_in_trie;
-            let record_tactic_moves = fen_tag.is_some() && config.tactic_from_fen;
+            let record_tactic_moves = start_fen.is_some() && config.tactic_from_fen;
             let ply = board_to_ply(&board);
-            let context = GameContext::new(
+            let mut context = GameContext::new(
                 board,
                 ply,
                 include_in_trie,
                 record_tactic_moves,
+                config.skip_conflicting_edges,
+                config.record_edge_provenance,
                 source_hint,
-            );
+            )
+            .with_loop_detection(config.detect_transposition_loops)
+            .with_quality_glyphs(config.retain_quality_glyphs)
+            .with_max_ply(config.max_ply);
             context.record_starting_position(store, metrics);
             Ok(Some(context))
         }
@@ -267,6 +771,26 @@ fn initialize_game_context<S: Storage>(
     }
 }
 
+/// Picks the starting FEN for a game: an inline `[FEN]` tag wins, otherwise a
+/// `[StartPosition]` tag is looked up in the configured named positions.
+fn resolve_start_fen<'a>(
+    config: &'a IngestConfig,
+    fen_tag: Option<&'a str>,
+    start_position_tag: Option<&str>,
+) -> Result<Option<&'a str>, ImportError> {
+    match (fen_tag, start_position_tag) {
+        (Some(fen), _) => Ok(Some(fen)),
+        (None, Some(name)) => config
+            .named_positions
+            .get(name)
+            .map(|fen| Some(fen.as_str()))
+            .ok_or_else(|| ImportError::UnknownStartPosition {
+                name: name.to_string(),
+            }),
+        (None, None) => Ok(None),
+    }
+}
+
 fn load_initial_board_from_optional_fen(
     fen_tag: Option<&str>,
     config: &IngestConfig,
@@ -283,7 +807,7 @@ fn load_initial_board_from_optional_fen(
 
 fn play_moves_and_finalize<S: Storage>(
     store: &mut S,
-    metrics: &mut ImportMetrics,
+    metrics: &mut RepertoireMetrics,
     owner: &str,
     repertoire: &str,
     game: &RawGame,
@@ -298,32 +822,50 @@ fn play_moves_and_finalize<S: Storage>(
 
 fn execute_full_move_sequence<S: Storage>(
     store: &mut S,
-    metrics: &mut ImportMetrics,
+    metrics: &mut RepertoireMetrics,
     owner: &str,
     repertoire: &str,
     game: &RawGame,
     index: usize,
     context: &mut GameContext,
 ) -> Result<(), ImportError> {
-    for san_text in &game.moves {
-        process_single_san_move(store, metrics, owner, repertoire, context, san_text, index)?;
+    for (move_index, san_text) in game.moves.iter().enumerate() {
+        if context.reached_ply_limit() {
+            break;
+        }
+        let original = game.raw_move(move_index).unwrap_or(san_text);
+        process_single_san_move(
+            store, metrics, owner, repertoire, context, san_text, original, index,
+        )?;
     }
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 fn process_single_san_move<S: Storage>(
     store: &mut S,
-    metrics: &mut ImportMetrics,
+    metrics: &mut RepertoireMetrics,
     owner: &str,
     repertoire: &str,
     context: &mut GameContext,
     san_text: &str,
+    original: &str,
     index: usize,
 ) -> Result<(), ImportError> {
     let san = parse_san(san_text)?;
     let mv = convert_san_to_move(&context.board, san, san_text, index)?;
-    let movement = MoveContext::new(&context.board, mv);
-    store_opening_data_if_requested(store, metrics, owner, repertoire, context, &movement, san);
+    let mut movement = MoveContext::new(&context.board, mv);
+    if context.record_provenance {
+        movement.provenance = Some(EdgeProvenance {
+            game_index: index,
+            ply: movement.child_ply,
+            original_san: original.to_string(),
+        });
+    }
+    if context.retain_quality_glyphs {
+        movement.quality_glyph = quality_glyph(original);
+    }
+    store_opening_data_if_requested(store, metrics, owner, repertoire, context, &movement, index)?;
     context.advance(movement);
     Ok(())
 }
@@ -342,27 +884,74 @@ fn convert_san_to_move(
 
 fn store_opening_data_if_requested<S: Storage>(
     store: &mut S,
-    metrics: &mut ImportMetrics,
+    metrics: &mut RepertoireMetrics,
     owner: &str,
     repertoire: &str,
-    context: &GameContext,
+    context: &mut GameContext,
     movement: &MoveContext,
-    _san: San,
-) {
+    index: usize,
+) -> Result<(), ImportError> {
     if !context.include_in_trie {
-        return;
+        return Ok(());
     }
+    let parent = position_from_board(&context.board, context.ply);
     let child = position_from_board(&movement.next_board, movement.child_ply);
+    let child_revisits = context.revisits(&child);
     metrics.note_position(store.upsert_position(child.clone()));
-    // OpeningEdgeRecord::new signature changed; update to use only move_uci and source_hint
-    let edge = OpeningEdgeRecord::new(&movement.uci, context.source_hint.clone());
-    metrics.note_edge(store.upsert_edge(edge.clone()));
-    let repertoire_outcome = store.upsert_repertoire_edge(RepertoireEdge::new(
-        owner,
-        repertoire,
-        edge.move_entry.edge_id,
-    ));
+    let mut edge = OpeningEdgeRecord::between(
+        parent.id,
+        child.id,
+        &movement.uci,
+        context.source_hint.clone(),
+    );
+    edge.source_url.clone_from(&context.source_url);
+    edge.provenance.clone_from(&movement.provenance);
+    edge.quality_glyph.clone_from(&movement.quality_glyph);
+    let edge_id = edge.move_entry.edge_id;
+    if !record_edge(store, metrics, context.skip_conflicting_edges, edge, index)? {
+        return Ok(());
+    }
+    if child_revisits {
+        metrics.transposition_edges += 1;
+    }
+    let repertoire_outcome =
+        store.upsert_repertoire_edge(RepertoireEdge::new(owner, repertoire, edge_id));
     metrics.note_repertoire(repertoire_outcome, context.record_tactic_moves);
+    Ok(())
+}
+
+/// Stores `edge` unless the store already maps its position and move to another child.
+///
+/// Returns `Ok(false)` when a conflicting edge was skipped because `skip_conflicts` is set.
+fn record_edge<S: Storage>(
+    store: &mut S,
+    metrics: &mut RepertoireMetrics,
+    skip_conflicts: bool,
+    edge: OpeningEdgeRecord,
+    game: usize,
+) -> Result<bool, ImportError> {
+    let Some(existing) = store
+        .find_edge(edge.move_entry.parent_id, &edge.move_entry.move_uci)
+        .filter(|existing| existing.move_entry.child_id != edge.move_entry.child_id)
+    else {
+        metrics.note_edge(store.upsert_edge(edge));
+        return Ok(true);
+    };
+    if skip_conflicts {
+        metrics.conflicting_edges += 1;
+        return Ok(false);
+    }
+    let describe = |id| {
+        store
+            .find_position(id)
+            .map_or_else(|| format!("position {}", id.get()), |position| position.fen)
+    };
+    Err(ImportError::ConflictingEdge {
+        existing_child_fen: describe(existing.move_entry.child_id),
+        conflicting_child_fen: describe(edge.move_entry.child_id),
+        move_uci: edge.move_entry.move_uci,
+        game,
+    })
 }
 
 fn parse_san(token: &str) -> Result<San, ImportError> {
@@ -385,8 +974,11 @@ fn move_to_uci(board: &Chess, mv: Move) -> String {
 }
 
 fn board_to_ply(board: &Chess) -> u32 {
-    let base = board.fullmoves().get().saturating_sub(1);
-    base * 2 + u32::from(board.turn() == Color::Black)
+    let side = match board.turn() {
+        Color::White => review_domain::Color::White,
+        Color::Black => review_domain::Color::Black,
+    };
+    side.ply_at(board.fullmoves().get())
 }
 
 fn position_from_board(board: &Chess, _ply: u32) -> Position {
@@ -397,6 +989,8 @@ fn position_from_board(board: &Chess, _ply: u32) -> Position {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::normalization::parse_games;
+    use std::collections::HashMap;
 
     #[test]
     fn with_in_memory_store_preserves_config() {
@@ -405,7 +999,19 @@ mod tests {
             include_fen_in_trie: true,
             require_setup_for_fen: true,
             skip_malformed_fen: true,
+            skip_conflicting_edges: false,
+            record_edge_provenance: true,
+            detect_transposition_loops: true,
+            split_on_result_tokens: true,
+            retain_quality_glyphs: true,
             max_rav_depth: 12,
+            max_ply: Some(20),
+            named_positions: HashMap::from([(
+                "KID-main".into(),
+                "8/8/8/8/8/8/8/8 w - - 0 1".into(),
+            )]),
+            source_hint_tag: "Site".into(),
+            source_url_tag: Some("Source".into()),
         };
 
         let importer = Importer::with_in_memory_store(config.clone());
@@ -476,7 +1082,7 @@ mod tests {
 
     #[test]
     fn metrics_only_increment_when_inserted() {
-        let mut metrics = ImportMetrics::default();
+        let mut metrics = RepertoireMetrics::default();
         metrics.note_position(UpsertOutcome::Replaced);
         metrics.note_edge(UpsertOutcome::Replaced);
         metrics.note_repertoire(UpsertOutcome::Replaced, false);
@@ -590,8 +1196,8 @@ mod tests {
             ..Default::default()
         };
         let mut store = InMemoryImportStore::default();
-        let mut metrics = ImportMetrics::default();
-        let context = initialize_game_context(&config, &mut store, &mut metrics, None, None)
+        let mut metrics = RepertoireMetrics::default();
+        let context = initialize_game_context(&config, &mut store, &mut metrics, None, None, None)
             .expect("context creation succeeds")
             .expect("default board available");
         assert!(context.include_in_trie);
@@ -599,6 +1205,29 @@ mod tests {
         assert_eq!(metrics.opening_positions, 1);
     }
 
+    #[test]
+    fn named_start_positions_join_the_trie_without_the_fen_flag() {
+        let fen = "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1";
+        let config = IngestConfig {
+            named_positions: HashMap::from([("e4".into(), fen.into())]),
+            ..Default::default()
+        };
+        let mut store = InMemoryImportStore::default();
+        let mut metrics = RepertoireMetrics::default();
+
+        let named =
+            initialize_game_context(&config, &mut store, &mut metrics, None, Some("e4"), None)
+                .expect("named position resolves")
+                .expect("valid FEN");
+        let inline =
+            initialize_game_context(&config, &mut store, &mut metrics, Some(fen), None, None)
+                .expect("inline FEN loads")
+                .expect("valid FEN");
+
+        assert!(named.include_in_trie);
+        assert!(!inline.include_in_trie);
+    }
+
     #[test]
     fn initialize_game_context_respects_skip_on_malformed_fen() {
         let config = IngestConfig {
@@ -606,19 +1235,48 @@ mod tests {
             ..Default::default()
         };
         let mut store = InMemoryImportStore::default();
-        let mut metrics = ImportMetrics::default();
-        let context =
-            initialize_game_context(&config, &mut store, &mut metrics, Some("bad fen"), None)
-                .expect("skip malformed");
+        let mut metrics = RepertoireMetrics::default();
+        let context = initialize_game_context(
+            &config,
+            &mut store,
+            &mut metrics,
+            Some("bad fen"),
+            None,
+            None,
+        )
+        .expect("skip malformed");
         assert!(context.is_none());
         assert_eq!(metrics.opening_positions, 0);
     }
 
+    #[test]
+    fn resolve_start_fen_prefers_inline_fen_over_named_position() {
+        let config = IngestConfig {
+            named_positions: HashMap::from([("named".into(), "named fen".into())]),
+            ..Default::default()
+        };
+        assert_eq!(
+            resolve_start_fen(&config, Some("inline"), Some("named")),
+            Ok(Some("inline"))
+        );
+        assert_eq!(
+            resolve_start_fen(&config, None, Some("named")),
+            Ok(Some("named fen"))
+        );
+        assert_eq!(resolve_start_fen(&config, None, None), Ok(None));
+        assert_eq!(
+            resolve_start_fen(&config, None, Some("missing")),
+            Err(ImportError::UnknownStartPosition {
+                name: "missing".into()
+            })
+        );
+    }
+
     #[test]
     fn game_context_advance_tracks_ply_and_tactic_moves() {
         let board = Chess::default();
         let ply = board_to_ply(&board);
-        let mut context = GameContext::new(board.clone(), ply, true, true, None);
+        let mut context = GameContext::new(board.clone(), ply, true, true, false, false, None);
         let san = parse_san("e4").expect("valid san");
         let mv = san.to_move(&board).expect("legal move");
         let movement = MoveContext::new(&board, mv);
@@ -653,10 +1311,11 @@ mod tests {
             ..Default::default()
         };
         let mut store = InMemoryImportStore::default();
-        let mut metrics = ImportMetrics::default();
-        let mut context = initialize_game_context(&config, &mut store, &mut metrics, None, None)
-            .expect("context creation")
-            .expect("available");
+        let mut metrics = RepertoireMetrics::default();
+        let mut context =
+            initialize_game_context(&config, &mut store, &mut metrics, None, None, None)
+                .expect("context creation")
+                .expect("available");
         process_single_san_move(
             &mut store,
             &mut metrics,
@@ -664,6 +1323,7 @@ mod tests {
             "rep",
             &mut context,
             "e4",
+            "1.e4",
             0,
         )
         .expect("processing succeeds");
@@ -678,10 +1338,167 @@ mod tests {
     // #[test]
     // fn finalize_tactic_if_requested_records_entry() { ... }
 
+    #[cfg(feature = "parallel")]
+    type ImportRun = (InMemoryImportStore, ImportMetrics, Result<(), ImportError>);
+
+    #[cfg(feature = "parallel")]
+    fn import_both_ways(config: &IngestConfig, pgn: &str) -> (ImportRun, ImportRun) {
+        let mut sequential = Importer::with_in_memory_store(config.clone());
+        let sequential_result = sequential.ingest_pgn_str("owner", "rep", pgn);
+        let (sequential_store, sequential_metrics) = sequential.finalize();
+
+        let mut parallel = Importer::with_in_memory_store(config.clone());
+        let parallel_result = parallel.ingest_pgn_str_parallel("owner", "rep", pgn);
+        let (parallel_store, parallel_metrics) = parallel.finalize();
+
+        (
+            (sequential_store, sequential_metrics, sequential_result),
+            (parallel_store, parallel_metrics, parallel_result),
+        )
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parallel_import_matches_sequential_records_and_metrics() {
+        let config = IngestConfig {
+            include_fen_in_trie: true,
+            ..Default::default()
+        };
+        let pgn = r#"[Event "Open"]
+
+1. e4 e5 2. Nf3 Nc6 *
+
+[Event "Sicilian"]
+
+1. e4 c5 2. Nf3 d6 *
+
+[Event "Closed"]
+
+1. d4 d5 *
+
+[Event "Tactic"]
+[SetUp "1"]
+[FEN "r1bqk2r/pppp1ppp/2n2n2/2b1p3/2B1P3/2N2N2/PPPP1PPP/R1BQ1RK1 w kq - 4 6"]
+
+6. Nxe5 Nxe5 *
+"#;
+
+        let ((seq_store, seq_metrics, seq_result), (par_store, par_metrics, par_result)) =
+            import_both_ways(&config, pgn);
+
+        assert!(seq_result.is_ok());
+        assert_eq!(par_result, seq_result);
+        assert_eq!(par_metrics, seq_metrics);
+        assert_eq!(par_metrics.totals.games_total, 4);
+        assert_eq!(par_store.positions(), seq_store.positions());
+        assert_eq!(par_store.edges(), seq_store.edges());
+        assert_eq!(par_store.repertoire_edges(), seq_store.repertoire_edges());
+        assert_eq!(par_store.tactics(), seq_store.tactics());
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parallel_import_counts_transposition_edges_like_sequential() {
+        let config = IngestConfig {
+            detect_transposition_loops: true,
+            ..Default::default()
+        };
+        let pgn = r#"[Event "Shuffle"]
+
+1. Nf3 Nf6 2. Ng1 Ng8 3. Nf3 *
+
+[Event "Straight"]
+
+1. e4 e5 *
+"#;
+
+        let ((_, seq_metrics, seq_result), (_, par_metrics, par_result)) =
+            import_both_ways(&config, pgn);
+
+        assert!(seq_result.is_ok());
+        assert_eq!(par_result, seq_result);
+        assert_eq!(seq_metrics.totals.transposition_edges, 2);
+        assert_eq!(par_metrics, seq_metrics);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parallel_import_stops_at_first_error_like_sequential() {
+        let config = IngestConfig::default();
+        let pgn = r#"[Event "Fine"]
+
+1. e4 e5 *
+
+[Event "Broken"]
+
+1. d4 Kxh8 *
+
+[Event "Skipped"]
+
+1. c4 e5 *
+
+[Event "Also broken"]
+
+1. Nf3 Qxh1 *
+"#;
+
+        let ((seq_store, seq_metrics, seq_result), (par_store, par_metrics, par_result)) =
+            import_both_ways(&config, pgn);
+
+        assert!(matches!(
+            seq_result,
+            Err(ImportError::IllegalSan { game: 1, .. })
+        ));
+        assert_eq!(par_result, seq_result);
+        assert_eq!(par_metrics, seq_metrics);
+        assert_eq!(par_metrics.totals.games_total, 2);
+        assert_eq!(par_store.positions(), seq_store.positions());
+        assert_eq!(par_store.edges(), seq_store.edges());
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parallel_import_skips_conflicting_edges_like_sequential() {
+        let start = position_from_board(&Chess::default(), 0);
+        let stale_child =
+            Position::new("rnbqkbnr/pppppppp/8/8/3P4/8/PPP1PPPP/RNBQKBNR b KQkq - 0 1");
+        let preloaded = || {
+            let mut store = InMemoryImportStore::default();
+            let _ = store.upsert_position(stale_child.clone());
+            let _ = store.upsert_edge(OpeningEdgeRecord::between(
+                start.id,
+                stale_child.id,
+                "e2e4",
+                None,
+            ));
+            store
+        };
+        let pgn = "[Event \"Open\"]\n\n1. e4 e5 2. Nf3 *\n";
+
+        for skip_conflicting_edges in [false, true] {
+            let config = IngestConfig {
+                skip_conflicting_edges,
+                ..Default::default()
+            };
+            let mut sequential = Importer::new(config.clone(), preloaded());
+            let sequential_result = sequential.ingest_pgn_str("owner", "rep", pgn);
+            let mut parallel = Importer::new(config, preloaded());
+            let parallel_result = parallel.ingest_pgn_str_parallel("owner", "rep", pgn);
+
+            assert_eq!(parallel_result, sequential_result);
+            assert_eq!(sequential_result.is_ok(), skip_conflicting_edges);
+            let (seq_store, seq_metrics) = sequential.finalize();
+            let (par_store, par_metrics) = parallel.finalize();
+            assert_eq!(par_metrics, seq_metrics);
+            assert_eq!(par_store.edges(), seq_store.edges());
+            assert_eq!(par_store.repertoire_edges(), seq_store.repertoire_edges());
+        }
+    }
+
     #[test]
     fn play_moves_and_finalize_is_noop_when_context_absent() {
         let mut store = InMemoryImportStore::default();
-        let mut metrics = ImportMetrics::default();
+        let mut metrics = RepertoireMetrics::default();
         let game = RawGame::default();
         assert!(
             play_moves_and_finalize(&mut store, &mut metrics, "owner", "rep", &game, 0, None)
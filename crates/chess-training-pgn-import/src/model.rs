@@ -1,4 +1,4 @@
-use review_domain::{EdgeId, RepertoireMove};
+use review_domain::{EdgeId, RepertoireMove, edge_id_for};
 
 /// Schema version applied to hashed identifiers.
 pub const SCHEMA_VERSION: u32 = 1;
@@ -11,8 +11,33 @@ pub struct OpeningEdgeRecord {
     /// Canonical opening edge generated from the PGN game.
     // #[serde(flatten)] removed: not needed or not available
     pub move_entry: RepertoireMove,
-    /// Optional origin metadata for analytics or debugging.
+    /// Optional origin metadata for analytics or debugging, read from
+    /// [`IngestConfig::source_hint_tag`](crate::config::IngestConfig::source_hint_tag).
     pub source_hint: Option<String>,
+    /// Link to the original game, read from
+    /// [`IngestConfig::source_url_tag`](crate::config::IngestConfig::source_url_tag) when set.
+    pub source_url: Option<String>,
+    /// Where in the imported PGN the edge was read, when provenance recording is enabled.
+    pub provenance: Option<EdgeProvenance>,
+    /// Move-quality glyph (`!`, `?`, `!?`, ...) written after the move, when
+    /// [`IngestConfig::retain_quality_glyphs`](crate::config::IngestConfig::retain_quality_glyphs)
+    /// is set. It never feeds into the edge identifier.
+    pub quality_glyph: Option<String>,
+}
+
+/// Location in the imported PGN that produced an opening edge.
+///
+/// Recorded only when [`IngestConfig::record_edge_provenance`](crate::config::IngestConfig::record_edge_provenance)
+/// is set. It never feeds into the edge identifier.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EdgeProvenance {
+    /// Zero-based index of the game within the ingested PGN text.
+    pub game_index: usize,
+    /// Ply reached after playing the move, counted from the standard start position.
+    pub ply: u32,
+    /// Movetext token as written, including move numbers and annotation glyphs.
+    pub original_san: String,
 }
 
 impl OpeningEdgeRecord {
@@ -24,8 +49,39 @@ impl OpeningEdgeRecord {
         Self {
             move_entry: RepertoireMove::new(EdgeId::new(0), PositionId(0), PositionId(0), move_uci),
             source_hint,
+            source_url: None,
+            provenance: None,
+            quality_glyph: None,
         }
     }
+
+    /// Construct an edge between two positions, keyed by the parent, child, and move.
+    ///
+    /// The identifier comes from [`edge_id_for`], so it matches the id a card store assigns to the
+    /// same edge.
+    #[must_use]
+    pub fn between(
+        parent_id: review_domain::PositionId,
+        child_id: review_domain::PositionId,
+        move_uci: &str,
+        source_hint: Option<String>,
+    ) -> Self {
+        let edge_id = EdgeId::new(edge_id_for(parent_id.get(), child_id.get(), move_uci));
+        Self {
+            move_entry: RepertoireMove::new(edge_id, parent_id, child_id, move_uci),
+            source_hint,
+            source_url: None,
+            provenance: None,
+            quality_glyph: None,
+        }
+    }
+
+    /// Attaches debugging provenance without affecting the edge identifier.
+    #[must_use]
+    pub fn with_provenance(mut self, provenance: EdgeProvenance) -> Self {
+        self.provenance = Some(provenance);
+        self
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -58,3 +114,39 @@ pub struct Tactic {
     pub id: u64,
     // Removed: all tests and code referencing Position or PositionId.
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use review_domain::PositionId;
+
+    #[test]
+    fn edges_between_positions_are_keyed_by_parent_child_and_move() {
+        let edge = OpeningEdgeRecord::between(PositionId::new(1), PositionId::new(2), "e2e4", None);
+        let same_key =
+            OpeningEdgeRecord::between(PositionId::new(1), PositionId::new(2), "e2e4", None);
+        let other_child =
+            OpeningEdgeRecord::between(PositionId::new(1), PositionId::new(9), "e2e4", None);
+        let other_move =
+            OpeningEdgeRecord::between(PositionId::new(1), PositionId::new(2), "d2d4", None);
+
+        assert_eq!(edge.move_entry.edge_id, same_key.move_entry.edge_id);
+        assert_ne!(edge.move_entry.edge_id, other_child.move_entry.edge_id);
+        assert_ne!(edge.move_entry.edge_id, other_move.move_entry.edge_id);
+        assert_eq!(edge.move_entry.child_id, PositionId::new(2));
+    }
+
+    #[test]
+    fn provenance_does_not_change_the_edge_id() {
+        let edge = OpeningEdgeRecord::between(PositionId::new(1), PositionId::new(2), "e2e4", None);
+        let traced = edge.clone().with_provenance(EdgeProvenance {
+            game_index: 3,
+            ply: 1,
+            original_san: "1.e4!".into(),
+        });
+
+        assert_eq!(traced.move_entry, edge.move_entry);
+        assert_eq!(traced.provenance.map(|p| p.game_index), Some(3));
+        assert!(edge.provenance.is_none());
+    }
+}
@@ -7,10 +7,20 @@ pub use raw_game::RawGame;
 /// Parses the input PGN string into a vector of `RawGame` instances.
 /// Each `RawGame` contains the tags and moves extracted from the PGN.
 pub fn parse_games(input: &str) -> Vec<RawGame> {
+    parse_games_with(input, false)
+}
+
+/// Parses PGN like [`parse_games`], optionally treating result tokens as game boundaries.
+///
+/// With `split_on_result_tokens` set, move tokens that follow a result token (`1-0`, `*`, ...)
+/// start a new `RawGame` instead of being dropped, so exports that run games together without
+/// `[` headers or blank lines still yield one game each.
+pub fn parse_games_with(input: &str, split_on_result_tokens: bool) -> Vec<RawGame> {
     let mut games = Vec::new();
     let mut current = RawGame::default();
     let mut header_in_progress = false;
     let mut saw_moves = false;
+    let mut open_comment: Option<String> = None;
 
     for line in input.lines() {
         let trimmed = line.trim();
@@ -23,6 +33,7 @@ pub fn parse_games(input: &str) -> Vec<RawGame> {
                 games.push(current);
                 current = RawGame::default();
                 saw_moves = false;
+                open_comment = None;
             }
             header_in_progress = true;
             if let Some(tag) = parse_tag(trimmed) {
@@ -33,19 +44,13 @@ pub fn parse_games(input: &str) -> Vec<RawGame> {
 
         header_in_progress = false;
         saw_moves = true;
-        let normalized = normalize_line(trimmed);
-        current.moves.extend(normalized.tokens);
-        if normalized.saw_variation_markers {
-            current.saw_variation_markers = true;
-        }
-        if normalized.saw_comment_markers {
-            current.saw_comment_markers = true;
-        }
-        if normalized.saw_result_token {
-            current.saw_result_token = true;
-        }
-        if normalized.tokens_after_result {
-            current.tokens_after_result = true;
+        let mut words = trimmed.split_whitespace().peekable();
+        while words.peek().is_some() {
+            let normalized = normalize_words(&mut words, &mut open_comment, split_on_result_tokens);
+            if split_on_result_tokens && current.saw_result_token && !normalized.tokens.is_empty() {
+                games.push(std::mem::take(&mut current));
+            }
+            append_line(&mut current, normalized);
         }
     }
 
@@ -56,41 +61,104 @@ pub fn parse_games(input: &str) -> Vec<RawGame> {
     games
 }
 
+/// Appends a normalized stretch of movetext, and the flags it raised, to `game`.
+fn append_line(game: &mut RawGame, normalized: NormalizedLine) {
+    let preceding_moves = game.moves.len();
+    game.comments.extend(
+        normalized
+            .comments
+            .into_iter()
+            .map(|(offset, text)| (preceding_moves + offset, text)),
+    );
+    game.moves.extend(normalized.tokens);
+    game.raw_moves.extend(normalized.raw_tokens);
+    if normalized.saw_variation_markers {
+        game.saw_variation_markers = true;
+    }
+    if normalized.saw_comment_markers {
+        game.saw_comment_markers = true;
+    }
+    if normalized.saw_result_token {
+        game.saw_result_token = true;
+    }
+    if normalized.tokens_after_result {
+        game.tokens_after_result = true;
+    }
+}
+
+/// Normalizes a single line of movetext. Brace comments left open at the end of the line
+/// are dropped; use [`parse_games`] to capture comments spanning several lines.
 pub fn normalize_line(line: &str) -> NormalizedLine {
+    normalize_continued_line(line, &mut None)
+}
+
+/// Normalizes a line of movetext, continuing (and possibly leaving open) a brace comment.
+///
+/// `open_comment` holds the text of a `{` comment that started on an earlier line.
+fn normalize_continued_line(line: &str, open_comment: &mut Option<String>) -> NormalizedLine {
+    normalize_words(&mut line.split_whitespace(), open_comment, false)
+}
+
+/// Normalizes whitespace-separated movetext words, continuing (and possibly leaving open) a
+/// brace comment.
+///
+/// With `stop_at_result` set, the words after a result token are left in `words` for the
+/// caller instead of being consumed.
+fn normalize_words<'a>(
+    words: &mut impl Iterator<Item = &'a str>,
+    open_comment: &mut Option<String>,
+    stop_at_result: bool,
+) -> NormalizedLine {
     let mut tokens = Vec::new();
+    let mut raw_tokens = Vec::new();
+    let mut comments = Vec::new();
     let mut saw_variation_markers = false;
     let mut saw_comment_markers = false;
     let mut saw_result_token = false;
     let mut tokens_after_result = false;
-    let mut in_brace_comment = false;
     let mut after_result = false;
 
-    for raw in line.split_whitespace() {
+    while let Some(raw) = words.next() {
         if raw.is_empty() {
             continue;
         }
 
+        if open_comment.is_none() && is_control_token(raw) {
+            continue;
+        }
+
         if after_result {
             tokens_after_result = true;
         }
 
-        if in_brace_comment {
-            if raw.contains('}') {
-                saw_comment_markers = true;
-                in_brace_comment = false;
+        if let Some(text) = open_comment.as_mut() {
+            match raw.split_once('}') {
+                Some((inner, _)) => {
+                    push_comment_word(text, inner);
+                    saw_comment_markers = true;
+                    let text = open_comment.take().unwrap_or_default();
+                    push_comment(&mut comments, tokens.len(), text);
+                }
+                None => push_comment_word(text, raw),
             }
             continue;
         }
 
-        if raw.starts_with(';') {
+        if let Some(rest) = raw.strip_prefix(';') {
             saw_comment_markers = true;
+            let mut text = String::new();
+            for word in std::iter::once(rest).chain(words.by_ref()) {
+                push_comment_word(&mut text, word);
+            }
+            push_comment(&mut comments, tokens.len(), text);
             break;
         }
 
-        if raw.contains('{') {
+        if let Some((_, after)) = raw.split_once('{') {
             saw_comment_markers = true;
-            if !raw.contains('}') {
-                in_brace_comment = true;
+            match after.split_once('}') {
+                Some((inner, _)) => push_comment(&mut comments, tokens.len(), inner.to_string()),
+                None => *open_comment = Some(after.to_string()),
             }
             continue;
         }
@@ -108,6 +176,9 @@ pub fn normalize_line(line: &str) -> NormalizedLine {
         if is_result_token(raw) {
             saw_result_token = true;
             after_result = true;
+            if stop_at_result {
+                break;
+            }
             continue;
         }
 
@@ -117,20 +188,40 @@ pub fn normalize_line(line: &str) -> NormalizedLine {
 
         if let Some(token) = sanitize_token(raw) {
             tokens.push(token);
+            raw_tokens.push(raw.to_string());
         }
     }
 
     NormalizedLine {
         tokens,
+        raw_tokens,
         saw_variation_markers,
         saw_comment_markers,
         saw_result_token,
         tokens_after_result,
+        comments,
+    }
+}
+
+fn push_comment_word(text: &mut String, word: &str) {
+    if word.is_empty() {
+        return;
+    }
+    if !text.is_empty() {
+        text.push(' ');
+    }
+    text.push_str(word);
+}
+
+fn push_comment(comments: &mut Vec<(usize, String)>, preceding_tokens: usize, text: String) {
+    let text = text.trim();
+    if !text.is_empty() {
+        comments.push((preceding_tokens, text.to_string()));
     }
 }
 
 pub fn sanitize_token(raw: &str) -> Option<String> {
-    if is_result_token(raw) {
+    if is_result_token(raw) || is_control_token(raw) {
         return None;
     }
 
@@ -155,10 +246,39 @@ pub fn sanitize_token(raw: &str) -> Option<String> {
     Some(cleaned.to_string())
 }
 
+/// Returns the `!`/`?` move-quality glyph written at the end of a movetext token.
+///
+/// Check (`+`) and mate (`#`) markers around the glyph are ignored, so `Qxe4+!` yields `!`.
+/// Tokens without a glyph, including comments and results, yield `None`.
+///
+/// # Examples
+/// ```rust
+/// use chess_training_pgn_import::normalization::quality_glyph;
+/// assert_eq!(quality_glyph("12.Qxe4!").as_deref(), Some("!"));
+/// assert_eq!(quality_glyph("Nf3?!").as_deref(), Some("?!"));
+/// assert_eq!(quality_glyph("Qh5#"), None);
+/// ```
+pub fn quality_glyph(raw: &str) -> Option<String> {
+    sanitize_token(raw)?;
+    let suffix_start = raw.trim_end_matches(['!', '?', '+', '#']).len();
+    let glyph: String = raw[suffix_start..]
+        .chars()
+        .filter(|c| matches!(c, '!' | '?'))
+        .collect();
+    (!glyph.is_empty()).then_some(glyph)
+}
+
 fn is_result_token(token: &str) -> bool {
     matches!(token, "1-0" | "0-1" | "1/2-1/2" | "*")
 }
 
+/// Draw offers (`(=)`, `=`) and resignation notes (`resign`, `resigns`) written between moves.
+fn is_control_token(token: &str) -> bool {
+    matches!(token, "(=)" | "=")
+        || token.eq_ignore_ascii_case("resign")
+        || token.eq_ignore_ascii_case("resigns")
+}
+
 #[must_use]
 pub fn parse_tag(line: &str) -> Option<(String, String)> {
     let trimmed = line.strip_prefix('[').and_then(|s| s.strip_suffix(']'))?;
@@ -171,6 +291,17 @@ pub fn parse_tag(line: &str) -> Option<(String, String)> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn quality_glyph_keeps_only_the_trailing_annotation() {
+        assert_eq!(quality_glyph("Qxe4!").as_deref(), Some("!"));
+        assert_eq!(quality_glyph("Qxe4+!").as_deref(), Some("!"));
+        assert_eq!(quality_glyph("e8=Q#??").as_deref(), Some("??"));
+        assert_eq!(quality_glyph("Qxe4"), None);
+        assert_eq!(quality_glyph("!?"), None);
+        assert_eq!(quality_glyph("1-0"), None);
+        assert_eq!(sanitize_token("Qxe4!").as_deref(), Some("Qxe4"));
+    }
+
     #[test]
     fn sanitize_token_removes_results_and_markers() {
         assert_eq!(sanitize_token("1-0"), None);
@@ -183,6 +314,30 @@ mod tests {
         assert_eq!(sanitize_token("12...Qxe4+!?"), Some("Qxe4".to_string()));
     }
 
+    #[test]
+    fn sanitize_token_drops_draw_offers_and_resignations() {
+        assert_eq!(sanitize_token("(=)"), None);
+        assert_eq!(sanitize_token("="), None);
+        assert_eq!(sanitize_token("Resigns"), None);
+    }
+
+    #[test]
+    fn normalize_line_ignores_control_tokens_between_moves() {
+        let normalized = normalize_line("1. e4 (=) e5 2. Nf3 = Nc6 resign");
+        assert_eq!(normalized.tokens, vec!["e4", "e5", "Nf3", "Nc6"]);
+        assert_eq!(normalized.raw_tokens, vec!["e4", "e5", "Nf3", "Nc6"]);
+        assert!(!normalized.saw_variation_markers);
+        assert!(!normalized.saw_result_token);
+    }
+
+    #[test]
+    fn normalize_line_does_not_flag_resignation_after_result() {
+        let normalized = normalize_line("1. e4 e5 1-0 resigns");
+        assert!(normalized.saw_result_token);
+        assert!(!normalized.tokens_after_result);
+        assert_eq!(normalized.tokens, vec!["e4", "e5"]);
+    }
+
     #[test]
     fn sanitize_token_drops_tokens_without_moves() {
         assert_eq!(sanitize_token("12...?!"), None);
@@ -223,6 +378,18 @@ mod tests {
         assert_eq!(games[1].moves, vec!["d4".to_string(), "d5".to_string()]);
     }
 
+    #[test]
+    fn parse_games_keeps_original_tokens_beside_sanitized_moves() {
+        let games = parse_games("1.e4 e5 2. Nf3!? {idea} Nc6 3. Bb5+ *");
+        assert_eq!(games[0].moves, vec!["e4", "e5", "Nf3", "Nc6", "Bb5"]);
+        assert_eq!(
+            games[0].raw_moves,
+            vec!["1.e4", "e5", "Nf3!?", "Nc6", "Bb5+"]
+        );
+        assert_eq!(games[0].raw_move(2), Some("Nf3!?"));
+        assert_eq!(games[0].raw_move(5), None);
+    }
+
     #[test]
     fn parse_games_handles_headers_without_moves() {
         let pgn = "[Event \"Header Only\"]";
@@ -244,9 +411,68 @@ mod tests {
         assert_eq!(moves[1], "e5");
     }
 
+    #[test]
+    fn normalize_line_captures_brace_and_line_comments() {
+        let normalized = normalize_line("1. e4 {Best by test} e5 {} 2. Nf3 ; attacks e5");
+        assert_eq!(
+            normalized.tokens,
+            vec!["e4".to_string(), "e5".to_string(), "Nf3".to_string()]
+        );
+        assert_eq!(
+            normalized.comments,
+            vec![
+                (1, "Best by test".to_string()),
+                (3, "attacks e5".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_games_keys_comments_by_preceding_moves_across_lines() {
+        let pgn = "{Opening} 1. e4 e5\n2. Qh5 {Scholar's mate\nthreat} Nc6 *";
+        let games = parse_games(pgn);
+        assert_eq!(games.len(), 1);
+        assert_eq!(games[0].moves, vec!["e4", "e5", "Qh5", "Nc6"]);
+        assert_eq!(
+            games[0].comments,
+            vec![
+                (0, "Opening".to_string()),
+                (3, "Scholar's mate threat".to_string())
+            ]
+        );
+        assert_eq!(games[0].trailing_comments(2), vec!["Scholar's mate threat"]);
+        assert!(games[0].trailing_comments(0).is_empty());
+    }
+
     #[test]
     fn parse_games_ignores_empty_input() {
         assert!(parse_games("").is_empty());
         assert!(parse_games(" \n\n\t  ").is_empty());
     }
+
+    #[test]
+    fn parse_games_with_splits_at_moves_after_a_result_token() {
+        let pgn = "[Event \"Blob\"]\n1. e4 e5 1-0 1. d4 d5\n0-1\n{next} 1. c4 *";
+
+        let merged = parse_games(pgn);
+        assert_eq!(merged.len(), 1);
+        assert!(merged[0].tokens_after_result);
+
+        let games = parse_games_with(pgn, true);
+        assert_eq!(games.len(), 3);
+        assert_eq!(games[0].tag("Event"), Some("Blob"));
+        assert_eq!(games[0].moves, vec!["e4", "e5"]);
+        assert_eq!(games[1].moves, vec!["d4", "d5"]);
+        assert!(games[1].tags.is_empty());
+        assert_eq!(games[2].moves, vec!["c4"]);
+        assert_eq!(games[2].comments, vec![(0, "next".to_string())]);
+        assert!(games.iter().all(|game| game.saw_result_token));
+        assert!(games.iter().all(|game| !game.tokens_after_result));
+    }
+
+    #[test]
+    fn parse_games_with_leaves_well_formed_pgn_unchanged() {
+        let pgn = "[Event \"A\"]\n1. e4 e5 1-0\n\n[Event \"B\"]\n1. d4 {draw 1/2-1/2} d5 *";
+        assert_eq!(parse_games_with(pgn, true), parse_games(pgn));
+    }
 }
@@ -1,8 +1,12 @@
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct NormalizedLine {
     pub tokens: Vec<String>,
+    /// Original text of each entry in `tokens`, including move numbers and annotation glyphs.
+    pub raw_tokens: Vec<String>,
     pub saw_variation_markers: bool,
     pub saw_comment_markers: bool,
     pub saw_result_token: bool,
     pub tokens_after_result: bool,
+    /// Comment texts keyed by the number of tokens on this line that precede them.
+    pub comments: Vec<(usize, String)>,
 }
@@ -9,22 +9,31 @@
 /// let game = RawGame {
 ///     tags: vec![("Event".to_string(), "My Game".to_string())],
 ///     moves: vec!["e4".to_string(), "e5".to_string()],
+///     raw_moves: vec!["1.e4!".to_string(), "e5".to_string()],
 ///     saw_variation_markers: false,
 ///     saw_comment_markers: false,
 ///     saw_result_token: true,
 ///     tokens_after_result: false,
+///     comments: vec![(1, "Best by test".to_string())],
 /// };
 /// assert_eq!(game.tag("Event"), Some("My Game"));
 /// assert!(game.has_content());
+/// assert_eq!(game.trailing_comments(0), vec!["Best by test"]);
+/// assert_eq!(game.raw_move(0), Some("1.e4!"));
 /// ```
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct RawGame {
     pub tags: Vec<(String, String)>,
     pub moves: Vec<String>,
+    /// Movetext tokens as written, before move numbers and glyphs were stripped. Parallel to
+    /// `moves` when produced by [`parse_games`](super::parse_games); may be empty otherwise.
+    pub raw_moves: Vec<String>,
     pub saw_variation_markers: bool,
     pub saw_comment_markers: bool,
     pub saw_result_token: bool,
     pub tokens_after_result: bool,
+    /// Comment texts keyed by the number of moves that precede them, in game order.
+    pub comments: Vec<(usize, String)>,
 }
 
 /// Builder for constructing a `RawGame` instance incrementally.
@@ -37,6 +46,7 @@ pub struct RawGameBuilder {
     pub saw_comment_markers: Option<bool>,
     pub saw_result_token: Option<bool>,
     pub tokens_after_result: Option<bool>,
+    pub comments: Option<Vec<(usize, String)>>,
 }
 
 impl RawGame {
@@ -68,6 +78,24 @@ impl RawGame {
     pub fn has_content(&self) -> bool {
         self.has_tags() || self.has_moves()
     }
+
+    /// Returns the move at `move_index` (zero-based) as originally written, falling back to
+    /// the sanitized move when no raw token was recorded.
+    pub fn raw_move(&self, move_index: usize) -> Option<&str> {
+        self.raw_moves
+            .get(move_index)
+            .or_else(|| self.moves.get(move_index))
+            .map(String::as_str)
+    }
+
+    /// Returns the comments written directly after the move at `move_index` (zero-based).
+    pub fn trailing_comments(&self, move_index: usize) -> Vec<&str> {
+        self.comments
+            .iter()
+            .filter(|(preceding_moves, _)| *preceding_moves == move_index + 1)
+            .map(|(_, text)| text.as_str())
+            .collect()
+    }
 }
 
 impl RawGameBuilder {
@@ -94,6 +122,14 @@ impl RawGameBuilder {
         self
     }
 
+    /// Adds a comment that follows the first `preceding_moves` moves.
+    pub fn add_comment(mut self, preceding_moves: usize, text: impl Into<String>) -> Self {
+        self.comments
+            .get_or_insert_with(Vec::new)
+            .push((preceding_moves, text.into()));
+        self
+    }
+
     /// Sets the `saw_variation_markers` flag to `true`.
     /// Since the default is `false`, this method does not need a parameter.
     pub fn has_variation_markers(mut self) -> Self {
@@ -128,10 +164,12 @@ impl RawGameBuilder {
         Ok(RawGame {
             tags: self.tags.unwrap_or_default(),
             moves: self.moves.unwrap_or_default(),
+            raw_moves: Vec::new(),
             saw_variation_markers: self.saw_variation_markers.unwrap_or(false),
             saw_comment_markers: self.saw_comment_markers.unwrap_or(false),
             saw_result_token: self.saw_result_token.unwrap_or(false),
             tokens_after_result: self.tokens_after_result.unwrap_or(false),
+            comments: self.comments.unwrap_or_default(),
         })
     }
 }
@@ -147,6 +185,7 @@ impl Default for RawGameBuilder {
             saw_comment_markers: Some(false),
             saw_result_token: Some(false),
             tokens_after_result: Some(false),
+            comments: None,
         }
     }
 }
@@ -286,6 +325,21 @@ mod tests {
         assert!(game.has_moves());
     }
 
+    #[test]
+    fn test_builder_add_comment() {
+        let game = RawGame::builder()
+            .add_ply("e4")
+            .add_comment(1, "Best by test")
+            .add_comment(1, "Controls d5")
+            .build()
+            .unwrap();
+        assert_eq!(
+            game.trailing_comments(0),
+            vec!["Best by test", "Controls d5"]
+        );
+        assert!(game.trailing_comments(1).is_empty());
+    }
+
     #[test]
     fn test_builder_flags() {
         let game_w_variation_markers = RawGame::builder()
@@ -2,4 +2,4 @@ pub mod result;
 pub mod tag;
 
 pub use result::PgnResult;
-pub use tag::PgnTag;
+pub use tag::{PgnTag, TagParseError};
@@ -24,6 +24,22 @@ pub trait Storage {
     fn upsert_repertoire_edge(&mut self, record: RepertoireEdge) -> UpsertOutcome;
     /// Insert or update a tactic opportunity.
     fn upsert_tactic(&mut self, tactic: Tactic) -> UpsertOutcome;
+
+    /// Look up the stored opening edge leaving `parent_id` via `move_uci`, whichever child it
+    /// records. The importer relies on it to detect conflicting edges, so every backend must
+    /// answer it from its stored edges.
+    fn find_edge(&self, parent_id: PositionId, move_uci: &str) -> Option<OpeningEdgeRecord>;
+
+    /// Look up a stored position, used to describe conflicting edges.
+    fn find_position(&self, _position_id: PositionId) -> Option<Position> {
+        None
+    }
+
+    /// Whether a repertoire edge is already stored. Used by dry runs to predict upsert outcomes.
+    fn contains_repertoire_edge(&self, record: &RepertoireEdge) -> bool;
+
+    /// Whether a tactic is already stored. Used by dry runs to predict upsert outcomes.
+    fn contains_tactic(&self, tactic_id: u64) -> bool;
 }
 
 #[must_use]
@@ -57,6 +73,7 @@ impl UpsertOutcome {
 pub struct InMemoryImportStore {
     positions: BTreeMap<PositionId, Position>,
     edges: BTreeMap<EdgeId, OpeningEdgeRecord>,
+    children: BTreeMap<PositionId, BTreeSet<EdgeId>>,
     repertoire_edges: BTreeSet<(String, String, EdgeId)>,
     tactics: BTreeMap<u64, Tactic>,
 }
@@ -67,7 +84,18 @@ impl Storage for InMemoryImportStore {
     }
 
     fn upsert_edge(&mut self, edge: OpeningEdgeRecord) -> UpsertOutcome {
-        UpsertOutcome::from_bool(self.edges.insert(edge.move_entry.edge_id, edge).is_none())
+        let edge_id = edge.move_entry.edge_id;
+        let parent_id = edge.move_entry.parent_id;
+        let previous = self.edges.insert(edge_id, edge);
+        if let Some(stale_parent) = previous
+            .as_ref()
+            .map(|record| record.move_entry.parent_id)
+            .filter(|stale_parent| *stale_parent != parent_id)
+        {
+            self.unlink_child(stale_parent, edge_id);
+        }
+        self.children.entry(parent_id).or_default().insert(edge_id);
+        UpsertOutcome::from_bool(previous.is_none())
     }
 
     fn upsert_repertoire_edge(&mut self, record: RepertoireEdge) -> UpsertOutcome {
@@ -81,6 +109,28 @@ impl Storage for InMemoryImportStore {
     fn upsert_tactic(&mut self, tactic: Tactic) -> UpsertOutcome {
         UpsertOutcome::from_bool(self.tactics.insert(tactic.id, tactic).is_none())
     }
+
+    fn find_edge(&self, parent_id: PositionId, move_uci: &str) -> Option<OpeningEdgeRecord> {
+        self.children_of(parent_id)
+            .find(|edge| edge.move_entry.move_uci == move_uci)
+            .cloned()
+    }
+
+    fn find_position(&self, position_id: PositionId) -> Option<Position> {
+        self.positions.get(&position_id).cloned()
+    }
+
+    fn contains_repertoire_edge(&self, record: &RepertoireEdge) -> bool {
+        self.repertoire_edges.contains(&(
+            record.owner.clone(),
+            record.repertoire_key.clone(),
+            record.edge_id,
+        ))
+    }
+
+    fn contains_tactic(&self, tactic_id: u64) -> bool {
+        self.tactics.contains_key(&tactic_id)
+    }
 }
 
 impl InMemoryImportStore {
@@ -102,6 +152,36 @@ impl InMemoryImportStore {
         self.edges.values().cloned().collect()
     }
 
+    /// Returns the stored edges that leave `parent_id`, ordered by edge id.
+    ///
+    /// Backed by an adjacency index maintained in `upsert_edge`, so walking the imported
+    /// opening tree does not scan every edge. Mirrors `OpeningGraph::children`.
+    pub fn children_of(
+        &self,
+        parent_id: PositionId,
+    ) -> impl Iterator<Item = &OpeningEdgeRecord> + '_ {
+        self.children
+            .get(&parent_id)
+            .into_iter()
+            .flat_map(|edge_ids| edge_ids.iter().map(|edge_id| &self.edges[edge_id]))
+    }
+
+    /// Returns the stored edge with `edge_id`, if any. Mirrors `OpeningGraph::edge`.
+    #[must_use]
+    pub fn edge_by_id(&self, edge_id: EdgeId) -> Option<&OpeningEdgeRecord> {
+        self.edges.get(&edge_id)
+    }
+
+    /// Removes `edge_id` from the adjacency index of `parent_id`, dropping empty entries.
+    fn unlink_child(&mut self, parent_id: PositionId, edge_id: EdgeId) {
+        if let Some(siblings) = self.children.get_mut(&parent_id) {
+            siblings.remove(&edge_id);
+            if siblings.is_empty() {
+                self.children.remove(&parent_id);
+            }
+        }
+    }
+
     /// Return the tactics currently stored.
     #[must_use]
     pub fn tactics(&self) -> Vec<Tactic> {
@@ -141,6 +221,25 @@ mod tests {
         assert!(!store.upsert_tactic(tactic).is_inserted());
     }
 
+    #[test]
+    fn contains_lookups_reflect_stored_records() {
+        let mut store = InMemoryImportStore::default();
+        let record = RepertoireEdge::new("owner", "rep", EdgeId::new(7));
+        assert!(!store.contains_repertoire_edge(&record));
+        assert!(!store.contains_tactic(42));
+
+        let _ = store.upsert_repertoire_edge(record.clone());
+        let _ = store.upsert_tactic(Tactic { id: 42 });
+
+        assert!(store.contains_repertoire_edge(&record));
+        assert!(!store.contains_repertoire_edge(&RepertoireEdge::new(
+            "other",
+            "rep",
+            EdgeId::new(7)
+        )));
+        assert!(store.contains_tactic(42));
+    }
+
     #[test]
     fn repertoire_edges_accessor_round_trips_entries() {
         let mut store = InMemoryImportStore::default();
@@ -164,12 +263,31 @@ mod tests {
         assert_eq!(records[0].edge_id, edge.move_entry.edge_id);
     }
 
+    #[test]
+    fn replacing_an_edge_moves_it_to_its_new_parent() {
+        let mut store = InMemoryImportStore::default();
+        let mut edge = OpeningEdgeRecord::new("e2e4", None);
+        edge.move_entry.parent_id = PositionId::new(1);
+        let _ = store.upsert_edge(edge.clone());
+
+        edge.move_entry.parent_id = PositionId::new(2);
+        assert!(!store.upsert_edge(edge.clone()).is_inserted());
+
+        assert_eq!(store.children_of(PositionId::new(1)).count(), 0);
+        assert_eq!(
+            store.children_of(PositionId::new(2)).collect::<Vec<_>>(),
+            vec![&edge]
+        );
+        assert!(!store.children.contains_key(&PositionId::new(1)));
+    }
+
     #[test]
     fn in_memory_store_default_is_the_same_as_new() {
         let default_store = InMemoryImportStore::default();
         let new_store = InMemoryImportStore::new();
         assert_eq!(default_store.positions.len(), new_store.positions.len());
         assert_eq!(default_store.edges.len(), new_store.edges.len());
+        assert_eq!(default_store.children.len(), new_store.children.len());
         assert_eq!(
             default_store.repertoire_edges.len(),
             new_store.repertoire_edges.len()
@@ -1,5 +1,6 @@
+use std::collections::HashMap;
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use chess_training_pgn_import::config::{CliArgs, ConfigError, IngestConfig};
 use std::error::Error as _;
@@ -67,9 +68,20 @@ fn cli_applies_boolean_and_depth_overrides() {
         "--include-fen-in-trie",
         "--require-setup-for-fen",
         "--skip-malformed-fen",
+        "--skip-conflicting-edges",
+        "--record-edge-provenance",
+        "--detect-transposition-loops",
+        "--split-on-result-tokens",
+        "--retain-quality-glyphs",
         "--disable-tactic-from-fen",
         "--max-rav-depth",
         "3",
+        "--max-ply",
+        "20",
+        "--source-hint-tag",
+        "Annotator",
+        "--source-url-tag",
+        "Site",
     ])
     .expect("CLI parsing should succeed with overrides");
 
@@ -91,10 +103,44 @@ fn cli_applies_boolean_and_depth_overrides() {
         config.skip_malformed_fen,
         "skip-malformed flag should enable skipping"
     );
+    assert!(
+        config.skip_conflicting_edges,
+        "skip-conflicting-edges flag should enable skipping"
+    );
+    assert!(
+        config.record_edge_provenance,
+        "record-edge-provenance flag should enable provenance"
+    );
+    assert!(
+        config.detect_transposition_loops,
+        "detect-transposition-loops flag should enable loop detection"
+    );
+    assert!(
+        config.split_on_result_tokens,
+        "split-on-result-tokens flag should enable result splitting"
+    );
+    assert!(
+        config.retain_quality_glyphs,
+        "retain-quality-glyphs flag should keep move-quality glyphs"
+    );
     assert_eq!(
         config.max_rav_depth, 3,
         "max rav depth should reflect CLI override"
     );
+    assert_eq!(
+        config.max_ply,
+        Some(20),
+        "max ply should reflect CLI override"
+    );
+    assert_eq!(
+        config.source_hint_tag, "Annotator",
+        "source hint tag should reflect CLI override"
+    );
+    assert_eq!(
+        config.source_url_tag.as_deref(),
+        Some("Site"),
+        "source url tag should reflect CLI override"
+    );
 }
 
 #[test]
@@ -336,3 +382,106 @@ fn config_loader_handles_missing_optional_fields() {
         "missing max depth should keep the default",
     );
 }
+
+const SAMPLE_CONFIG: &str = r#"
+inputs = ["config/alpha.pgn"]
+tactic_from_fen = false
+include_fen_in_trie = true
+require_setup_for_fen = true
+skip_malformed_fen = true
+skip_conflicting_edges = true
+record_edge_provenance = true
+detect_transposition_loops = true
+split_on_result_tokens = true
+retain_quality_glyphs = true
+max_rav_depth = 5
+max_ply = 24
+source_hint_tag = "White"
+source_url_tag = "Site"
+"#;
+
+#[test]
+fn ingest_config_round_trips_the_cli_config_file() {
+    let mut file = NamedTempFile::new().expect("temp config should be created");
+    write!(file, "{SAMPLE_CONFIG}").expect("temp config should be writeable");
+    let path = file.into_temp_path();
+
+    let from_path = IngestConfig::from_toml_path(&path).expect("config file should load");
+    let from_str = IngestConfig::from_toml_str(SAMPLE_CONFIG).expect("config text should parse");
+    let (from_cli, inputs) = CliArgs::try_parse_from([
+        "pgn-import",
+        "--config-file",
+        path.to_str().expect("path should be valid UTF-8"),
+    ])
+    .expect("CLI parsing should succeed")
+    .build_ingest_config()
+    .expect("CLI conversion should succeed");
+
+    let expected = IngestConfig {
+        tactic_from_fen: false,
+        include_fen_in_trie: true,
+        require_setup_for_fen: true,
+        skip_malformed_fen: true,
+        skip_conflicting_edges: true,
+        record_edge_provenance: true,
+        detect_transposition_loops: true,
+        split_on_result_tokens: true,
+        retain_quality_glyphs: true,
+        max_rav_depth: 5,
+        max_ply: Some(24),
+        named_positions: HashMap::new(),
+        source_hint_tag: "White".to_string(),
+        source_url_tag: Some("Site".to_string()),
+    };
+    assert_eq!(from_path, expected);
+    assert_eq!(from_str, expected);
+    assert_eq!(from_cli, expected);
+    assert_eq!(inputs, vec![PathBuf::from("config/alpha.pgn")]);
+}
+
+#[test]
+fn ingest_config_from_toml_keeps_defaults_without_requiring_inputs() {
+    let config = IngestConfig::from_toml_str("max_rav_depth = 2\n")
+        .expect("inputs should not be required when loading IngestConfig");
+
+    assert_eq!(
+        config,
+        IngestConfig {
+            max_rav_depth: 2,
+            ..IngestConfig::default()
+        }
+    );
+    assert_eq!(
+        IngestConfig::from_toml_str("").expect("empty config is valid"),
+        IngestConfig::default()
+    );
+}
+
+#[test]
+fn ingest_config_from_toml_reads_named_positions() {
+    let config = IngestConfig::from_toml_str(
+        "[named_positions]\n\"KID-main\" = \"8/8/8/8/8/8/8/K6k w - - 0 1\"\n",
+    )
+    .expect("named positions should parse");
+
+    assert_eq!(
+        config.named_positions,
+        HashMap::from([(
+            "KID-main".to_string(),
+            "8/8/8/8/8/8/8/K6k w - - 0 1".to_string()
+        )])
+    );
+}
+
+#[test]
+fn ingest_config_from_toml_reports_io_and_parse_errors() {
+    let missing = IngestConfig::from_toml_path("/definitely/missing/config.toml")
+        .expect_err("missing file should error");
+    assert!(
+        matches!(&missing, ConfigError::Io(error) if error.path() == Path::new("/definitely/missing/config.toml"))
+    );
+
+    let invalid = IngestConfig::from_toml_str("max_rav_depth = \"deep\"")
+        .expect_err("wrongly typed value should error");
+    assert!(matches!(&invalid, ConfigError::Parse(error) if error.path() == Path::new("<inline>")));
+}
@@ -20,5 +20,34 @@ fn ingest_config_defaults_match_plan() {
         !cfg.skip_malformed_fen,
         "skip-malformed-fen should default to fail-fast"
     );
+    assert!(
+        !cfg.skip_conflicting_edges,
+        "skip-conflicting-edges should default to fail-fast"
+    );
+    assert!(
+        !cfg.record_edge_provenance,
+        "record-edge-provenance should default to disabled"
+    );
+    assert!(
+        !cfg.detect_transposition_loops,
+        "detect-transposition-loops should default to disabled"
+    );
+    assert!(
+        !cfg.split_on_result_tokens,
+        "split-on-result-tokens should default to disabled"
+    );
+    assert!(
+        !cfg.retain_quality_glyphs,
+        "retain-quality-glyphs should default to disabled"
+    );
     assert_eq!(cfg.max_rav_depth, 8, "max RAV depth should default to 8");
+    assert_eq!(cfg.max_ply, None, "max ply should default to full games");
+    assert_eq!(
+        cfg.source_hint_tag, "Event",
+        "source hints should default to the Event tag"
+    );
+    assert_eq!(
+        cfg.source_url_tag, None,
+        "source URLs should default to unrecorded"
+    );
 }
@@ -0,0 +1,32 @@
+use card_store::ReviewCardStore;
+use card_store::config::StorageConfig;
+use card_store::memory::InMemoryCardStore;
+use card_store::model::EdgeInput;
+use chess_training_pgn_import::config::IngestConfig;
+use chess_training_pgn_import::importer::Importer;
+
+#[test]
+fn imported_edges_share_ids_with_card_store_edges() {
+    let mut importer = Importer::with_in_memory_store(IngestConfig::default());
+    importer
+        .ingest_pgn_str("owner", "main", "1. e4 e5 2. Nf3 *")
+        .expect("import should succeed");
+    let (store, _) = importer.finalize();
+    let card_store = InMemoryCardStore::new(StorageConfig::default());
+
+    let imported = store.edges();
+    assert_eq!(imported.len(), 3);
+    for record in imported {
+        let entry = record.move_entry;
+        let created = card_store
+            .upsert_edge(EdgeInput {
+                parent_id: entry.parent_id.get(),
+                move_uci: entry.move_uci.clone(),
+                move_san: String::new(),
+                child_id: entry.child_id.get(),
+            })
+            .expect("edge stored");
+
+        assert_eq!(created.id, entry.edge_id.get(), "{}", entry.move_uci);
+    }
+}
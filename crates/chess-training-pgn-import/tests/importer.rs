@@ -1,6 +1,13 @@
+use std::collections::{BTreeMap, HashMap};
+
 use chess_training_pgn_import::config::IngestConfig;
-use chess_training_pgn_import::importer::{ImportError, Importer};
-use chess_training_pgn_import::storage::InMemoryImportStore;
+use chess_training_pgn_import::importer::{
+    ImportError, ImportMetrics, Importer, RepertoireMetrics,
+};
+use chess_training_pgn_import::model::{EdgeProvenance, OpeningEdgeRecord};
+use chess_training_pgn_import::storage::{InMemoryImportStore, Storage};
+use chess_training_pgn_import::{RawGame, parse_games};
+use review_domain::Position;
 
 fn sample_pgn() -> &'static str {
     r#"[Event "Opening"]
@@ -37,13 +44,16 @@ fn importer_builds_opening_trie_and_tactics() {
 
     let (store, metrics) = importer.finalize();
 
-    assert_eq!(metrics.games_total, 2, "two games should be processed");
     assert_eq!(
-        metrics.opening_edges, 1,
-        "opening game adds one move (legacy code removed)"
+        metrics.totals.games_total, 2,
+        "two games should be processed"
+    );
+    assert_eq!(
+        metrics.totals.opening_edges, 6,
+        "opening game adds one edge per ply"
     );
     assert_eq!(
-        metrics.tactics, 0,
+        metrics.totals.tactics, 0,
         "no tactics should be extracted (legacy code removed)"
     );
 
@@ -55,8 +65,13 @@ fn importer_builds_opening_trie_and_tactics() {
         "start position must be recorded"
     );
 
-    // let edge_uci: Vec<_> = store.edges().into_iter().map(|edge| edge.move_entry.move_uci.clone()).collect();
-    // Only one edge is present after legacy code removal; skip UCI assertions
+    let edge_uci: Vec<_> = store
+        .edges()
+        .into_iter()
+        .map(|edge| edge.move_entry.move_uci)
+        .collect();
+    assert!(edge_uci.iter().any(|uci| uci == "e2e4"));
+    assert!(edge_uci.iter().any(|uci| uci == "f1b5"));
 
     // let tactics = store.tactics();
     // assert_eq!(tactics.len(), 1, "exactly one tactic is expected");
@@ -113,7 +128,10 @@ fn importer_skips_malformed_fens_when_configured() {
 
     let (store, metrics) = importer.finalize();
 
-    assert_eq!(metrics.games_total, 1, "game counter should increment");
+    assert_eq!(
+        metrics.totals.games_total, 1,
+        "game counter should increment"
+    );
     assert!(
         store.positions().is_empty(),
         "malformed game should be ignored"
@@ -169,6 +187,67 @@ fn importer_reports_contextual_illegal_san() {
     }));
 }
 
+#[test]
+fn analyze_pgn_str_predicts_metrics_without_writing() {
+    let mut importer = Importer::with_in_memory_store(IngestConfig::default());
+
+    let predicted = importer
+        .analyze_pgn_str("owner", "main", sample_pgn())
+        .expect("dry run should succeed");
+    let again = importer
+        .analyze_pgn_str("owner", "main", sample_pgn())
+        .expect("dry run should be repeatable");
+    assert_eq!(predicted, again);
+
+    importer
+        .ingest_pgn_str("owner", "main", sample_pgn())
+        .expect("import should succeed");
+    let repeat = importer
+        .analyze_pgn_str("owner", "main", sample_pgn())
+        .expect("dry run after import should succeed");
+    assert_eq!(
+        repeat,
+        ImportMetrics {
+            totals: RepertoireMetrics {
+                games_total: 2,
+                ..RepertoireMetrics::default()
+            },
+            by_repertoire: BTreeMap::from([(
+                "main".to_string(),
+                RepertoireMetrics {
+                    games_total: 2,
+                    ..RepertoireMetrics::default()
+                },
+            )]),
+        },
+        "records already stored are not counted as inserts"
+    );
+
+    let (_, metrics) = importer.finalize();
+    assert_eq!(metrics, predicted);
+}
+
+#[test]
+fn analyze_pgn_str_reports_import_errors_and_leaves_store_empty() {
+    let importer = Importer::with_in_memory_store(IngestConfig::default());
+
+    let err = importer
+        .analyze_pgn_str("owner", "main", "1. e4 e5\n\n[Event \"Bad\"]\n1. Qh4 *\n")
+        .expect_err("illegal SAN should surface in a dry run");
+    assert_eq!(
+        err,
+        ImportError::IllegalSan {
+            san: "Qh4".to_string(),
+            game: 1,
+        }
+    );
+
+    let (store, metrics) = importer.finalize();
+    assert!(store.positions().is_empty());
+    assert!(store.edges().is_empty());
+    assert_eq!(metrics, ImportMetrics::default());
+}
+
 #[test]
 fn importer_does_not_emit_tactics_when_disabled() {
     let config = IngestConfig {
@@ -192,7 +271,10 @@ fn importer_does_not_emit_tactics_when_disabled() {
 
     let (store, metrics) = importer.finalize();
 
-    assert_eq!(metrics.tactics, 0, "tactic extraction should be disabled");
+    assert_eq!(
+        metrics.totals.tactics, 0,
+        "tactic extraction should be disabled"
+    );
     assert!(store.tactics().is_empty(), "no tactic should be stored");
     assert!(
         !store.positions().is_empty(),
@@ -209,9 +291,9 @@ fn importer_ignores_empty_inputs() {
         .expect("empty input should succeed");
 
     let (_store, metrics) = importer.finalize();
-    assert_eq!(metrics.games_total, 0, "no games should be recorded");
-    assert_eq!(metrics.opening_edges, 0);
-    assert_eq!(metrics.tactics, 0);
+    assert_eq!(metrics.totals.games_total, 0, "no games should be recorded");
+    assert_eq!(metrics.totals.opening_edges, 0);
+    assert_eq!(metrics.totals.tactics, 0);
 }
 
 #[test]
@@ -233,3 +315,501 @@ fn importer_errors_on_invalid_fen_without_skip() {
     assert!(is_invalid_fen(&err));
     assert!(!is_invalid_fen(&ImportError::Pgn("pgn".to_string())));
 }
+
+const KID_MAIN_FEN: &str = "rnbqk2r/ppp1ppbp/3p1np1/8/2PPP3/2N5/PP3PPP/R1BQKBNR w KQkq - 0 5";
+
+fn config_with_named(name: &str, fen: &str) -> IngestConfig {
+    IngestConfig {
+        named_positions: HashMap::from([(name.to_string(), fen.to_string())]),
+        ..IngestConfig::default()
+    }
+}
+
+#[test]
+fn importer_starts_games_from_named_positions() {
+    let mut importer = Importer::with_in_memory_store(config_with_named("KID-main", KID_MAIN_FEN));
+
+    let pgn = r#"[Event "KID drill"]
+[StartPosition "KID-main"]
+
+5. Nf3 O-O *
+"#;
+    importer
+        .ingest_pgn_str("owner", "kid", pgn)
+        .expect("named start position should resolve");
+
+    let (store, metrics) = importer.finalize();
+    assert_eq!(metrics.totals.opening_edges, 2);
+    assert!(
+        store.positions().iter().any(|pos| pos.fen == KID_MAIN_FEN),
+        "named start position should be recorded as the game root"
+    );
+}
+
+#[test]
+fn importer_rejects_unknown_or_invalid_named_positions() {
+    let mut importer = Importer::with_in_memory_store(IngestConfig::default());
+    let pgn = r#"[StartPosition "KID-main"]
+
+5. Nf3 *
+"#;
+    let err = importer
+        .ingest_pgn_str("owner", "kid", pgn)
+        .expect_err("unknown names should fail");
+    assert_eq!(
+        err,
+        ImportError::UnknownStartPosition {
+            name: "KID-main".to_string()
+        }
+    );
+    assert_eq!(err.to_string(), "unknown named start position `KID-main`");
+
+    let mut importer = Importer::with_in_memory_store(config_with_named("KID-main", "not a fen"));
+    let err = importer
+        .ingest_pgn_str("owner", "kid", pgn)
+        .expect_err("named FEN must be valid");
+    assert!(matches!(err, ImportError::InvalidFen { fen } if fen == "not a fen"));
+}
+
+const START_FEN: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+const STALE_CHILD_FEN: &str = "rnbqkbnr/pppppppp/8/8/3P4/8/PPP1PPPP/RNBQKBNR b KQkq - 0 1";
+
+/// Store whose `e2e4` edge from the start position points at the wrong child.
+fn store_with_conflicting_e4() -> InMemoryImportStore {
+    let mut store = InMemoryImportStore::new();
+    let start = Position::new(START_FEN);
+    let stale_child = Position::new(STALE_CHILD_FEN);
+    let _ = store.upsert_position(start.clone());
+    let _ = store.upsert_position(stale_child.clone());
+    let _ = store.upsert_edge(OpeningEdgeRecord::between(
+        start.id,
+        stale_child.id,
+        "e2e4",
+        None,
+    ));
+    store
+}
+
+const E4_GAME: &str = r#"[Event "Conflict"]
+
+1. e4 e5 *
+"#;
+
+#[test]
+fn importer_reports_conflicting_edges_with_both_children() {
+    let mut importer = Importer::new(IngestConfig::default(), store_with_conflicting_e4());
+
+    let err = importer
+        .ingest_pgn_str("owner", "main", E4_GAME)
+        .expect_err("conflicting edge should fail without skip flag");
+
+    let ImportError::ConflictingEdge {
+        move_uci,
+        game,
+        existing_child_fen,
+        conflicting_child_fen,
+    } = err
+    else {
+        panic!("expected a conflicting edge error, got {err:?}");
+    };
+    assert_eq!(move_uci, "e2e4");
+    assert_eq!(game, 0);
+    assert_eq!(existing_child_fen, STALE_CHILD_FEN);
+    assert!(conflicting_child_fen.starts_with("rnbqkbnr/pppppppp/8/8/4P3/"));
+}
+
+#[test]
+fn importer_skips_only_the_conflicting_edge_when_configured() {
+    let config = IngestConfig {
+        skip_conflicting_edges: true,
+        ..IngestConfig::default()
+    };
+    let mut importer = Importer::new(config, store_with_conflicting_e4());
+
+    importer
+        .ingest_pgn_str("owner", "main", E4_GAME)
+        .expect("conflicting edge should be skipped");
+
+    let (store, metrics) = importer.finalize();
+    assert_eq!(metrics.totals.conflicting_edges, 1);
+    assert_eq!(metrics.totals.opening_edges, 1, "only e7e5 is newly stored");
+    assert_eq!(metrics.totals.repertoire_edges, 1);
+    let e4 = store
+        .edges()
+        .into_iter()
+        .find(|edge| edge.move_entry.move_uci == "e2e4")
+        .expect("preloaded edge is kept");
+    assert_eq!(e4.move_entry.child_id, Position::new(STALE_CHILD_FEN).id);
+}
+
+#[test]
+fn importer_records_edge_provenance_only_when_enabled() {
+    let pgn = r#"[Event "First"]
+
+1. d4 d5 *
+
+[Event "Second"]
+
+1.e4 e5 2. Nf3!? Nc6 *
+"#;
+    let mut plain = Importer::with_in_memory_store(IngestConfig::default());
+    plain.ingest_pgn_str("owner", "main", pgn).expect("import");
+    let (plain_store, plain_metrics) = plain.finalize();
+    assert!(
+        plain_store
+            .edges()
+            .iter()
+            .all(|edge| edge.provenance.is_none())
+    );
+
+    let mut traced = Importer::with_in_memory_store(IngestConfig {
+        record_edge_provenance: true,
+        ..IngestConfig::default()
+    });
+    traced.ingest_pgn_str("owner", "main", pgn).expect("import");
+    let (traced_store, traced_metrics) = traced.finalize();
+
+    assert_eq!(traced_metrics, plain_metrics);
+    let plain_ids: Vec<_> = plain_store
+        .edges()
+        .iter()
+        .map(|edge| edge.move_entry.edge_id)
+        .collect();
+    let traced_ids: Vec<_> = traced_store
+        .edges()
+        .iter()
+        .map(|edge| edge.move_entry.edge_id)
+        .collect();
+    assert_eq!(traced_ids, plain_ids);
+
+    let knight = traced_store
+        .edges()
+        .into_iter()
+        .find(|edge| edge.move_entry.move_uci == "g1f3")
+        .expect("Nf3 edge stored");
+    assert_eq!(
+        knight.provenance,
+        Some(EdgeProvenance {
+            game_index: 1,
+            ply: 3,
+            original_san: "Nf3!?".to_string(),
+        })
+    );
+    let opening = traced_store
+        .edges()
+        .into_iter()
+        .find(|edge| edge.move_entry.move_uci == "e2e4")
+        .and_then(|edge| edge.provenance)
+        .expect("e4 provenance recorded");
+    assert_eq!((opening.ply, opening.original_san.as_str()), (1, "1.e4"));
+}
+
+#[test]
+fn importer_keeps_quality_glyphs_on_edges_only_when_enabled() {
+    let pgn = "1. e4! e5 2. Nf3?! Nc6 3. Bb5+? *\n";
+    let glyphs = |config: IngestConfig| {
+        let mut importer = Importer::with_in_memory_store(config);
+        importer
+            .ingest_pgn_str("owner", "main", pgn)
+            .expect("import");
+        let (store, _) = importer.finalize();
+        let mut glyphs: Vec<_> = store
+            .edges()
+            .into_iter()
+            .filter_map(|edge| Some((edge.move_entry.move_uci, edge.quality_glyph?)))
+            .collect();
+        glyphs.sort();
+        glyphs
+    };
+
+    assert!(glyphs(IngestConfig::default()).is_empty());
+    assert_eq!(
+        glyphs(IngestConfig {
+            retain_quality_glyphs: true,
+            ..IngestConfig::default()
+        }),
+        vec![
+            ("e2e4".to_string(), "!".to_string()),
+            ("f1b5".to_string(), "?".to_string()),
+            ("g1f3".to_string(), "?!".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn importer_stops_each_game_at_max_ply_counted_from_its_start() {
+    let config = IngestConfig {
+        include_fen_in_trie: true,
+        max_ply: Some(2),
+        ..IngestConfig::default()
+    };
+    let mut importer = Importer::with_in_memory_store(config);
+    importer
+        .ingest_pgn_str("owner", "main", sample_pgn())
+        .expect("import");
+    let (store, metrics) = importer.finalize();
+
+    assert_eq!(metrics.totals.games_total, 2);
+    assert_eq!(metrics.totals.opening_edges, 4, "two plies from each game");
+    let mut edge_uci: Vec<_> = store
+        .edges()
+        .into_iter()
+        .map(|edge| edge.move_entry.move_uci)
+        .collect();
+    edge_uci.sort();
+    assert_eq!(edge_uci, vec!["c6e5", "e2e4", "e7e5", "f3e5"]);
+
+    let mut full = Importer::with_in_memory_store(IngestConfig {
+        max_ply: None,
+        ..IngestConfig::default()
+    });
+    full.ingest_pgn_str("owner", "main", sample_pgn())
+        .expect("import");
+    assert_eq!(full.finalize().1.totals.opening_edges, 6);
+}
+
+#[test]
+fn importer_reads_source_hint_and_url_from_configured_tags() {
+    let pgn = r#"[Event "Club night"]
+[Annotator "Coach"]
+[Site "https://example.org/game/42"]
+
+1. e4 e5 *
+"#;
+
+    let mut default = Importer::with_in_memory_store(IngestConfig::default());
+    default
+        .ingest_pgn_str("owner", "main", pgn)
+        .expect("import");
+    let (store, _) = default.finalize();
+    assert!(store.edges().iter().all(|edge| {
+        edge.source_hint.as_deref() == Some("Club night") && edge.source_url.is_none()
+    }));
+
+    let mut configured = Importer::with_in_memory_store(IngestConfig {
+        source_hint_tag: "annotator".to_string(),
+        source_url_tag: Some("Site".to_string()),
+        ..IngestConfig::default()
+    });
+    configured
+        .ingest_pgn_str("owner", "main", pgn)
+        .expect("import");
+    let (store, _) = configured.finalize();
+    let edges = store.edges();
+    assert_eq!(edges.len(), 2);
+    assert!(edges.iter().all(|edge| {
+        edge.source_hint.as_deref() == Some("Coach")
+            && edge.source_url.as_deref() == Some("https://example.org/game/42")
+    }));
+}
+
+#[test]
+fn importer_leaves_source_fields_empty_when_tags_are_missing() {
+    let mut importer = Importer::with_in_memory_store(IngestConfig {
+        source_hint_tag: "Annotator".to_string(),
+        source_url_tag: Some("Source".to_string()),
+        ..IngestConfig::default()
+    });
+    importer
+        .ingest_pgn_str("owner", "main", "[Event \"Club night\"]\n\n1. d4 d5 *\n")
+        .expect("import");
+    let (store, _) = importer.finalize();
+
+    let edges = store.edges();
+    assert_eq!(edges.len(), 2);
+    assert!(
+        edges
+            .iter()
+            .all(|edge| edge.source_hint.is_none() && edge.source_url.is_none())
+    );
+}
+
+#[test]
+fn importer_counts_edges_that_loop_back_only_when_enabled() {
+    let pgn = "1. Nf3 Nf6 2. Ng1 Ng8 3. Nf3 Nc6 *\n";
+
+    let mut plain = Importer::with_in_memory_store(IngestConfig::default());
+    plain.ingest_pgn_str("owner", "main", pgn).expect("import");
+    let (plain_store, plain_metrics) = plain.finalize();
+    assert_eq!(plain_metrics.totals.transposition_edges, 0);
+
+    let mut checked = Importer::with_in_memory_store(IngestConfig {
+        detect_transposition_loops: true,
+        ..IngestConfig::default()
+    });
+    checked
+        .ingest_pgn_str("owner", "main", pgn)
+        .expect("import");
+    let (checked_store, checked_metrics) = checked.finalize();
+
+    // 2...Ng8 returns to the initial position and 3. Nf3 to the one after 1. Nf3.
+    assert_eq!(checked_metrics.totals.transposition_edges, 2);
+    assert_eq!(checked_store.edges(), plain_store.edges());
+    assert_eq!(
+        RepertoireMetrics {
+            transposition_edges: 0,
+            ..checked_metrics.totals
+        },
+        plain_metrics.totals
+    );
+}
+
+#[test]
+fn importer_splits_headerless_games_at_result_tokens_when_enabled() {
+    let pgn = "1. e4 e5 1-0 1. d4 d5 0-1\n";
+
+    let mut merged = Importer::with_in_memory_store(IngestConfig::default());
+    merged.ingest_pgn_str("owner", "main", pgn).expect("import");
+    let (_, merged_metrics) = merged.finalize();
+    assert_eq!(merged_metrics.totals.games_total, 1);
+    assert_eq!(merged_metrics.totals.opening_edges, 2);
+
+    let mut split = Importer::with_in_memory_store(IngestConfig {
+        split_on_result_tokens: true,
+        ..IngestConfig::default()
+    });
+    split.ingest_pgn_str("owner", "main", pgn).expect("import");
+    let (store, metrics) = split.finalize();
+    assert_eq!(metrics.totals.games_total, 2);
+    assert_eq!(metrics.totals.opening_edges, 4);
+    let mut moves: Vec<_> = store
+        .edges()
+        .into_iter()
+        .map(|edge| edge.move_entry.move_uci)
+        .collect();
+    moves.sort();
+    assert_eq!(moves, vec!["d2d4", "d7d5", "e2e4", "e7e5"]);
+}
+
+#[test]
+fn importer_keeps_moves_around_draw_offer_tokens() {
+    let pgn = "1. e4 (=) e5 2. Nf3 resign *\n";
+    let mut importer = Importer::with_in_memory_store(IngestConfig::default());
+
+    importer
+        .ingest_pgn_str("owner", "main", pgn)
+        .expect("import");
+    let (store, metrics) = importer.finalize();
+
+    assert_eq!(metrics.totals.opening_edges, 3);
+    let moves: Vec<_> = store
+        .edges()
+        .into_iter()
+        .map(|edge| edge.move_entry.move_uci)
+        .collect();
+    assert!(moves.contains(&"e2e4".to_string()));
+    assert!(moves.contains(&"e7e5".to_string()));
+    assert!(moves.contains(&"g1f3".to_string()));
+}
+
+#[test]
+fn ingest_games_matches_ingest_pgn_str_for_pre_parsed_games() {
+    let mut from_text = Importer::with_in_memory_store(IngestConfig::default());
+    from_text
+        .ingest_pgn_str("owner", "main", sample_pgn())
+        .expect("import");
+    let mut from_games = Importer::with_in_memory_store(IngestConfig::default());
+    from_games
+        .ingest_games("owner", "main", &parse_games(sample_pgn()))
+        .expect("import");
+
+    let (text_store, text_metrics) = from_text.finalize();
+    let (games_store, games_metrics) = from_games.finalize();
+    assert_eq!(games_metrics, text_metrics);
+    assert_eq!(games_store.edges(), text_store.edges());
+}
+
+#[test]
+fn ingest_games_accepts_games_built_without_pgn_text() {
+    let game = RawGame {
+        moves: vec!["e4".into(), "c5".into(), "Nf3".into()],
+        saw_result_token: true,
+        ..RawGame::default()
+    };
+    let illegal = RawGame {
+        moves: vec!["e5".into()],
+        ..RawGame::default()
+    };
+    let mut importer = Importer::with_in_memory_store(IngestConfig::default());
+
+    importer
+        .ingest_games("owner", "main", std::slice::from_ref(&game))
+        .expect("import");
+    let error = importer
+        .ingest_games("owner", "main", &[game, illegal])
+        .expect_err("e5 is illegal for White");
+    let (store, metrics) = importer.finalize();
+
+    assert!(matches!(error, ImportError::IllegalSan { game: 1, .. }));
+    assert_eq!(metrics.totals.games_total, 3);
+    let mut moves: Vec<_> = store
+        .edges()
+        .into_iter()
+        .map(|edge| edge.move_entry.move_uci)
+        .collect();
+    moves.sort();
+    assert_eq!(moves, vec!["c7c5", "e2e4", "g1f3"]);
+}
+
+#[test]
+fn in_memory_store_walks_an_imported_line_through_children_of() {
+    let pgn = "[Event \"Line\"]\n\n1. e4 e5 2. Nf3 Nc6 *\n";
+    let mut importer = Importer::with_in_memory_store(IngestConfig::default());
+    importer
+        .ingest_pgn_str("owner", "main", pgn)
+        .expect("import should succeed");
+    let (store, _) = importer.finalize();
+
+    let start = store
+        .positions()
+        .into_iter()
+        .find(|pos| pos.ply() == Some(0))
+        .expect("start position stored");
+    let mut parent = start.id;
+    let mut line = Vec::new();
+    while let Some(edge) = store.children_of(parent).next() {
+        assert_eq!(store.children_of(parent).count(), 1, "single line");
+        assert_eq!(store.edge_by_id(edge.move_entry.edge_id), Some(edge));
+        line.push(edge.move_entry.move_uci.clone());
+        parent = edge.move_entry.child_id;
+    }
+
+    assert_eq!(line, ["e2e4", "e7e5", "g1f3", "b8c6"]);
+    assert_eq!(store.edge_by_id(review_domain::EdgeId::new(0)), None);
+}
+
+#[test]
+fn metrics_break_down_by_repertoire_and_sum_to_the_totals() {
+    let mut importer = Importer::with_in_memory_store(IngestConfig::default());
+    importer
+        .ingest_pgn_str("owner", "French", "1. e4 e6 2. d4 d5 *\n")
+        .expect("French imports");
+    importer
+        .ingest_pgn_str(
+            "owner",
+            "Caro",
+            "[Event \"Main\"]\n1. e4 c6 *\n\n[Event \"Queen's pawn\"]\n1. d4 d5 *\n",
+        )
+        .expect("Caro imports");
+    let (_, metrics) = importer.finalize();
+
+    let french = &metrics.by_repertoire["French"];
+    let caro = &metrics.by_repertoire["Caro"];
+    assert_eq!(metrics.by_repertoire.len(), 2);
+    assert_eq!((french.games_total, french.repertoire_edges), (1, 4));
+    assert_eq!((caro.games_total, caro.repertoire_edges), (2, 4));
+    // 1. e4 was already stored by the French game, so only c6, d4 and d5 are new edges.
+    assert_eq!(caro.opening_edges, 3);
+
+    let mut sum = RepertoireMetrics::default();
+    for entry in metrics.by_repertoire.values() {
+        sum.games_total += entry.games_total;
+        sum.opening_positions += entry.opening_positions;
+        sum.opening_edges += entry.opening_edges;
+        sum.repertoire_edges += entry.repertoire_edges;
+        sum.tactics += entry.tactics;
+        sum.conflicting_edges += entry.conflicting_edges;
+        sum.transposition_edges += entry.transposition_edges;
+    }
+    assert_eq!(sum, metrics.totals);
+}
@@ -0,0 +1,90 @@
+use std::sync::mpsc::{self, Receiver, Sender};
+
+use crate::errors::{AdapterResult, QuizError};
+use crate::ports::{FeedbackMessage, PromptContext, QuizPort};
+use crate::state::QuizSummary;
+
+/// Message sent from a [`ChannelPort`] to the frontend that owns the other channel ends.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PortEvent {
+    /// The engine is waiting for a SAN response to this prompt.
+    Prompt(PromptContext),
+    /// Outcome of the most recent attempt.
+    Feedback(FeedbackMessage),
+    /// Final summary; no further events follow.
+    Summary(QuizSummary),
+}
+
+/// Adapter implementing [`QuizPort`] over `std::sync::mpsc` channels.
+///
+/// Lets the engine run on a worker thread while a GUI thread renders [`PortEvent`]s and
+/// answers each [`PortEvent::Prompt`] with one SAN string. A disconnected channel in either
+/// direction surfaces as [`QuizError::Io`].
+#[derive(Debug)]
+pub struct ChannelPort {
+    events: Sender<PortEvent>,
+    responses: Receiver<String>,
+}
+
+impl ChannelPort {
+    /// Creates a port from an event sender and a response receiver.
+    #[must_use]
+    pub fn new(events: Sender<PortEvent>, responses: Receiver<String>) -> Self {
+        Self { events, responses }
+    }
+
+    /// Creates a port together with the frontend's ends of both channels: the receiver for
+    /// [`PortEvent`]s and the sender for SAN responses.
+    #[must_use]
+    pub fn connected() -> (Self, Receiver<PortEvent>, Sender<String>) {
+        let (event_tx, event_rx) = mpsc::channel();
+        let (response_tx, response_rx) = mpsc::channel();
+        (Self::new(event_tx, response_rx), event_rx, response_tx)
+    }
+
+    fn send(&self, event: PortEvent) -> AdapterResult<()> {
+        self.events.send(event).map_err(|_| QuizError::Io)
+    }
+}
+
+impl QuizPort for ChannelPort {
+    fn present_prompt(&mut self, context: PromptContext) -> AdapterResult<String> {
+        self.send(PortEvent::Prompt(context))?;
+        self.responses.recv().map_err(|_| QuizError::Io)
+    }
+
+    fn publish_feedback(&mut self, feedback: FeedbackMessage) -> AdapterResult<()> {
+        self.send(PortEvent::Feedback(feedback))
+    }
+
+    fn present_summary(&mut self, summary: &QuizSummary) -> AdapterResult<()> {
+        self.send(PortEvent::Summary(summary.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::QuizEngine;
+
+    #[test]
+    fn prompt_fails_with_io_when_responses_are_closed() {
+        let (mut port, events, responses) = ChannelPort::connected();
+        drop(responses);
+        let mut engine = QuizEngine::from_pgn("1. e4 *", 1).expect("valid PGN");
+
+        assert_eq!(engine.run(&mut port).expect_err("closed"), QuizError::Io);
+        assert!(matches!(events.recv(), Ok(PortEvent::Prompt(_))));
+    }
+
+    #[test]
+    fn send_fails_with_io_when_events_are_closed() {
+        let (mut port, events, _responses) = ChannelPort::connected();
+        drop(events);
+
+        assert_eq!(
+            port.present_summary(&QuizSummary::default()),
+            Err(QuizError::Io)
+        );
+    }
+}
@@ -1,20 +1,37 @@
-use std::io::{self, BufRead, BufReader, Write};
+use std::io::{self, BufRead, BufReader, IsTerminal, Write};
+
+use chrono::NaiveDate;
+use review_domain::Grade;
+use scheduler_core::{Card, CardKind, ReviewGrade, Scheduler, SchedulerError, SchedulerStore};
 
 use crate::errors::AdapterResult;
 use crate::ports::{FeedbackMessage, PromptContext, QuizPort};
-use crate::state::{AttemptResult, QuizSummary};
+use crate::state::{AttemptResult, QuizSummary, StepMetadata};
+
+const ANSI_GREEN: &str = "\x1b[32m";
+const ANSI_RED: &str = "\x1b[31m";
+const ANSI_YELLOW: &str = "\x1b[33m";
+const ANSI_RESET: &str = "\x1b[0m";
 
 /// Terminal-backed adapter implementing the [`QuizPort`] contract.
 pub struct TerminalPort<R, W> {
     reader: R,
     writer: W,
+    color: bool,
+    quiet: bool,
+    annotation_bullet: String,
+    annotation_width: Option<usize>,
 }
 
 impl TerminalPort<BufReader<io::Stdin>, io::Stdout> {
     /// Constructs a terminal port using standard input and output streams.
+    ///
+    /// Colour output is enabled only when standard output is attached to a terminal.
     #[must_use]
     pub fn new() -> Self {
-        Self::with_io(BufReader::new(io::stdin()), io::stdout())
+        let stdout = io::stdout();
+        let color = stdout.is_terminal();
+        Self::with_io(BufReader::new(io::stdin()), stdout).with_color(color)
     }
 }
 
@@ -26,9 +43,70 @@ impl Default for TerminalPort<BufReader<io::Stdin>, io::Stdout> {
 
 impl<R, W> TerminalPort<R, W> {
     /// Creates a terminal port from custom reader and writer handles.
+    ///
+    /// Colour output is disabled because arbitrary writers cannot be assumed to be terminals.
     #[must_use]
     pub fn with_io(reader: R, writer: W) -> Self {
-        Self { reader, writer }
+        Self {
+            reader,
+            writer,
+            color: false,
+            quiet: false,
+            annotation_bullet: "- ".to_string(),
+            annotation_width: None,
+        }
+    }
+
+    /// Enables or disables ANSI colour highlighting of result lines.
+    #[must_use]
+    pub fn with_color(mut self, enabled: bool) -> Self {
+        self.color = enabled;
+        self
+    }
+
+    /// Enables or disables quiet mode, which suppresses the move label, board, and metadata
+    /// lines.
+    #[must_use]
+    pub fn with_quiet(mut self, quiet: bool) -> Self {
+        self.quiet = quiet;
+        self
+    }
+
+    /// Sets the prefix written before each failure annotation (default `"- "`).
+    #[must_use]
+    pub fn with_annotation_bullet(mut self, bullet: impl Into<String>) -> Self {
+        self.annotation_bullet = bullet.into();
+        self
+    }
+
+    /// Wraps failure annotations at `width` columns, bullet included; `None` disables wrapping.
+    ///
+    /// Lines only break between words, so a single word longer than the width keeps its own
+    /// line. Continuation lines are indented to align with the text after the bullet.
+    #[must_use]
+    pub fn with_annotation_width(mut self, width: Option<usize>) -> Self {
+        self.annotation_width = width;
+        self
+    }
+
+    /// Returns `true` when result lines are wrapped in ANSI colour codes.
+    #[must_use]
+    pub fn color_enabled(&self) -> bool {
+        self.color
+    }
+
+    /// Returns `true` when board and metadata lines are suppressed.
+    #[must_use]
+    pub fn is_quiet(&self) -> bool {
+        self.quiet
+    }
+
+    fn paint(&self, text: &str, code: &str) -> String {
+        if self.color {
+            format!("{code}{text}{ANSI_RESET}")
+        } else {
+            text.to_string()
+        }
     }
 
     /// Consumes the port and returns the underlying I/O handles.
@@ -38,6 +116,57 @@ impl<R, W> TerminalPort<R, W> {
     }
 }
 
+impl<R, W: Write> TerminalPort<R, W> {
+    /// Writes each metadata extra as a `key: value` line, in key order.
+    fn write_extras(&mut self, metadata: &StepMetadata) -> io::Result<()> {
+        for (key, value) in &metadata.extras {
+            writeln!(self.writer, "{key}: {value}")?;
+        }
+        Ok(())
+    }
+
+    /// Writes one failure annotation using the configured bullet and wrap width.
+    fn write_annotation(&mut self, note: &str) -> io::Result<()> {
+        let Some(width) = self.annotation_width else {
+            return writeln!(self.writer, "{}{note}", self.annotation_bullet);
+        };
+        let indent = " ".repeat(self.annotation_bullet.chars().count());
+        let text_width = width.saturating_sub(indent.len());
+        for (index, line) in wrap_words(note, text_width).iter().enumerate() {
+            let prefix = if index == 0 {
+                self.annotation_bullet.as_str()
+            } else {
+                indent.as_str()
+            };
+            writeln!(self.writer, "{prefix}{line}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Greedily packs the words of `text` into lines of at most `width` characters.
+///
+/// Words are never split: one longer than `width` is placed on a line by itself. Empty text
+/// yields a single empty line so the bullet is still printed.
+fn wrap_words(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    for word in text.split_whitespace() {
+        let needed = current.chars().count() + 1 + word.chars().count();
+        if !current.is_empty() && needed > width {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+    }
+    if !current.is_empty() || lines.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
 impl<R, W> QuizPort for TerminalPort<R, W>
 where
     R: BufRead,
@@ -50,27 +179,39 @@ where
             context.display_index(),
             context.total_steps
         )?;
-        writeln!(self.writer, "Board FEN: {}", context.board_fen)?;
+        if !self.quiet {
+            writeln!(self.writer, "{}", context.move_label())?;
+            writeln!(self.writer, "Board FEN: {}", context.board_fen)?;
 
-        if let Some(step_id) = context.metadata.step_id.as_deref() {
-            writeln!(self.writer, "Step ID: {step_id}")?;
-        }
+            if let Some(step_id) = context.metadata.step_id.as_deref() {
+                writeln!(self.writer, "Step ID: {step_id}")?;
+            }
 
-        if let Some(card_ref) = context.metadata.card_ref.as_deref() {
-            writeln!(self.writer, "Card ref: {card_ref}")?;
-        }
+            if let Some(card_ref) = context.metadata.card_ref.as_deref() {
+                writeln!(self.writer, "Card ref: {card_ref}")?;
+            }
 
-        if !context.metadata.themes.is_empty() {
-            writeln!(
-                self.writer,
-                "Themes: {}",
-                context.metadata.themes.join(", ")
-            )?;
+            if !context.metadata.themes.is_empty() {
+                writeln!(
+                    self.writer,
+                    "Themes: {}",
+                    context.metadata.themes.join(", ")
+                )?;
+            }
+
+            self.write_extras(&context.metadata)?;
         }
 
         if let Some(previous) = context.previous_move_san.as_deref() {
             writeln!(self.writer, "Previous move: {previous}")?;
         }
+        if context.context_moves.len() > 1 {
+            writeln!(
+                self.writer,
+                "Recent moves: {}",
+                context.context_moves.join(" ")
+            )?;
+        }
 
         writeln!(self.writer, "Your move (SAN): {}", context.prompt_san)?;
 
@@ -93,35 +234,64 @@ where
 
     fn publish_feedback(&mut self, feedback: FeedbackMessage) -> AdapterResult<()> {
         match feedback.result {
+            AttemptResult::Correct
+                if feedback.continues_line() && !feedback.remaining_solutions.is_empty() =>
+            {
+                let line = self.paint("Correct!", ANSI_GREEN);
+                writeln!(
+                    self.writer,
+                    "{line} {} more solution(s) to find.",
+                    feedback.moves_remaining
+                )?;
+            }
+            AttemptResult::Correct if feedback.continues_line() => {
+                let line = self.paint("Correct!", ANSI_GREEN);
+                writeln!(
+                    self.writer,
+                    "{line} {} more move(s) in this line.",
+                    feedback.moves_remaining
+                )?;
+            }
             AttemptResult::Correct => {
-                writeln!(self.writer, "Correct!")?;
-                if let Some(step_id) = feedback.metadata.step_id.as_deref() {
-                    writeln!(self.writer, "Step ID: {step_id}")?;
-                }
-                if let Some(card_ref) = feedback.metadata.card_ref.as_deref() {
-                    writeln!(self.writer, "Card ref: {card_ref}")?;
+                let line = self.paint("Correct!", ANSI_GREEN);
+                writeln!(self.writer, "{line}")?;
+                if !self.quiet {
+                    if let Some(step_id) = feedback.metadata.step_id.as_deref() {
+                        writeln!(self.writer, "Step ID: {step_id}")?;
+                    }
+                    if let Some(card_ref) = feedback.metadata.card_ref.as_deref() {
+                        writeln!(self.writer, "Card ref: {card_ref}")?;
+                    }
+                    self.write_extras(&feedback.metadata)?;
                 }
                 for note in &feedback.annotations {
                     writeln!(self.writer, "Note: {note}")?;
                 }
             }
             AttemptResult::Pending => {
-                writeln!(self.writer, "Incorrect, try again.")?;
+                let line = self.paint("Incorrect, try again.", ANSI_YELLOW);
+                writeln!(self.writer, "{line}")?;
                 writeln!(
                     self.writer,
                     "Retries remaining: {}",
                     feedback.remaining_retries
                 )?;
+                if let Some(hint) = &feedback.hint {
+                    writeln!(self.writer, "Final try: the answer starts with {hint}")?;
+                }
 
                 if let Some(response) = &feedback.learner_response {
                     writeln!(self.writer, "Your answer: {response}")?;
                 }
-                if let Some(step_id) = feedback.metadata.step_id.as_deref() {
+                if !self.quiet
+                    && let Some(step_id) = feedback.metadata.step_id.as_deref()
+                {
                     writeln!(self.writer, "Step ID: {step_id}")?;
                 }
             }
             AttemptResult::Incorrect => {
-                writeln!(self.writer, "Incorrect.")?;
+                let line = self.paint("Incorrect.", ANSI_RED);
+                writeln!(self.writer, "{line}")?;
 
                 if let Some(response) = &feedback.learner_response {
                     writeln!(self.writer, "Your answer: {response}")?;
@@ -130,19 +300,29 @@ where
                 if !feedback.solution_san.is_empty() {
                     writeln!(self.writer, "Solution: {}", feedback.solution_san)?;
                 }
+                if !feedback.remaining_solutions.is_empty() {
+                    writeln!(
+                        self.writer,
+                        "Missed: {}",
+                        feedback.remaining_solutions.join(", ")
+                    )?;
+                }
 
                 if !feedback.annotations.is_empty() {
                     writeln!(self.writer, "Annotations:")?;
                     for note in &feedback.annotations {
-                        writeln!(self.writer, "- {note}")?;
+                        self.write_annotation(note)?;
                     }
                 }
 
-                if let Some(step_id) = feedback.metadata.step_id.as_deref() {
-                    writeln!(self.writer, "Step ID: {step_id}")?;
-                }
-                if let Some(card_ref) = feedback.metadata.card_ref.as_deref() {
-                    writeln!(self.writer, "Card ref: {card_ref}")?;
+                if !self.quiet {
+                    if let Some(step_id) = feedback.metadata.step_id.as_deref() {
+                        writeln!(self.writer, "Step ID: {step_id}")?;
+                    }
+                    if let Some(card_ref) = feedback.metadata.card_ref.as_deref() {
+                        writeln!(self.writer, "Card ref: {card_ref}")?;
+                    }
+                    self.write_extras(&feedback.metadata)?;
                 }
             }
         }
@@ -160,12 +340,247 @@ where
         writeln!(self.writer, "Correct: {}", summary.correct_answers)?;
         writeln!(self.writer, "Incorrect: {}", summary.incorrect_answers)?;
         writeln!(self.writer, "Retries used: {}", summary.retries_consumed)?;
+        if summary.solutions_expected > 0 {
+            writeln!(
+                self.writer,
+                "Solutions found: {}/{}",
+                summary.solutions_found, summary.solutions_expected
+            )?;
+        }
         self.writer.flush()?;
         Ok(())
     }
 }
 
+impl<R: BufRead, W: Write> TerminalPort<R, W> {
+    /// Runs a self-graded spaced-repetition review over `cards`, typically the day's due cards.
+    ///
+    /// Each card is shown with the due date every grade would give it, then a grade is read
+    /// (`0`-`4` or `again`/`hard`/`good`/`easy`, re-prompting on anything else) and passed to
+    /// [`Scheduler::review`]. Cards missing from the store are skipped, and the loop stops
+    /// early when input ends. Returns how many cards were graded.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QuizError::Io`](crate::QuizError::Io) when reading or writing fails.
+    pub fn run_reviews<S: SchedulerStore>(
+        &mut self,
+        scheduler: &mut Scheduler<S>,
+        cards: &[Card],
+        today: NaiveDate,
+    ) -> AdapterResult<usize> {
+        let mut reviewed = 0;
+        for (index, card) in cards.iter().enumerate() {
+            writeln!(
+                self.writer,
+                "\nCard {}/{}: {}",
+                index + 1,
+                cards.len(),
+                describe_card(card)
+            )?;
+            let preview = scheduler
+                .preview_intervals(card, today)
+                .map(|(grade, due)| format!("{} {due}", grade_label(grade)));
+            writeln!(self.writer, "Next due by grade: {}", preview.join(", "))?;
+
+            let Some(grade) = self.read_grade()? else {
+                break;
+            };
+            match scheduler.review(card.id, ReviewGrade::from(grade), today) {
+                Ok(outcome) => {
+                    reviewed += 1;
+                    writeln!(self.writer, "Next due: {}", outcome.card.state.due)?;
+                }
+                Err(SchedulerError::CardNotFound { .. }) => {
+                    let line = self.paint("Card is no longer stored; skipped.", ANSI_YELLOW);
+                    writeln!(self.writer, "{line}")?;
+                }
+                Err(SchedulerError::InvalidDate { earliest, .. }) => {
+                    let message = format!("Card cannot be reviewed before {earliest}; skipped.");
+                    let line = self.paint(&message, ANSI_YELLOW);
+                    writeln!(self.writer, "{line}")?;
+                }
+                Err(error) => {
+                    let line = self.paint(&format!("{error}; skipped."), ANSI_YELLOW);
+                    writeln!(self.writer, "{line}")?;
+                }
+            }
+        }
+
+        writeln!(self.writer, "\nReviewed {reviewed}/{} cards", cards.len())?;
+        self.writer.flush()?;
+        Ok(reviewed)
+    }
+
+    /// Reads grades until one parses, returning `None` at end of input.
+    fn read_grade(&mut self) -> AdapterResult<Option<Grade>> {
+        loop {
+            write!(self.writer, "Grade (0-4 or again/hard/good/easy)> ")?;
+            self.writer.flush()?;
+
+            let mut buffer = String::new();
+            if self.reader.read_line(&mut buffer)? == 0 {
+                return Ok(None);
+            }
+            if let Ok(grade) = buffer.parse::<Grade>() {
+                return Ok(Some(grade));
+            }
+            let line = self.paint("Unrecognised grade, try again.", ANSI_YELLOW);
+            writeln!(self.writer, "{line}")?;
+        }
+    }
+}
+
+fn describe_card(card: &Card) -> String {
+    let kind = match &card.kind {
+        CardKind::Opening(opening) => format!("opening {}", opening.parent_prefix),
+        CardKind::Tactic(_) => "tactic".to_string(),
+    };
+    format!(
+        "{kind} (due {}, interval {} day(s))",
+        card.state.due, card.state.interval_days
+    )
+}
+
+fn grade_label(grade: ReviewGrade) -> &'static str {
+    match grade {
+        ReviewGrade::Again => "again",
+        ReviewGrade::Hard => "hard",
+        ReviewGrade::Good => "good",
+        ReviewGrade::Easy => "easy",
+    }
+}
+
 /// Placeholder CLI adapter entry point for manual smoke tests.
 pub fn run() {
     eprintln!("quiz-core CLI adapter is not yet orchestrating a session");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scheduler_core::{
+        CardState, InMemoryStore, SchedulerConfig, SchedulerOpeningCard, new_card,
+    };
+    use std::io::Cursor;
+    use uuid::Uuid;
+
+    fn naive_date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).expect("valid date")
+    }
+
+    fn review_card(prefix: &str, today: NaiveDate) -> Card {
+        let mut card = new_card(
+            Uuid::new_v4(),
+            CardKind::Opening(SchedulerOpeningCard::new(prefix)),
+            today,
+            &SchedulerConfig::default(),
+        );
+        card.state.stage = CardState::Review;
+        card
+    }
+
+    fn failure_output(port: TerminalPort<Cursor<String>, Vec<u8>>, notes: Vec<String>) -> String {
+        let mut port = port;
+        port.publish_feedback(FeedbackMessage::failure(
+            0,
+            Some("Qh4".into()),
+            "Qh5+",
+            notes,
+            StepMetadata::default(),
+        ))
+        .expect("feedback output should succeed");
+        let (_, writer) = port.into_inner();
+        String::from_utf8(writer).expect("utf8")
+    }
+
+    #[test]
+    fn wrap_words_breaks_between_words_only() {
+        assert_eq!(
+            wrap_words("fork the king and queen", 10),
+            vec!["fork the", "king and", "queen"]
+        );
+        assert_eq!(
+            wrap_words("a Nxf7+!! sacrifice", 4),
+            vec!["a", "Nxf7+!!", "sacrifice"]
+        );
+        assert_eq!(wrap_words("", 10), vec![""]);
+    }
+
+    #[test]
+    fn annotations_use_the_configured_bullet() {
+        let port = TerminalPort::with_io(Cursor::new(String::new()), Vec::new())
+            .with_annotation_bullet("* ");
+        let output = failure_output(port, vec!["Fork the king".into()]);
+
+        assert!(output.contains("\n* Fork the king\n"));
+        assert!(!output.contains("- Fork the king"));
+    }
+
+    #[test]
+    fn long_annotations_wrap_with_an_aligned_indent() {
+        let port = TerminalPort::with_io(Cursor::new(String::new()), Vec::new())
+            .with_annotation_width(Some(20));
+        let output = failure_output(
+            port,
+            vec!["**Idea:** trade the dark-squared bishop early".into()],
+        );
+
+        assert!(
+            output
+                .contains("Annotations:\n- **Idea:** trade\n  the dark-squared\n  bishop early\n")
+        );
+    }
+
+    #[test]
+    fn run_reviews_grades_each_card_and_prints_the_next_due_date() {
+        let today = naive_date(2024, 1, 1);
+        let cards = vec![review_card("e4", today), review_card("d4", today)];
+        let mut scheduler = Scheduler::new(
+            InMemoryStore::from_cards(cards.clone()),
+            SchedulerConfig::default(),
+        );
+        let expected_due = scheduler.preview_intervals(&cards[1], today)[3].1;
+        let mut port = TerminalPort::with_io("0\nmaybe\neasy\n".as_bytes(), Vec::new());
+
+        let reviewed = port
+            .run_reviews(&mut scheduler, &cards, today)
+            .expect("review loop runs");
+
+        assert_eq!(reviewed, 2);
+        let (_, output) = port.into_inner();
+        let output = String::from_utf8(output).expect("utf8 output");
+        assert!(output.contains("Card 1/2: opening e4 (due 2024-01-01, interval 0 day(s))"));
+        assert!(output.contains("Next due by grade: again 2024-01-02, hard"));
+        assert!(output.contains("Unrecognised grade, try again."));
+        assert!(output.contains(&format!("Next due: {expected_due}")));
+        assert!(output.contains("Reviewed 2/2 cards"));
+        let store = scheduler.into_store();
+        let first = store.get_card(cards[0].id).expect("card stored");
+        assert_eq!(first.state.stage, CardState::Relearning);
+        assert_eq!(first.state.due, naive_date(2024, 1, 2));
+    }
+
+    #[test]
+    fn run_reviews_stops_at_end_of_input_and_skips_missing_cards() {
+        let today = naive_date(2024, 1, 1);
+        let stored = review_card("e4", today);
+        let missing = review_card("c4", today);
+        let cards = vec![missing, stored.clone()];
+        let mut scheduler = Scheduler::new(
+            InMemoryStore::from_cards([stored]),
+            SchedulerConfig::default(),
+        );
+        let mut port = TerminalPort::with_io("good\n".as_bytes(), Vec::new());
+
+        let reviewed = port
+            .run_reviews(&mut scheduler, &cards, today)
+            .expect("review loop runs");
+
+        assert_eq!(reviewed, 0);
+        let (_, output) = port.into_inner();
+        let output = String::from_utf8(output).expect("utf8 output");
+        assert!(output.contains("Card is no longer stored; skipped."));
+        assert!(output.contains("Reviewed 0/2 cards"));
+    }
+}
@@ -1,18 +1,66 @@
-use crate::errors::QuizResult;
-use crate::ports::{FeedbackMessage, PromptContext, QuizPort};
+use shakmaty::fen::Fen;
+use shakmaty::san::San;
+use shakmaty::{CastlingMode, Chess, EnPassantMode, Move, Position};
+
+use crate::errors::{QuizError, QuizResult};
+use crate::ports::{FeedbackMessage, QuizPort};
 use crate::source::QuizSource;
-use crate::state::{AttemptResult, QuizSession, QuizStep, QuizSummary};
+use crate::state::{
+    AttemptResult, QuizSession, QuizStep, QuizSummary, StepMetadata, canonical_fen,
+};
+
+/// Opt-in leniencies applied when grading a learner's move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GradingPolicy {
+    /// Accept a legal move that reaches the same position as the expected move, or, in a
+    /// multi-move line, a move from later in the line when playing the line in the learner's
+    /// order reaches the same final position (positions compared ignoring move clocks).
+    ///
+    /// The expected move is resolved leniently, so a solution whose capture marker was written
+    /// for a slightly different position still has a position to compare against. The step's
+    /// remaining `solution_line` is rewritten to the learner's moves so later prompts show the
+    /// board they actually reached.
+    pub accept_transpositions: bool,
+    /// Attach a [`FeedbackMessage::hint`] naming the first character of the expected move
+    /// to the retry feedback that leaves the learner one final attempt.
+    ///
+    /// Multi-solution steps never receive hints.
+    pub hint_on_final_retry: bool,
+}
 
 /// Orchestrates quiz sessions by coordinating prompts, retries, and summaries.
 pub struct QuizEngine {
     session: QuizSession,
+    policy: GradingPolicy,
+    io_retries: u8,
 }
 
 impl QuizEngine {
     /// Creates a new engine from an existing [`QuizSession`].
     #[must_use]
     pub fn new(session: QuizSession) -> Self {
-        Self { session }
+        Self {
+            session,
+            policy: GradingPolicy::default(),
+            io_retries: 0,
+        }
+    }
+
+    /// Replaces the grading policy, returning the updated engine.
+    #[must_use]
+    pub fn with_grading_policy(mut self, policy: GradingPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Retries each port call that fails with [`QuizError::Io`] up to `retries` more times
+    /// before giving up, returning the updated engine.
+    ///
+    /// The default of zero fails on the first error. Other errors are never retried.
+    #[must_use]
+    pub fn with_io_retries(mut self, retries: u8) -> Self {
+        self.io_retries = retries;
+        self
     }
 
     /// Builds an engine from a pre-parsed [`QuizSource`].
@@ -24,70 +72,102 @@ impl QuizEngine {
     /// Parses PGN text into a quiz engine ready to run.
     ///
     /// # Errors
-    /// Returns an error when the PGN text cannot be parsed into a valid quiz.
+    /// Same as [`QuizSession::from_pgn`].
     pub fn from_pgn(pgn: &str, max_retries: u8) -> QuizResult<Self> {
         Ok(Self::new(QuizSession::from_pgn(pgn, max_retries)?))
     }
 
+    /// Restores an engine from a JSON-serialised [`QuizSession`].
+    ///
+    /// # Errors
+    /// Returns [`QuizError::Parse`] for undecodable JSON and [`QuizError::InvalidStep`] when
+    /// a step is malformed.
+    pub fn from_json(json: &str) -> QuizResult<Self> {
+        Ok(Self::new(QuizSession::from_json(json)?))
+    }
+
     /// Runs the quiz using the supplied adapter port.
     ///
     /// # Errors
-    /// Propagates any adapter or grading errors encountered while running the quiz.
+    /// Propagates adapter errors unchanged, after any [`with_io_retries`](Self::with_io_retries)
+    /// attempts, and returns [`QuizError::InvalidStep`] when the
+    /// current step has no move to grade against. State is not advanced on error.
     pub fn run<P: QuizPort>(&mut self, port: &mut P) -> QuizResult<&QuizSummary> {
-        while !self.session.is_complete() {
+        self.run_dyn(port)
+    }
+
+    /// Runs the quiz like [`QuizEngine::run`] over a port chosen at runtime, such as a
+    /// `Box<dyn QuizPort>` picked by a plugin registry, without monomorphising per adapter.
+    ///
+    /// # Errors
+    /// Same as [`QuizEngine::run`].
+    pub fn run_dyn(&mut self, port: &mut dyn QuizPort) -> QuizResult<&QuizSummary> {
+        self.run_until(port, || true)
+    }
+
+    /// Runs the quiz like [`QuizEngine::run`], checking `should_continue` before each step.
+    ///
+    /// When the predicate returns `false` the quiz stops early: the summary, which counts only
+    /// completed steps, is presented and returned. A later call resumes at the next step.
+    ///
+    /// # Errors
+    /// Same as [`QuizEngine::run`].
+    pub fn run_until<P: QuizPort + ?Sized>(
+        &mut self,
+        port: &mut P,
+        mut should_continue: impl FnMut() -> bool,
+    ) -> QuizResult<&QuizSummary> {
+        while !self.session.is_complete() && should_continue() {
             self.process_current_step(port)?;
         }
 
-        port.present_summary(&self.session.summary)?;
+        let summary = &self.session.summary;
+        retry_io(self.io_retries, || port.present_summary(summary))?;
         Ok(&self.session.summary)
     }
 
-    fn process_current_step<P: QuizPort>(&mut self, port: &mut P) -> QuizResult<()> {
+    fn process_current_step<P: QuizPort + ?Sized>(&mut self, port: &mut P) -> QuizResult<()> {
         loop {
             let step_index = self.session.current_index;
             let total_steps = self.session.steps.len();
-            let previous_move = if step_index == 0 {
-                None
-            } else {
-                Some(self.session.steps[step_index - 1].solution_san.clone())
-            };
-
-            let (board_fen, prompt_san, remaining_retries, metadata) = {
-                let step = &self.session.steps[step_index];
-                (
-                    step.board_fen.clone(),
-                    step.prompt_san.clone(),
-                    step.attempt.remaining_retries(),
-                    step.metadata.clone(),
-                )
-            };
-
-            let context = PromptContext {
+            if self.session.steps[step_index]
+                .current_expected_move()
+                .is_none_or(|san| san.trim().is_empty())
+            {
+                return Err(QuizError::InvalidStep { index: step_index });
+            }
+            let previous_move = self.previous_move_san(step_index);
+            let context_moves = self.context_moves_san(step_index);
+            let step = &self.session.steps[step_index];
+            let context = step.to_prompt_context(
                 step_index,
                 total_steps,
-                board_fen,
-                prompt_san,
-                previous_move_san: previous_move,
-                remaining_retries,
-                metadata,
-            };
+                previous_move,
+                context_moves,
+                step.attempt.remaining_retries(),
+            );
 
-            let response = port.present_prompt(context)?;
+            let response = retry_io(self.io_retries, || port.present_prompt(context.clone()))?;
 
             let GradeOutcome {
                 feedback,
                 final_result,
             } = {
                 let step = &mut self.session.steps[step_index];
-                Self::grade_attempt(step_index, step, &response)
+                Self::grade_attempt(step_index, step, &response, self.policy)
             };
 
-            port.publish_feedback(feedback)?;
+            retry_io(self.io_retries, || port.publish_feedback(feedback.clone()))?;
 
             if let Some(result) = final_result {
-                let retries_used = self.session.steps[step_index].attempt.retries_used as usize;
+                let step = &self.session.steps[step_index];
+                let retries_used = step.attempt.total_retries_used();
                 self.session.summary.completed_steps += 1;
                 self.session.summary.retries_consumed += retries_used;
+                if step.is_multi_solution() {
+                    self.session.summary.solutions_found += step.attempt.found_solutions.len();
+                    self.session.summary.solutions_expected += step.solution_set.len();
+                }
 
                 match result {
                     AttemptResult::Correct => self.session.summary.correct_answers += 1,
@@ -103,18 +183,102 @@ impl QuizEngine {
         Ok(())
     }
 
+    /// Returns the SAN most recently played before the learner's next entry.
+    ///
+    /// Within a multi-move line this is the last accepted move; otherwise it is the
+    /// final move leading into the step.
+    fn previous_move_san(&self, step_index: usize) -> Option<String> {
+        self.recent_moves_san(step_index, 1).pop()
+    }
+
+    /// Returns up to [`QuizSession::context_moves`] SANs played before the learner's next
+    /// entry, oldest first.
+    fn context_moves_san(&self, step_index: usize) -> Vec<String> {
+        self.recent_moves_san(step_index, self.session.context_moves)
+    }
+
+    /// Returns up to `limit` SANs played before the learner's next entry, oldest first: the
+    /// accepted moves of the current line after the step's lead-in (see
+    /// [`QuizSession::lead_in_moves`]).
+    fn recent_moves_san(&self, step_index: usize, limit: usize) -> Vec<String> {
+        let step = &self.session.steps[step_index];
+        let accepted: Vec<String> = step
+            .expected_moves()
+            .into_iter()
+            .take(step.attempt.accepted_moves)
+            .map(str::to_string)
+            .collect();
+        let mut moves = self
+            .session
+            .lead_in_moves(step_index, limit.saturating_sub(accepted.len()));
+        moves.extend(accepted);
+        let skip = moves.len().saturating_sub(limit);
+        moves.split_off(skip)
+    }
+
     /// Advances to the next step once the current step completes.
     fn advance(&mut self) {
         self.session.current_index += 1;
     }
 
     /// Grades an attempt and returns the corresponding feedback message.
-    fn grade_attempt(step_index: usize, step: &mut QuizStep, response: &str) -> GradeOutcome {
+    fn grade_attempt(
+        step_index: usize,
+        step: &mut QuizStep,
+        response: &str,
+        policy: GradingPolicy,
+    ) -> GradeOutcome {
         let trimmed = response.trim().to_string();
         step.attempt.responses.push(trimmed.clone());
         let metadata = step.metadata.clone();
 
-        if san_matches(&trimmed, &step.solution_san) {
+        let board_fen = step.current_board_fen();
+        if step.is_multi_solution() {
+            return Self::grade_set_attempt(step_index, step, trimmed, &board_fen, metadata);
+        }
+        let expected = step.current_expected_move().unwrap_or_default();
+        let mut matched = san_matches_on_board(&trimmed, expected, &board_fen);
+        if !matched
+            && policy.accept_transpositions
+            && reaches_solution_position(&trimmed, expected, &board_fen)
+        {
+            if !step.solution_line.is_empty() {
+                let accepted = step.attempt.accepted_moves;
+                step.solution_line[accepted].clone_from(&trimmed);
+            }
+            matched = true;
+        }
+        if !matched
+            && policy.accept_transpositions
+            && let Some(reordered) = transposed_line(step, &trimmed, &board_fen)
+        {
+            let accepted = step.attempt.accepted_moves;
+            let line = step
+                .expected_moves()
+                .into_iter()
+                .take(accepted)
+                .map(str::to_string)
+                .chain(reordered)
+                .collect::<Vec<_>>();
+            *step = step.clone().with_solution_line(line);
+            matched = true;
+        }
+        if matched {
+            let moves_remaining = step.moves_remaining_after_current();
+            if moves_remaining > 0 {
+                step.attempt.accept_line_move();
+                return GradeOutcome {
+                    feedback: FeedbackMessage::line_progress(
+                        step_index,
+                        trimmed,
+                        step.attempt.remaining_retries(),
+                        moves_remaining,
+                        metadata,
+                    ),
+                    final_result: None,
+                };
+            }
+
             step.attempt.result = AttemptResult::Correct;
             return GradeOutcome {
                 feedback: FeedbackMessage::success(
@@ -127,12 +291,21 @@ impl QuizEngine {
             };
         }
 
-        let remaining_before = step.attempt.remaining_retries();
-        if remaining_before > 0 {
+        if step.attempt.remaining_retries() > 0 {
             step.attempt.retries_used += 1;
             let remaining_after = step.attempt.remaining_retries();
+            let mut feedback =
+                FeedbackMessage::retry(step_index, trimmed, remaining_after, metadata);
+            if policy.hint_on_final_retry
+                && remaining_after == 0
+                && let Some(hint) = step
+                    .current_expected_move()
+                    .and_then(|san| san.chars().next())
+            {
+                feedback = feedback.with_hint(hint);
+            }
             return GradeOutcome {
-                feedback: FeedbackMessage::retry(step_index, trimmed, remaining, metadata),
+                feedback,
                 final_result: None,
             };
         }
@@ -150,6 +323,85 @@ impl QuizEngine {
         }
     }
 
+    /// Grades an attempt at a multi-solution step.
+    ///
+    /// A response matching a member not yet found is recorded; the step stays open until
+    /// every member is found. Wrong or repeated answers consume a retry, and once retries
+    /// run out the step fails, reporting the members that were missed.
+    fn grade_set_attempt(
+        step_index: usize,
+        step: &mut QuizStep,
+        response: String,
+        board_fen: &str,
+        metadata: StepMetadata,
+    ) -> GradeOutcome {
+        let found = step
+            .missing_solutions()
+            .into_iter()
+            .find(|san| san_matches_on_board(&response, san, board_fen))
+            .map(str::to_string);
+        if let Some(san) = found {
+            step.attempt.found_solutions.push(san);
+            let missing: Vec<String> = step
+                .missing_solutions()
+                .into_iter()
+                .map(str::to_string)
+                .collect();
+            if !missing.is_empty() {
+                return GradeOutcome {
+                    feedback: FeedbackMessage::set_progress(
+                        step_index,
+                        response,
+                        step.attempt.remaining_retries(),
+                        missing,
+                        metadata,
+                    ),
+                    final_result: None,
+                };
+            }
+
+            step.attempt.result = AttemptResult::Correct;
+            return GradeOutcome {
+                feedback: FeedbackMessage::success(
+                    step_index,
+                    response,
+                    step.annotations.clone(),
+                    metadata,
+                ),
+                final_result: Some(AttemptResult::Correct),
+            };
+        }
+
+        if step.attempt.remaining_retries() > 0 {
+            step.attempt.retries_used += 1;
+            let remaining_after = step.attempt.remaining_retries();
+            return GradeOutcome {
+                feedback: FeedbackMessage::retry(step_index, response, remaining_after, metadata),
+                final_result: None,
+            };
+        }
+
+        step.attempt.result = AttemptResult::Incorrect;
+        let missed = step
+            .missing_solutions()
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        GradeOutcome {
+            feedback: FeedbackMessage {
+                remaining_solutions: missed,
+                ..FeedbackMessage::failure(
+                    step_index,
+                    (!response.is_empty()).then_some(response),
+                    step.solution_san.clone(),
+                    step.annotations.clone(),
+                    metadata,
+                )
+            },
+            final_result: Some(AttemptResult::Incorrect),
+        }
+    }
+
     /// Provides read-only access to the underlying session for inspection.
     #[must_use]
     pub fn session(&self) -> &QuizSession {
@@ -162,6 +414,164 @@ struct GradeOutcome {
     final_result: Option<AttemptResult>,
 }
 
+/// Compares SAN tokens as moves on the given board so that any legal spelling matches.
+///
+/// Both sides are resolved through shakmaty, which accepts over-disambiguated forms such as
+/// `Rad1` as long as they identify a single legal move. Inputs that do not resolve (for example
+/// a capitalised `E4`) and boards or solutions that cannot be resolved fall back to the
+/// case-insensitive [`san_matches`], so an illegal string only matches its exact spelling.
+fn san_matches_on_board(input: &str, solution: &str, board_fen: &str) -> bool {
+    let Some(board) = parse_board(board_fen) else {
+        return san_matches(input, solution);
+    };
+    let Some(expected) = resolve_move(solution, &board) else {
+        return san_matches(input, solution);
+    };
+
+    match resolve_move(input, &board) {
+        Some(candidate) => candidate == expected,
+        None => san_matches(input, solution),
+    }
+}
+
+/// Plays the learner's move and the expected move on `board_fen` and reports whether both
+/// reach the same position by [`canonical_fen`].
+///
+/// The learner's move must be legal. The expected move is resolved with
+/// [`resolve_move_leniently`], so a solution written for a slightly different position can
+/// still be compared.
+fn reaches_solution_position(input: &str, solution: &str, board_fen: &str) -> bool {
+    let Some(board) = parse_board(board_fen) else {
+        return false;
+    };
+    let (Some(chosen), Some(intended)) = (
+        resolve_move(input, &board),
+        resolve_move_leniently(solution, &board),
+    ) else {
+        return false;
+    };
+    position_after(&board, chosen) == position_after(&board, intended)
+}
+
+/// Looks for a reordering of the step's remaining line that starts with the learner's move.
+///
+/// Only moves by the side to move are swapped, so the opponent's replies keep their slots. A
+/// reordering is accepted when every move stays legal and the final position matches the
+/// intended one by [`canonical_fen`]. Returns the reordered remaining moves.
+fn transposed_line(step: &QuizStep, input: &str, board_fen: &str) -> Option<Vec<String>> {
+    let board = parse_board(board_fen)?;
+    let chosen = resolve_move(input, &board)?;
+    let remaining: Vec<&str> = step
+        .expected_moves()
+        .into_iter()
+        .skip(step.attempt.accepted_moves)
+        .collect();
+    let target = replay_line(&board, &remaining)?;
+
+    (2..remaining.len()).step_by(2).find_map(|swap| {
+        let mut candidate = remaining.clone();
+        candidate.swap(0, swap);
+        let first = resolve_move(candidate[0], &board)?;
+        (first == chosen && replay_line(&board, &candidate)? == target)
+            .then(|| candidate.into_iter().map(str::to_string).collect())
+    })
+}
+
+/// Plays `moves` from `board` and returns the clock-free FEN of the final position.
+fn replay_line(board: &Chess, moves: &[&str]) -> Option<String> {
+    let mut position = board.clone();
+    for token in moves {
+        let mv = resolve_move(token, &position)?;
+        position.play_unchecked(mv);
+    }
+    Some(canonical_position(&position))
+}
+
+/// Returns the clock-free FEN of `board` after playing the legal move `mv`.
+fn position_after(board: &Chess, mv: Move) -> String {
+    let mut position = board.clone();
+    position.play_unchecked(mv);
+    canonical_position(&position)
+}
+
+fn canonical_position(position: &Chess) -> String {
+    canonical_fen(&Fen::from_position(position, EnPassantMode::Legal).to_string())
+}
+
+pub(crate) fn parse_board(board_fen: &str) -> Option<Chess> {
+    Fen::from_ascii(board_fen.as_bytes())
+        .ok()?
+        .into_position(CastlingMode::Standard)
+        .ok()
+}
+
+/// Lists every legal move in the position described by `board_fen` as SAN.
+///
+/// Moves are spelled the way [`QuizStep::solution_san`] is, without check suffixes, so the
+/// result can be used to build distractor options next to the real solution. Checkmate and
+/// stalemate positions yield an empty list.
+///
+/// # Errors
+///
+/// Returns [`QuizError::InvalidFen`] when `board_fen` does not describe a legal position.
+///
+/// # Examples
+/// ```rust
+/// use quiz_core::legal_moves_san;
+///
+/// let moves = legal_moves_san("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1")
+///     .expect("valid FEN");
+/// assert_eq!(moves.len(), 20);
+/// assert!(moves.contains(&"Nf3".to_string()));
+/// ```
+pub fn legal_moves_san(board_fen: &str) -> QuizResult<Vec<String>> {
+    let board: Chess = Fen::from_ascii(board_fen.as_bytes())
+        .map_err(|err| QuizError::InvalidFen(format!("{board_fen}: {err}")))?
+        .into_position(CastlingMode::Standard)
+        .map_err(|err| QuizError::InvalidFen(format!("{board_fen}: {err}")))?;
+    Ok(board
+        .legal_moves()
+        .iter()
+        .map(|mv| San::from_move(&board, *mv).to_string())
+        .collect())
+}
+
+fn resolve_move(token: &str, board: &Chess) -> Option<Move> {
+    let normalised = normalise_san(token)?;
+    San::from_ascii(normalised.as_bytes())
+        .ok()?
+        .to_move(board)
+        .ok()
+}
+
+/// Resolves `token` like [`resolve_move`], retrying with the capture marker toggled when the
+/// SAN does not match a legal move as written.
+fn resolve_move_leniently(token: &str, board: &Chess) -> Option<Move> {
+    resolve_move(token, board).or_else(|| {
+        let normalised = normalise_san(token)?;
+        match San::from_ascii(normalised.as_bytes()).ok()? {
+            San::Normal {
+                role,
+                file,
+                rank,
+                capture,
+                to,
+                promotion,
+            } => San::Normal {
+                role,
+                file,
+                rank,
+                capture: !capture,
+                to,
+                promotion,
+            }
+            .to_move(board)
+            .ok(),
+            _ => None,
+        }
+    })
+}
+
 fn san_matches(input: &str, solution: &str) -> bool {
     let Some(normalised_input) = normalise_san(input) else {
         return false;
@@ -173,34 +583,53 @@ fn san_matches(input: &str, solution: &str) -> bool {
     normalised_input.eq_ignore_ascii_case(&normalised_solution)
 }
 
+/// Trims `token`, strips check, annotation, and `e.p.` suffixes, and spells figurine piece
+/// symbols (`♘f3`) with their Latin letters (`Nf3`). Returns `None` when nothing is left.
 fn normalise_san(token: &str) -> Option<String> {
-    let trimmed = token.trim();
-    if trimmed.is_empty() {
-        return None;
-    }
-
-    let mut end = trimmed.len();
-    while end > 0 {
-        let ch = trimmed[..end].chars().next_back()?;
-        if matches!(ch, '+' | '#' | '!' | '?') {
-            end -= ch.len_utf8();
-        } else {
+    let mut trimmed = token.trim();
+    loop {
+        let stripped = trimmed
+            .trim_end_matches(['+', '#', '!', '?'])
+            .trim_end_matches("e.p.")
+            .trim_end();
+        if stripped.len() == trimmed.len() {
             break;
         }
+        trimmed = stripped;
     }
 
-    if end == 0 {
-        return None;
+    (!trimmed.is_empty()).then(|| trimmed.chars().map(latin_piece_letter).collect())
+}
+
+/// Maps a white or black figurine piece symbol to its SAN letter; other characters pass
+/// through unchanged.
+fn latin_piece_letter(symbol: char) -> char {
+    match symbol {
+        '♔' | '♚' => 'K',
+        '♕' | '♛' => 'Q',
+        '♖' | '♜' => 'R',
+        '♗' | '♝' => 'B',
+        '♘' | '♞' => 'N',
+        other => other,
     }
+}
 
-    Some(trimmed[..end].to_string())
+/// Calls `attempt` until it succeeds, retrying [`QuizError::Io`] up to `retries` more times.
+fn retry_io<T>(retries: u8, mut attempt: impl FnMut() -> QuizResult<T>) -> QuizResult<T> {
+    let mut remaining = retries;
+    loop {
+        match attempt() {
+            Err(QuizError::Io) if remaining > 0 => remaining -= 1,
+            result => return result,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::errors::QuizError;
-    use crate::ports::QuizPort;
+    use crate::ports::{PromptContext, QuizPort};
     use std::collections::VecDeque;
 
     struct FakePort {
@@ -211,6 +640,7 @@ mod tests {
         feedback_calls: usize,
         fail_feedback_after: Option<usize>,
         fail_summary: bool,
+        transient_failures: usize,
     }
 
     impl FakePort {
@@ -223,6 +653,7 @@ mod tests {
                 feedback_calls: 0,
                 fail_feedback_after: None,
                 fail_summary: false,
+                transient_failures: 0,
             }
         }
 
@@ -237,15 +668,34 @@ mod tests {
             port.fail_summary = true;
             port
         }
+
+        /// Fails the next `failures` port calls of any kind with [`QuizError::Io`].
+        fn flaky(responses: Vec<&str>, failures: usize) -> Self {
+            let mut port = Self::with_responses(responses);
+            port.transient_failures = failures;
+            port
+        }
+
+        fn take_transient_failure(&mut self) -> bool {
+            let failing = self.transient_failures > 0;
+            self.transient_failures = self.transient_failures.saturating_sub(1);
+            failing
+        }
     }
 
     impl QuizPort for FakePort {
         fn present_prompt(&mut self, context: PromptContext) -> Result<String, QuizError> {
+            if self.take_transient_failure() {
+                return Err(QuizError::Io);
+            }
             self.prompts.push(context);
             self.responses.pop_front().ok_or(QuizError::Io)
         }
 
         fn publish_feedback(&mut self, feedback: FeedbackMessage) -> Result<(), QuizError> {
+            if self.take_transient_failure() {
+                return Err(QuizError::Io);
+            }
             self.feedback_calls += 1;
 
             if let Some(threshold) = self.fail_feedback_after
@@ -259,7 +709,7 @@ mod tests {
         }
 
         fn present_summary(&mut self, summary: &QuizSummary) -> Result<(), QuizError> {
-            if self.fail_summary {
+            if self.fail_summary || self.take_transient_failure() {
                 return Err(QuizError::Io);
             }
 
@@ -268,6 +718,73 @@ mod tests {
         }
     }
 
+    #[test]
+    fn context_moves_carry_the_last_k_played_moves() {
+        let session = QuizSession::from_pgn("1. e4 e5 2. Nf3 Nc6 3. Bb5 *", 1)
+            .expect("PGN should parse")
+            .with_context_moves(3);
+        let mut engine = QuizEngine::new(session);
+        let mut port = FakePort::with_responses(vec!["e4", "e5", "Nf3", "Nc6", "Bb5"]);
+
+        engine.run(&mut port).expect("engine should complete");
+
+        let history: Vec<_> = port
+            .prompts
+            .iter()
+            .map(|prompt| prompt.context_moves.join(" "))
+            .collect();
+        assert_eq!(history, ["", "e4", "e4 e5", "e4 e5 Nf3", "e5 Nf3 Nc6"]);
+    }
+
+    #[test]
+    fn reordered_steps_keep_the_moves_that_led_to_their_board() {
+        let mut session = QuizSession::from_pgn("1. e4 e5 2. Nf3 Nc6 3. Bb5 *", 1)
+            .expect("PGN should parse")
+            .with_context_moves(2);
+        for (step, difficulty) in session.steps.iter_mut().zip([4, 2, 5, 1]) {
+            step.difficulty = Some(difficulty);
+        }
+        let mut engine = QuizEngine::new(session.ordered_by_difficulty());
+        let mut port = FakePort::with_responses(vec!["Nc6", "e5", "e4", "Nf3", "Bb5"]);
+
+        engine.run(&mut port).expect("engine should complete");
+
+        let prompts: Vec<_> = port
+            .prompts
+            .iter()
+            .map(|prompt| {
+                (
+                    prompt.prompt_san.as_str(),
+                    prompt.previous_move_san.as_deref(),
+                    prompt.context_moves.join(" "),
+                )
+            })
+            .collect();
+        assert_eq!(
+            prompts,
+            [
+                ("Nc6", Some("Nf3"), "e5 Nf3".to_string()),
+                ("e5", Some("e4"), "e4".to_string()),
+                ("e4", None, String::new()),
+                ("Nf3", Some("e5"), "e4 e5".to_string()),
+                ("Bb5", Some("Nc6"), "Nf3 Nc6".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn one_context_move_matches_the_previous_move() {
+        let mut engine = QuizEngine::from_pgn("1. e4 e5 2. Nf3 *", 1).expect("PGN should parse");
+        let mut port = FakePort::with_responses(vec!["e4", "e5", "Nf3"]);
+
+        engine.run(&mut port).expect("engine should complete");
+
+        for prompt in &port.prompts {
+            let previous: Vec<_> = prompt.previous_move_san.iter().cloned().collect();
+            assert_eq!(prompt.context_moves, previous);
+        }
+    }
+
     #[test]
     fn run_processes_correct_answers_and_publishes_summary() {
         let mut engine = QuizEngine::from_pgn("1. e4 e5 *", 1).expect("PGN should parse");
@@ -304,6 +821,65 @@ mod tests {
         );
     }
 
+    #[test]
+    fn boxed_trait_object_ports_drive_the_engine() {
+        let mut port: Box<dyn QuizPort> = Box::new(FakePort::with_responses(vec!["e4", "e5"]));
+        let mut engine = QuizEngine::from_pgn("1. e4 e5 *", 1).expect("PGN should parse");
+
+        let summary = engine
+            .run_dyn(port.as_mut())
+            .expect("engine should complete");
+        assert_eq!(summary.correct_answers, 2);
+
+        let mut port: Box<dyn QuizPort> = Box::new(FakePort::with_responses(vec!["d4", "e4"]));
+        let mut engine = QuizEngine::from_pgn("1. e4 *", 1).expect("PGN should parse");
+        let summary = engine.run(&mut port).expect("boxed ports are ports too");
+        assert_eq!(summary.correct_answers, 1);
+        assert_eq!(summary.retries_consumed, 1);
+    }
+
+    #[test]
+    fn run_until_stops_early_with_a_partial_summary() {
+        let mut engine = QuizEngine::from_pgn("1. e4 e5 2. Nf3 *", 1).expect("PGN should parse");
+        let mut port = FakePort::with_responses(vec!["e4", "d6", "e5", "Nf3"]);
+        let mut checks = 0;
+
+        let summary = engine
+            .run_until(&mut port, || {
+                checks += 1;
+                checks <= 2
+            })
+            .expect("engine should stop cleanly")
+            .clone();
+
+        assert_eq!(summary.total_steps, 3);
+        assert_eq!(summary.completed_steps, 2);
+        assert_eq!(summary.correct_answers, 2);
+        assert_eq!(summary.retries_consumed, 1);
+        assert_eq!(port.summary, Some(summary));
+        assert_eq!(port.prompts.len(), 3);
+        assert_eq!(engine.session().current_index, 2);
+        assert!(!engine.session().is_complete());
+
+        let resumed = engine.run(&mut port).expect("engine should resume");
+        assert_eq!(resumed.completed_steps, 3);
+        assert_eq!(resumed.correct_answers, 3);
+    }
+
+    #[test]
+    fn run_until_checks_the_predicate_before_the_first_step() {
+        let mut engine = QuizEngine::from_pgn("1. e4 *", 1).expect("PGN should parse");
+        let mut port = FakePort::with_responses(vec![]);
+
+        let summary = engine
+            .run_until(&mut port, || false)
+            .expect("engine should stop cleanly");
+
+        assert_eq!(summary.completed_steps, 0);
+        assert!(port.prompts.is_empty());
+        assert!(port.summary.is_some());
+    }
+
     #[test]
     fn engine_allows_single_retry_and_tracks_consumed_retries() {
         let mut engine = QuizEngine::from_pgn("1. e4 *", 1).expect("PGN should parse");
@@ -382,6 +958,49 @@ mod tests {
         assert_eq!(attempt.result, AttemptResult::Correct);
     }
 
+    #[test]
+    fn io_retries_ride_out_transient_port_failures() {
+        let mut engine = QuizEngine::from_pgn("1. e4 *", 1)
+            .expect("PGN should parse")
+            .with_io_retries(2);
+        let mut port = FakePort::flaky(vec!["e4"], 2);
+
+        let summary = engine.run(&mut port).expect("retries absorb the failures");
+
+        assert_eq!(summary.correct_answers, 1);
+        assert_eq!(port.prompts.len(), 1);
+        assert_eq!(port.feedback.len(), 1);
+        assert!(port.summary.is_some());
+    }
+
+    #[test]
+    fn io_retries_give_up_with_io_once_exhausted() {
+        let mut engine = QuizEngine::from_pgn("1. e4 *", 1)
+            .expect("PGN should parse")
+            .with_io_retries(1);
+        let mut port = FakePort::flaky(vec!["e4"], 2);
+
+        let error = engine
+            .run(&mut port)
+            .expect_err("two failures exceed one retry");
+
+        assert_eq!(error, QuizError::Io);
+        assert!(port.prompts.is_empty());
+        assert!(engine.session().steps[0].attempt.responses.is_empty());
+    }
+
+    #[test]
+    fn io_retries_repeat_failed_feedback_before_giving_up() {
+        let mut engine = QuizEngine::from_pgn("1. e4 *", 1)
+            .expect("PGN should parse")
+            .with_io_retries(3);
+        let mut port = FakePort::failing_feedback(vec!["e4"]);
+
+        assert_eq!(engine.run(&mut port), Err(QuizError::Io));
+        assert_eq!(port.feedback_calls, 4);
+        assert_eq!(engine.session().summary.completed_steps, 0);
+    }
+
     #[test]
     fn engine_preserves_summary_when_summary_delivery_fails() {
         let mut engine = QuizEngine::from_pgn("1. e4 e5 *", 1).expect("PGN should parse");
@@ -411,6 +1030,199 @@ mod tests {
         assert_eq!(attempt.responses, vec!["d4".to_string(), "E4".to_string()]);
     }
 
+    #[test]
+    fn engine_reports_invalid_step_without_prompting() {
+        let step = QuizStep::new(
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "e4",
+            "  ",
+            1,
+        );
+        let mut engine = QuizEngine::new(QuizSession::new(vec![step]));
+        let mut port = FakePort::with_responses(vec!["e4"]);
+
+        let error = engine
+            .run(&mut port)
+            .expect_err("blank solution cannot be graded");
+
+        assert_eq!(error, QuizError::InvalidStep { index: 0 });
+        assert!(port.prompts.is_empty());
+        assert_eq!(engine.session().current_index, 0);
+    }
+
+    #[test]
+    fn engine_round_trips_through_json() {
+        let engine = QuizEngine::from_pgn("1. e4 e5 *", 2).expect("PGN should parse");
+        let json = serde_json::to_string(engine.session()).expect("session serialises");
+
+        let mut restored = QuizEngine::from_json(&json).expect("JSON should restore");
+        assert_eq!(restored.session(), engine.session());
+
+        let mut port = FakePort::with_responses(vec!["e4", "e5"]);
+        let summary = restored.run(&mut port).expect("restored engine should run");
+        assert_eq!(summary.correct_answers, 2);
+    }
+
+    #[test]
+    fn engine_from_json_distinguishes_parse_and_step_errors() {
+        let parse = QuizEngine::from_json("{ not json")
+            .err()
+            .expect("parse error");
+        assert!(matches!(parse, QuizError::Parse(_)));
+
+        let mut session = QuizSession::from_pgn("1. e4 e5 *", 1).expect("PGN should parse");
+        session.steps[1].board_fen = "not a fen".into();
+        let json = serde_json::to_string(&session).expect("session serialises");
+        let invalid = QuizEngine::from_json(&json).err().expect("invalid step");
+        assert_eq!(invalid, QuizError::InvalidStep { index: 1 });
+    }
+
+    fn line_engine(max_retries: u8) -> QuizEngine {
+        let step = QuizStep::new(
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "Play the opening line",
+            "",
+            max_retries,
+        )
+        .with_solution_line(["e4", "e5", "Nf3"]);
+        QuizEngine::new(QuizSession::new(vec![step]))
+    }
+
+    #[test]
+    fn combined_lines_prompt_with_the_move_before_each_step_in_its_own_line() {
+        let open = QuizSource::from_pgn("1. e4 e5 2. Nf3 *").expect("valid PGN");
+        let italian = QuizSource::from_pgn("1. e4 e5 2. Bc4 *").expect("valid PGN");
+        let sources = [open, italian];
+
+        let previous_moves = |collapse: bool, answers: Vec<&str>| {
+            let mut engine = QuizEngine::new(QuizSession::from_sources(&sources, 1, collapse));
+            let mut port = FakePort::with_responses(answers);
+            engine.run(&mut port).expect("engine should complete");
+            port.prompts
+                .iter()
+                .map(|prompt| prompt.previous_move_san.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let joined = previous_moves(false, vec!["e4", "e5", "Nf3", "e4", "e5", "Bc4"]);
+        assert_eq!(joined[3], None);
+        assert_eq!(joined[5].as_deref(), Some("e5"));
+
+        let collapsed = previous_moves(true, vec!["e4", "e5", "Nf3", "Bc4"]);
+        assert_eq!(collapsed[2].as_deref(), Some("e5"));
+        assert_eq!(collapsed[3].as_deref(), Some("e5"));
+    }
+
+    #[test]
+    fn multi_move_step_prompts_for_each_move_with_updated_board() {
+        let mut engine = line_engine(1);
+        let mut port = FakePort::with_responses(vec!["e4", "e5", "Nf3"]);
+
+        let summary = engine.run(&mut port).expect("engine should complete");
+
+        assert_eq!(summary.completed_steps, 1);
+        assert_eq!(summary.correct_answers, 1);
+        assert_eq!(port.prompts.len(), 3);
+        assert!(port.prompts.iter().all(|prompt| prompt.step_index == 0));
+        assert_eq!(
+            port.prompts[1].board_fen,
+            "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1"
+        );
+        assert_eq!(
+            port.prompts[2].board_fen,
+            "rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 0 2"
+        );
+        assert_eq!(port.prompts[2].previous_move_san.as_deref(), Some("e5"));
+        assert_eq!(port.prompts[2].context_moves, ["e5"]);
+        assert_eq!(port.feedback[0].moves_remaining, 2);
+        assert_eq!(port.feedback[1].moves_remaining, 1);
+        assert!(!port.feedback[2].continues_line());
+        assert_eq!(port.feedback[2].result, AttemptResult::Correct);
+    }
+
+    #[test]
+    fn multi_move_step_applies_retries_per_sub_move() {
+        let mut engine = line_engine(1);
+        let mut port = FakePort::with_responses(vec!["d4", "e4", "c5", "e5", "Nf3"]);
+
+        let summary = engine.run(&mut port).expect("engine should complete");
+
+        assert_eq!(summary.correct_answers, 1);
+        assert_eq!(summary.retries_consumed, 2);
+        assert_eq!(port.prompts[2].remaining_retries, 1);
+        assert_eq!(engine.session().steps[0].attempt.total_retries_used(), 2);
+    }
+
+    #[test]
+    fn multi_move_step_fails_when_a_sub_move_exhausts_retries() {
+        let mut engine = line_engine(1);
+        let mut port = FakePort::with_responses(vec!["e4", "c5", "d5"]);
+
+        let summary = engine.run(&mut port).expect("engine should complete");
+
+        assert_eq!(summary.incorrect_answers, 1);
+        assert_eq!(summary.retries_consumed, 1);
+        let failure = port.feedback.last().expect("final feedback");
+        assert_eq!(failure.result, AttemptResult::Incorrect);
+        assert_eq!(failure.solution_san, "e4 e5 Nf3");
+    }
+
+    fn set_engine(max_retries: u8) -> QuizEngine {
+        let step = QuizStep::new(
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "Name every good first move",
+            "",
+            max_retries,
+        )
+        .with_solution_set(["e4", "d4", "Nf3"]);
+        QuizEngine::new(QuizSession::new(vec![step]))
+    }
+
+    #[test]
+    fn multi_solution_step_keeps_prompting_until_every_move_is_found() {
+        let mut engine = set_engine(1);
+        let mut port = FakePort::with_responses(vec!["Nf3", "e4", "d4"]);
+
+        let summary = engine.run(&mut port).expect("engine should complete");
+
+        assert_eq!(summary.correct_answers, 1);
+        assert_eq!(
+            (summary.solutions_found, summary.solutions_expected),
+            (3, 3)
+        );
+        assert_eq!(port.prompts.len(), 3);
+        assert!(
+            port.prompts
+                .iter()
+                .all(|prompt| prompt.board_fen == port.prompts[0].board_fen)
+        );
+        assert_eq!(port.feedback[0].remaining_solutions, vec!["e4", "d4"]);
+        assert_eq!(port.feedback[1].remaining_solutions, vec!["d4"]);
+        assert!(port.feedback[1].continues_line());
+        assert!(port.feedback[2].is_final());
+    }
+
+    #[test]
+    fn multi_solution_step_awards_partial_credit_when_retries_run_out() {
+        let mut engine = set_engine(1);
+        let mut port = FakePort::with_responses(vec!["e4", "e4", "c4"]);
+
+        let summary = engine.run(&mut port).expect("engine should complete");
+
+        assert_eq!(summary.incorrect_answers, 1);
+        assert_eq!(summary.retries_consumed, 1);
+        assert_eq!(
+            (summary.solutions_found, summary.solutions_expected),
+            (1, 3)
+        );
+        assert_eq!(summary.solution_credit(), Some(1.0 / 3.0));
+        let failure = port.feedback.last().expect("final feedback");
+        assert_eq!(failure.result, AttemptResult::Incorrect);
+        assert_eq!(failure.solution_san, "e4, d4, Nf3");
+        assert_eq!(failure.remaining_solutions, vec!["d4", "Nf3"]);
+        assert_eq!(engine.session().steps[0].attempt.wrong_attempts(), 2);
+    }
+
     #[test]
     fn san_matches_treats_equivalent_suffixes_as_identical_moves() {
         assert!(san_matches("Nf3+", "Nf3"));
@@ -425,4 +1237,235 @@ mod tests {
         assert!(!san_matches("Nf3+", "Nc3"));
         assert!(!san_matches("Bb5!!", "Bb4"));
     }
+
+    #[test]
+    fn figurine_and_latin_spellings_match_in_both_directions() {
+        assert!(san_matches("♘f3", "Nf3"));
+        assert!(san_matches("Nf3", "♞f3"));
+        assert!(san_matches("♕xe5#", "Qxe5"));
+        assert!(san_matches("axb8=♛", "axb8=Q"));
+        assert!(!san_matches("♗f3", "Nf3"));
+
+        let start = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+        assert!(san_matches_on_board("♘f3", "Nf3", start));
+        assert!(san_matches_on_board("Nf3", "♘f3", start));
+        assert!(san_matches_on_board("♘gf3", "Nf3", start));
+        assert!(!san_matches_on_board("♘c3", "Nf3", start));
+    }
+
+    #[test]
+    fn figurine_answers_are_graded_correct_by_the_engine() {
+        let mut engine = QuizEngine::from_pgn("1. Nf3 Nf6 2. c4 *", 1).expect("PGN should parse");
+        let mut port = FakePort::with_responses(vec!["♘f3", "♞f6", "c4"]);
+
+        let summary = engine.run(&mut port).expect("engine should complete");
+
+        assert_eq!(summary.correct_answers, 3);
+        assert_eq!(summary.retries_consumed, 0);
+    }
+
+    const ROOKS_ON_A1_AND_F1: &str = "4k3/8/8/8/8/8/8/R4RK1 w - - 0 1";
+    const EN_PASSANT_AVAILABLE: &str = "4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 2";
+
+    #[test]
+    fn board_matching_accepts_redundant_and_minimal_disambiguation() {
+        assert!(san_matches_on_board("Rad1", "Rad1", ROOKS_ON_A1_AND_F1));
+        assert!(san_matches_on_board("Ra1d1", "Rad1", ROOKS_ON_A1_AND_F1));
+        assert!(!san_matches_on_board("Rfd1", "Rad1", ROOKS_ON_A1_AND_F1));
+
+        let start = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+        assert!(san_matches_on_board("Ngf3", "Nf3", start));
+        assert!(san_matches_on_board("Nf3", "Ngf3", start));
+    }
+
+    #[test]
+    fn board_matching_accepts_en_passant_suffixes() {
+        assert!(san_matches_on_board(
+            "exd6",
+            "exd6 e.p.",
+            EN_PASSANT_AVAILABLE
+        ));
+        assert!(san_matches_on_board(
+            "exd6e.p.",
+            "exd6",
+            EN_PASSANT_AVAILABLE
+        ));
+        assert!(san_matches_on_board(
+            "exd6 e.p.+",
+            "exd6",
+            EN_PASSANT_AVAILABLE
+        ));
+    }
+
+    #[test]
+    fn board_matching_rejects_illegal_input_and_falls_back_without_board() {
+        assert!(!san_matches_on_board("Rd1", "Rad1", ROOKS_ON_A1_AND_F1));
+        assert!(!san_matches_on_board("Qd1", "Rad1", ROOKS_ON_A1_AND_F1));
+        assert!(!san_matches_on_board("", "Rad1", ROOKS_ON_A1_AND_F1));
+        assert!(san_matches_on_board("Nf3+", "Nf3", "not a fen"));
+    }
+
+    fn transposable_line_engine(policy: GradingPolicy) -> QuizEngine {
+        let step = QuizStep::new(
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "Play the Italian setup",
+            "",
+            0,
+        )
+        .with_solution_line(["e4", "e5", "Nf3", "Nc6", "Bc4"]);
+        QuizEngine::new(QuizSession::new(vec![step])).with_grading_policy(policy)
+    }
+
+    fn hinted_engine(policy: GradingPolicy) -> QuizEngine {
+        QuizEngine::from_pgn("1. Nf3 *", 2)
+            .expect("valid PGN")
+            .with_grading_policy(policy)
+    }
+
+    #[test]
+    fn retry_feedback_has_no_hint_by_default() {
+        let mut engine = hinted_engine(GradingPolicy::default());
+        let mut port = FakePort::with_responses(vec!["e4", "d4", "Nf3"]);
+
+        engine.run(&mut port).expect("engine should complete");
+
+        assert!(port.feedback.iter().all(|feedback| feedback.hint.is_none()));
+    }
+
+    #[test]
+    fn hint_accompanies_only_the_retry_before_the_final_attempt() {
+        let policy = GradingPolicy {
+            hint_on_final_retry: true,
+            ..GradingPolicy::default()
+        };
+        let mut engine = hinted_engine(policy);
+        let mut port = FakePort::with_responses(vec!["e4", "d4", "c4"]);
+
+        let summary = engine.run(&mut port).expect("engine should complete");
+
+        assert_eq!(port.feedback[0].hint, None);
+        assert_eq!(port.feedback[1].remaining_retries, 0);
+        assert_eq!(port.feedback[1].hint.as_deref(), Some("N"));
+        assert_eq!(port.feedback[2].result, AttemptResult::Incorrect);
+        assert_eq!(port.feedback[2].hint, None);
+        assert_eq!(summary.incorrect_answers, 1);
+    }
+
+    #[test]
+    fn transpositions_are_rejected_by_default() {
+        let mut engine = transposable_line_engine(GradingPolicy::default());
+        let mut port = FakePort::with_responses(vec!["e4", "e5", "Bc4"]);
+
+        let summary = engine.run(&mut port).expect("engine should complete");
+
+        assert_eq!(summary.incorrect_answers, 1);
+        assert_eq!(port.feedback[2].result, AttemptResult::Incorrect);
+    }
+
+    #[test]
+    fn transpositions_reaching_the_same_position_are_accepted_when_enabled() {
+        let policy = GradingPolicy {
+            accept_transpositions: true,
+            ..GradingPolicy::default()
+        };
+        let mut engine = transposable_line_engine(policy);
+        let mut port = FakePort::with_responses(vec!["e4", "e5", "Bc4", "Nc6", "Nf3"]);
+
+        let summary = engine.run(&mut port).expect("engine should complete");
+
+        assert_eq!(summary.correct_answers, 1);
+        assert_eq!(
+            port.prompts[3].board_fen,
+            "rnbqkbnr/pppp1ppp/8/4p3/2B1P3/8/PPPP1PPP/RNBQK1NR b KQkq - 1 2"
+        );
+        assert_eq!(
+            engine.session().steps[0].solution_line,
+            vec!["e4", "e5", "Bc4", "Nc6", "Nf3"]
+        );
+    }
+
+    #[test]
+    fn single_moves_reaching_the_solution_position_are_accepted_when_enabled() {
+        let after_e4_e5 = "rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 0 2";
+        // The capture marker was written for a different position, so "Nxf3" is not legal here.
+        let engine = |policy| {
+            QuizEngine::new(QuizSession::new(vec![QuizStep::new(
+                after_e4_e5,
+                "Develop the knight",
+                "Nxf3",
+                0,
+            )]))
+            .with_grading_policy(policy)
+        };
+
+        let mut strict = engine(GradingPolicy::default());
+        let mut port = FakePort::with_responses(vec!["Nf3"]);
+        let summary = strict.run(&mut port).expect("engine should complete");
+        assert_eq!(summary.incorrect_answers, 1);
+
+        let mut lenient = engine(GradingPolicy {
+            accept_transpositions: true,
+            ..GradingPolicy::default()
+        });
+        let mut port = FakePort::with_responses(vec!["Nf3"]);
+        let summary = lenient.run(&mut port).expect("engine should complete");
+        assert_eq!(summary.correct_answers, 1);
+
+        let mut lenient = engine(GradingPolicy {
+            accept_transpositions: true,
+            ..GradingPolicy::default()
+        });
+        let mut port = FakePort::with_responses(vec!["Nc3"]);
+        let summary = lenient.run(&mut port).expect("engine should complete");
+        assert_eq!(summary.incorrect_answers, 1);
+    }
+
+    #[test]
+    fn transpositions_must_reach_the_intended_position() {
+        let policy = GradingPolicy {
+            accept_transpositions: true,
+            ..GradingPolicy::default()
+        };
+        let step = QuizStep::new(
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "Play the line",
+            "",
+            0,
+        )
+        .with_solution_line(["e4", "e5", "Nf3"]);
+        let mut engine = QuizEngine::new(QuizSession::new(vec![step])).with_grading_policy(policy);
+        // d4 is legal but never part of the line, so no reordering reaches the target.
+        let mut port = FakePort::with_responses(vec!["d4"]);
+
+        let summary = engine.run(&mut port).expect("engine should complete");
+
+        assert_eq!(summary.incorrect_answers, 1);
+        assert_eq!(
+            engine.session().steps[0].solution_line,
+            vec!["e4", "e5", "Nf3"]
+        );
+    }
+
+    #[test]
+    fn legal_moves_san_lists_candidates_and_handles_terminal_positions() {
+        let session = QuizSession::from_pgn("1. e4 e5 2. Nf3 *", 1).expect("valid PGN");
+        let step = &session.steps[2];
+        let candidates = legal_moves_san(&step.board_fen).expect("valid board");
+        assert!(candidates.contains(&step.solution_san));
+        assert!(candidates.iter().all(|san| !san.ends_with('+')));
+
+        let fools_mate = "rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3";
+        assert_eq!(legal_moves_san(fools_mate), Ok(Vec::new()));
+        let stalemate = "7k/5Q2/6K1/8/8/8/8/8 b - - 0 1";
+        assert_eq!(legal_moves_san(stalemate), Ok(Vec::new()));
+
+        assert!(matches!(
+            legal_moves_san("not a fen"),
+            Err(QuizError::InvalidFen(message)) if message.starts_with("not a fen")
+        ));
+        assert!(matches!(
+            legal_moves_san("8/8/8/8/8/8/8/8 w - - 0 1"),
+            Err(QuizError::InvalidFen(_))
+        ));
+    }
 }
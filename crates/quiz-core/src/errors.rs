@@ -13,6 +13,8 @@ use thiserror::Error;
 /// - `VariationsUnsupported`: Raised when the PGN contains nested variations or alternate main lines.
 /// - `WrongFormat`: Raised when the PGN includes unsupported annotations or lacks a single main line.
 /// - `NoMoves`: Raised when a PGN entry parses but does not provide any playable moves.
+/// - `Parse(String)`: Raised when a serialised quiz session or a PGN move token cannot be decoded.
+/// - `InvalidStep { index }`: Raised when a step is illegal in its position or cannot be graded because its board or solution is malformed.
 /// - `Io`: Adapter-facing error for underlying I/O failures.
 ///
 /// # Examples
@@ -38,6 +40,19 @@ pub enum QuizError {
     /// Raised when a PGN entry parses but does not provide any playable moves.
     #[error("PGN did not contain any moves")]
     NoMoves,
+    /// Raised when a serialised quiz session or a PGN move token cannot be decoded.
+    #[error("failed to parse quiz session: {0}")]
+    Parse(String),
+    /// Raised when a step is illegal in its position or cannot be graded because its board
+    /// or solution is malformed.
+    #[error("quiz step {index} is invalid")]
+    InvalidStep {
+        /// Zero-based index of the offending step.
+        index: usize,
+    },
+    /// Raised when a FEN string cannot be parsed into a legal position.
+    #[error("invalid FEN: {0}")]
+    InvalidFen(String),
     /// Adapter-facing error for underlying I/O failures.
     #[error("I/O error")]
     Io,
@@ -55,6 +70,12 @@ impl From<io::Error> for QuizError {
     }
 }
 
+impl From<serde_json::Error> for QuizError {
+    fn from(err: serde_json::Error) -> Self {
+        QuizError::Parse(err.to_string())
+    }
+}
+
 impl From<ParseSanError> for QuizError {
     fn from(err: ParseSanError) -> Self {
         QuizError::UnreadablePgn(err.to_string())
@@ -67,30 +88,6 @@ impl From<SanError> for QuizError {
     }
 }
 
-impl QuizError {
-    pub(crate) fn unreadable_from_parse(token: impl Into<String>, err: &ParseSanError) -> Self {
-        let token = token.into();
-        let detail = err.to_string();
-
-        if token.is_empty() {
-            QuizError::UnreadablePgn(detail)
-        } else {
-            QuizError::UnreadablePgn(format!("{token}: {detail}"))
-        }
-    }
-
-    pub(crate) fn unreadable_from_san(token: impl Into<String>, err: &SanError) -> Self {
-        let token = token.into();
-        let detail = err.to_string();
-
-        if token.is_empty() {
-            QuizError::UnreadablePgn(detail)
-        } else {
-            QuizError::UnreadablePgn(format!("{token}: {detail}"))
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::QuizError;
@@ -120,4 +117,25 @@ mod tests {
 
         assert_eq!(quiz_error, QuizError::UnreadablePgn("ambiguous san".into()));
     }
+
+    #[test]
+    fn converts_json_error_into_parse_error() {
+        let json_error = serde_json::from_str::<u8>("nope").expect_err("invalid JSON");
+        let quiz_error: QuizError = json_error.into();
+
+        assert!(matches!(quiz_error, QuizError::Parse(ref detail) if detail.contains("expected")));
+    }
+
+    #[test]
+    fn new_variants_describe_the_failure() {
+        assert_eq!(
+            QuizError::Parse("bad".into()).to_string(),
+            "failed to parse quiz session: bad"
+        );
+        assert_eq!(
+            QuizError::InvalidStep { index: 2 }.to_string(),
+            "quiz step 2 is invalid"
+        );
+        assert_eq!(QuizError::Io.to_string(), "I/O error");
+    }
 }
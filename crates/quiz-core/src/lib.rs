@@ -2,21 +2,32 @@
 //!
 //! Modules and adapters are placeholders that will be implemented in later tasks.
 
+pub mod channel;
 pub mod engine;
 pub mod errors;
 pub mod ports;
 pub mod source;
 pub mod state;
 
-pub use engine::QuizEngine;
+pub use channel::{ChannelPort, PortEvent};
+pub use engine::{GradingPolicy, QuizEngine, legal_moves_san};
 pub use errors::{AdapterResult, QuizError, QuizResult};
 pub use ports::{FeedbackMessage, PromptContext, QuizPort};
 pub use source::QuizSource;
-pub use state::{AttemptResult, AttemptState, QuizSession, QuizStep, QuizSummary};
+pub use state::{
+    AttemptResult, AttemptState, QUIZ_SESSION_SCHEMA_VERSION, QuizSession, QuizStep, QuizSummary,
+    canonical_fen,
+};
 
 #[cfg(feature = "cli")]
 pub mod cli;
 
+#[cfg(feature = "svg")]
+pub mod svg;
+
+#[cfg(feature = "svg")]
+pub use svg::{BoardOrientation, render_board_svg, render_board_svg_with};
+
 #[cfg(feature = "api")]
 pub mod api;
 
@@ -30,6 +30,22 @@ pub trait QuizPort {
     fn present_summary(&mut self, summary: &QuizSummary) -> AdapterResult<()>;
 }
 
+/// Forwards to the boxed adapter, so a `Box<dyn QuizPort>` chosen at runtime can be passed
+/// wherever a [`QuizPort`] is expected.
+impl<P: QuizPort + ?Sized> QuizPort for Box<P> {
+    fn present_prompt(&mut self, context: PromptContext) -> AdapterResult<String> {
+        (**self).present_prompt(context)
+    }
+
+    fn publish_feedback(&mut self, feedback: FeedbackMessage) -> AdapterResult<()> {
+        (**self).publish_feedback(feedback)
+    }
+
+    fn present_summary(&mut self, summary: &QuizSummary) -> AdapterResult<()> {
+        (**self).present_summary(summary)
+    }
+}
+
 /// Context supplied to adapters when prompting for the next SAN move.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PromptContext {
@@ -39,22 +55,63 @@ pub struct PromptContext {
     pub total_steps: usize,
     /// FEN board snapshot that adapters can render before requesting input.
     pub board_fen: String,
+    /// Full-move number read from `board_fen`; `1` when the FEN or serialised prompt lacks one.
+    #[serde(default = "default_move_number")]
+    pub move_number: u32,
+    /// Side to move read from `board_fen` (`'w'` or `'b'`); `'w'` when the FEN or serialised
+    /// prompt lacks one.
+    #[serde(default = "default_side_to_move")]
+    pub side_to_move: char,
     /// SAN prompt communicated to the learner.
     pub prompt_san: String,
     /// Optional SAN of the immediately prior move.
     pub previous_move_san: Option<String>,
+    /// SANs of up to [`QuizSession::context_moves`](crate::QuizSession::context_moves)
+    /// preceding moves, oldest first. With one context move it holds `previous_move_san`.
+    #[serde(default)]
+    pub context_moves: Vec<String>,
     /// Number of retries remaining for the current step.
     pub remaining_retries: u8,
     /// Metadata describing the repertoire linkage and theme for the step.
     pub metadata: StepMetadata,
 }
 
+fn default_move_number() -> u32 {
+    1
+}
+
+fn default_side_to_move() -> char {
+    'w'
+}
+
 impl PromptContext {
     /// Returns the human-friendly (1-indexed) move number.
     #[must_use]
     pub fn display_index(&self) -> usize {
         self.step_index + 1
     }
+
+    /// Chess move label for the position, such as `12.` with White to move or `12...` with
+    /// Black to move, followed by the side in words.
+    #[must_use]
+    pub fn move_label(&self) -> String {
+        if self.side_to_move == 'b' {
+            format!("{}... (Black to move)", self.move_number)
+        } else {
+            format!("{}. (White to move)", self.move_number)
+        }
+    }
+
+    /// Returns a copy safe to share in a transcript: metadata identifiers are stripped and
+    /// `prompt_san`, which carries the expected move for PGN-sourced steps, is blanked.
+    #[must_use]
+    pub fn redacted(&self) -> Self {
+        Self {
+            prompt_san: String::new(),
+            metadata: self.metadata.redacted(),
+            ..self.clone()
+        }
+    }
 }
 
 /// Feedback delivered to adapters after an attempt is graded.
@@ -72,6 +129,15 @@ pub struct FeedbackMessage {
     pub annotations: Vec<String>,
     /// Remaining retries after processing the attempt.
     pub remaining_retries: u8,
+    /// Moves still to be entered when a correct answer continues a multi-move line.
+    #[serde(default)]
+    pub moves_remaining: usize,
+    /// Members of a multi-solution step's set not yet found; on failure, the ones missed.
+    #[serde(default)]
+    pub remaining_solutions: Vec<String>,
+    /// Partial reveal shown with retry feedback before the learner's final attempt.
+    #[serde(default)]
+    pub hint: Option<String>,
     /// Metadata describing the repertoire linkage and theme for the step.
     pub metadata: StepMetadata,
 }
@@ -92,10 +158,94 @@ impl FeedbackMessage {
             solution_san: String::new(),
             annotations,
             remaining_retries: 0,
+            moves_remaining: 0,
+            remaining_solutions: Vec::new(),
+            hint: None,
             metadata,
         }
     }
 
+    /// Convenience constructor for a correct move that continues a multi-move line.
+    ///
+    /// The step remains open until `moves_remaining` reaches zero.
+    #[must_use]
+    pub fn line_progress(
+        step_index: usize,
+        learner_response: impl Into<String>,
+        remaining_retries: u8,
+        moves_remaining: usize,
+        metadata: StepMetadata,
+    ) -> Self {
+        Self {
+            step_index,
+            result: AttemptResult::Correct,
+            learner_response: Some(learner_response.into()),
+            solution_san: String::new(),
+            annotations: Vec::new(),
+            remaining_retries,
+            moves_remaining,
+            remaining_solutions: Vec::new(),
+            hint: None,
+            metadata,
+        }
+    }
+
+    /// Convenience constructor for a correct move on a multi-solution step that still has
+    /// `remaining_solutions` to find.
+    #[must_use]
+    pub fn set_progress(
+        step_index: usize,
+        learner_response: impl Into<String>,
+        remaining_retries: u8,
+        remaining_solutions: Vec<String>,
+        metadata: StepMetadata,
+    ) -> Self {
+        Self {
+            moves_remaining: remaining_solutions.len(),
+            remaining_solutions,
+            ..Self::line_progress(step_index, learner_response, remaining_retries, 0, metadata)
+        }
+    }
+
+    /// Returns `true` when the step is over, either solved or out of retries.
+    #[must_use]
+    pub fn is_final(&self) -> bool {
+        match self.result {
+            AttemptResult::Correct => self.moves_remaining == 0,
+            AttemptResult::Incorrect => true,
+            AttemptResult::Pending => false,
+        }
+    }
+
+    /// Returns a copy safe to share in a transcript: metadata identifiers are stripped and
+    /// `solution_san`, `remaining_solutions`, and `hint` are blanked unless the message
+    /// [is final](Self::is_final).
+    #[must_use]
+    pub fn redacted(&self) -> Self {
+        let (solution_san, remaining_solutions, hint) = if self.is_final() {
+            (
+                self.solution_san.clone(),
+                self.remaining_solutions.clone(),
+                self.hint.clone(),
+            )
+        } else {
+            (String::new(), Vec::new(), None)
+        };
+        Self {
+            solution_san,
+            remaining_solutions,
+            hint,
+            metadata: self.metadata.redacted(),
+            ..self.clone()
+        }
+    }
+
+    /// Returns `true` when the feedback acknowledges a move but the step is not finished.
+    #[must_use]
+    pub fn continues_line(&self) -> bool {
+        self.result == AttemptResult::Correct && self.moves_remaining > 0
+    }
+
     /// Convenience constructor for incorrect attempts with remaining retries.
     #[must_use]
     pub fn retry(
@@ -111,10 +261,20 @@ impl FeedbackMessage {
             solution_san: String::new(),
             annotations: Vec::new(),
             remaining_retries,
+            moves_remaining: 0,
+            remaining_solutions: Vec::new(),
+            hint: None,
             metadata,
         }
     }
 
+    /// Attaches a partial reveal of the solution, returning the updated message.
+    #[must_use]
+    pub fn with_hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
     /// Convenience constructor for final incorrect attempts.
     #[must_use]
     pub fn failure(
@@ -131,6 +291,9 @@ impl FeedbackMessage {
             solution_san: solution_san.into(),
             annotations,
             remaining_retries: 0,
+            moves_remaining: 0,
+            remaining_solutions: Vec::new(),
+            hint: None,
             metadata,
         }
     }
@@ -140,6 +303,7 @@ impl FeedbackMessage {
 mod tests {
     use super::*;
     use crate::state::QuizSummary;
+    use std::collections::BTreeMap;
     use std::io::Cursor;
     use std::io::{self, Write};
 
@@ -151,13 +315,20 @@ mod tests {
             step_index: 0,
             total_steps: 2,
             board_fen: "8/8/8/8/8/8/8/8 w - - 0 1".into(),
+            move_number: 1,
+            side_to_move: 'w',
             prompt_san: "Qh5+".into(),
             previous_move_san: Some("Nc6".into()),
+            context_moves: vec!["Nc6".into()],
             remaining_retries: 1,
             metadata: StepMetadata {
                 step_id: Some("quiz-step-1".into()),
                 card_ref: Some("card-123".into()),
                 themes: vec!["attack".into(), "mate".into()],
+                extras: BTreeMap::from([
+                    ("difficulty".into(), "hard".into()),
+                    ("source".into(), "https://example.com/scholars-mate".into()),
+                ]),
             },
         }
     }
@@ -218,6 +389,54 @@ mod tests {
         assert_eq!(failure.annotations, vec!["skewer".to_string()]);
         assert_eq!(failure.remaining_retries, 0);
         assert_eq!(failure.metadata.step_id.as_deref(), Some("quiz-step-1"));
+
+        let progress = FeedbackMessage::line_progress(3, "e5", 1, 2, StepMetadata::default());
+        assert_eq!(progress.result, AttemptResult::Correct);
+        assert_eq!(progress.moves_remaining, 2);
+        assert!(progress.continues_line());
+        assert!(!success.continues_line());
+    }
+
+    #[test]
+    fn redacted_prompt_hides_identifiers_and_expected_move() {
+        let original = context();
+
+        let shared = original.redacted();
+
+        assert_eq!(original, context());
+        assert_eq!(shared.prompt_san, "");
+        assert_eq!(shared.metadata.step_id, None);
+        assert_eq!(shared.metadata.card_ref, None);
+        assert_eq!(shared.metadata.themes, original.metadata.themes);
+        assert_eq!(shared.metadata.extras, original.metadata.extras);
+        assert_eq!(shared.board_fen, original.board_fen);
+        assert_eq!(shared.previous_move_san, original.previous_move_san);
+    }
+
+    #[test]
+    fn redacted_feedback_reveals_solution_only_on_final_messages() {
+        let metadata = context().metadata;
+        let mut retry = FeedbackMessage::retry(0, "Qh4", 0, metadata.clone()).with_hint("Q");
+        retry.solution_san = "Qh5+".into();
+        let mut progress = FeedbackMessage::line_progress(0, "e4", 1, 2, metadata.clone());
+        progress.solution_san = "e4".into();
+        let failure = FeedbackMessage::failure(0, Some("Qh4".into()), "Qh5+", vec![], metadata);
+
+        assert!(!retry.is_final());
+        assert!(!progress.is_final());
+        assert!(failure.is_final());
+        assert_eq!(retry.redacted().solution_san, "");
+        assert_eq!(retry.redacted().hint, None);
+        assert_eq!(retry.hint.as_deref(), Some("Q"));
+        assert_eq!(progress.redacted().solution_san, "");
+        assert_eq!(failure.redacted().solution_san, "Qh5+");
+        assert_eq!(retry.solution_san, "Qh5+");
+        for shared in [retry.redacted(), failure.redacted()] {
+            assert_eq!(shared.metadata.step_id, None);
+            assert_eq!(shared.metadata.card_ref, None);
+            assert_eq!(shared.learner_response.as_deref(), Some("Qh4"));
+        }
+        assert_eq!(failure.metadata.card_ref.as_deref(), Some("card-123"));
     }
 
     #[test]
@@ -233,12 +452,43 @@ mod tests {
 
         let (_, writer) = port.into_inner();
         let output = String::from_utf8(writer).expect("utf8");
-        assert!(output.contains("Move 1/2"));
+        assert!(output.contains("Move 1/2\n1. (White to move)\n"));
         assert!(output.contains("Qh5+"));
         assert!(output.contains("Previous move: Nc6"));
         assert!(output.contains("Step ID: quiz-step-1"));
         assert!(output.contains("Card ref: card-123"));
         assert!(output.contains("Themes: attack, mate"));
+        assert!(output.contains("difficulty: hard\nsource: https://example.com/scholars-mate\n"));
+    }
+
+    #[test]
+    fn step_metadata_extras_serialise_with_prompts_and_feedback() {
+        let prompt = serde_json::to_value(context()).expect("prompt serialises");
+        assert_eq!(prompt["metadata"]["extras"]["difficulty"], "hard");
+
+        let feedback = FeedbackMessage::success(0, "Qh5+", Vec::new(), context().metadata);
+        let json = serde_json::to_string(&feedback).expect("feedback serialises");
+        let restored: FeedbackMessage = serde_json::from_str(&json).expect("feedback restores");
+        assert_eq!(restored.metadata.extras, context().metadata.extras);
+    }
+
+    #[test]
+    fn prompt_move_fields_default_when_missing_from_json() {
+        let mut prompt = serde_json::to_value(context()).expect("prompt serialises");
+        let fields = prompt.as_object_mut().expect("prompt is an object");
+        fields.remove("move_number");
+        fields.remove("side_to_move");
+
+        let restored: PromptContext = serde_json::from_value(prompt).expect("prompt restores");
+        assert_eq!(restored.move_number, 1);
+        assert_eq!(restored.side_to_move, 'w');
+
+        let black = PromptContext {
+            move_number: 12,
+            side_to_move: 'b',
+            ..context()
+        };
+        assert_eq!(black.move_label(), "12... (Black to move)");
     }
 
     #[test]
@@ -249,6 +499,7 @@ mod tests {
 
         let mut ctx = context();
         ctx.previous_move_san = None;
+        ctx.context_moves.clear();
         ctx.remaining_retries = 0;
 
         let response = port
@@ -263,6 +514,23 @@ mod tests {
         assert!(!output.contains("Retries remaining"));
     }
 
+    #[test]
+    fn terminal_port_renders_recent_moves_only_beyond_the_previous_move() {
+        let render = |context_moves: Vec<String>| {
+            let mut port = TerminalPort::with_io(Cursor::new("Qh5+\n"), Vec::new());
+            port.present_prompt(PromptContext {
+                context_moves,
+                ..context()
+            })
+            .expect("terminal prompt should succeed");
+            String::from_utf8(port.into_inner().1).expect("utf8")
+        };
+
+        let history = render(vec!["e4".into(), "e5".into(), "Nc6".into()]);
+        assert!(history.contains("Previous move: Nc6\nRecent moves: e4 e5 Nc6\n"));
+        assert!(!render(vec!["Nc6".into()]).contains("Recent moves"));
+    }
+
     #[test]
     fn terminal_port_renders_success_feedback() {
         let input = Cursor::new(String::new());
@@ -276,6 +544,10 @@ mod tests {
             solution_san: "Qh5+".into(),
             annotations: vec!["Classic Scholar's Mate pattern".into()],
             remaining_retries: 1,
+            moves_remaining: 0,
+            remaining_solutions: Vec::new(),
+            hint: None,
+            metadata: StepMetadata::canonical_for_index(0),
         };
 
         port.publish_feedback(message)
@@ -293,7 +565,7 @@ mod tests {
         let writer = Vec::new();
         let mut port = TerminalPort::with_io(input, writer);
 
-        let message = FeedbackMessage::retry(0, "Qh5", 0);
+        let message = FeedbackMessage::retry(0, "Qh5", 0, StepMetadata::default());
 
         port.publish_feedback(message)
             .expect("feedback output should succeed");
@@ -303,6 +575,23 @@ mod tests {
         assert!(output.contains("Incorrect, try again."));
         assert!(output.contains("Retries remaining: 0"));
         assert!(output.contains("Your answer: Qh5"));
+        assert!(!output.contains("Final try"));
+    }
+
+    #[test]
+    fn terminal_port_renders_retry_hint() {
+        let input = Cursor::new(String::new());
+        let writer = Vec::new();
+        let mut port = TerminalPort::with_io(input, writer);
+
+        let message = FeedbackMessage::retry(0, "Qh4", 0, StepMetadata::default()).with_hint("Q");
+
+        port.publish_feedback(message)
+            .expect("feedback output should succeed");
+
+        let (_, writer) = port.into_inner();
+        let output = String::from_utf8(writer).expect("utf8");
+        assert!(output.contains("Final try: the answer starts with Q"));
     }
 
     #[test]
@@ -318,6 +607,10 @@ mod tests {
             solution_san: "Qh5+".into(),
             annotations: vec![],
             remaining_retries: 0,
+            moves_remaining: 0,
+            remaining_solutions: Vec::new(),
+            hint: None,
+            metadata: StepMetadata::canonical_for_index(1),
         };
 
         port.publish_feedback(message)
@@ -335,8 +628,13 @@ mod tests {
         let writer = Vec::new();
         let mut port = TerminalPort::with_io(input, writer);
 
-        let message =
-            FeedbackMessage::failure(0, Some("Qh4".into()), "Qh5+", vec!["Fork the king".into()]);
+        let message = FeedbackMessage::failure(
+            0,
+            Some("Qh4".into()),
+            "Qh5+",
+            vec!["Fork the king".into()],
+            StepMetadata::default(),
+        );
 
         port.publish_feedback(message)
             .expect("feedback output should succeed");
@@ -347,6 +645,106 @@ mod tests {
         assert!(output.contains("- Fork the king"));
     }
 
+    #[test]
+    fn terminal_port_defaults_to_plain_output_for_custom_writers() {
+        let port = TerminalPort::with_io(Cursor::new(String::new()), Vec::new());
+        assert!(!port.color_enabled());
+        assert!(!port.is_quiet());
+
+        let mut port = port;
+        port.publish_feedback(FeedbackMessage::success(
+            0,
+            "Qh5+",
+            vec![],
+            StepMetadata::default(),
+        ))
+        .expect("feedback output should succeed");
+
+        let (_, writer) = port.into_inner();
+        let output = String::from_utf8(writer).expect("utf8");
+        assert!(output.contains("Correct!"));
+        assert!(!output.contains('\x1b'));
+    }
+
+    #[test]
+    fn terminal_port_colours_result_lines_when_enabled() {
+        let mut port =
+            TerminalPort::with_io(Cursor::new(String::new()), Vec::new()).with_color(true);
+
+        port.publish_feedback(FeedbackMessage::success(
+            0,
+            "Qh5+",
+            vec![],
+            StepMetadata::default(),
+        ))
+        .expect("feedback output should succeed");
+        port.publish_feedback(FeedbackMessage::retry(0, "Qh4", 1, StepMetadata::default()))
+            .expect("feedback output should succeed");
+        port.publish_feedback(FeedbackMessage::failure(
+            0,
+            Some("Qh4".into()),
+            "Qh5+",
+            vec![],
+            StepMetadata::default(),
+        ))
+        .expect("feedback output should succeed");
+
+        let (_, writer) = port.into_inner();
+        let output = String::from_utf8(writer).expect("utf8");
+        assert!(output.contains("\x1b[32mCorrect!\x1b[0m"));
+        assert!(output.contains("\x1b[33mIncorrect, try again.\x1b[0m"));
+        assert!(output.contains("\x1b[31mIncorrect.\x1b[0m"));
+        assert!(output.contains("Solution: Qh5+\n"));
+    }
+
+    #[test]
+    fn terminal_port_quiet_mode_suppresses_board_and_metadata() {
+        let mut port = TerminalPort::with_io(Cursor::new("Qh5+\n"), Vec::new()).with_quiet(true);
+
+        port.present_prompt(context())
+            .expect("terminal prompt should succeed");
+        port.publish_feedback(FeedbackMessage::failure(
+            0,
+            Some("Qh4".into()),
+            "Qh5+",
+            vec!["Fork the king".into()],
+            context().metadata,
+        ))
+        .expect("feedback output should succeed");
+
+        let (_, writer) = port.into_inner();
+        let output = String::from_utf8(writer).expect("utf8");
+        assert!(output.contains("Move 1/2"));
+        assert!(output.contains("Your move (SAN): Qh5+"));
+        assert!(output.contains("Solution: Qh5+"));
+        assert!(output.contains("- Fork the king"));
+        assert!(!output.contains("to move)"));
+        assert!(!output.contains("Board FEN"));
+        assert!(!output.contains("Step ID"));
+        assert!(!output.contains("Card ref"));
+        assert!(!output.contains("Themes"));
+        assert!(!output.contains("difficulty"));
+    }
+
+    #[test]
+    fn terminal_port_renders_line_progress_feedback() {
+        let mut port = TerminalPort::with_io(Cursor::new(String::new()), Vec::new());
+
+        port.publish_feedback(FeedbackMessage::line_progress(
+            0,
+            "e4",
+            1,
+            2,
+            StepMetadata::canonical_for_index(0),
+        ))
+        .expect("feedback output should succeed");
+
+        let (_, writer) = port.into_inner();
+        let output = String::from_utf8(writer).expect("utf8");
+        assert!(output.contains("Correct! 2 more move(s) in this line."));
+        assert!(!output.contains("Step ID"));
+    }
+
     #[test]
     fn terminal_port_prints_summary() {
         let input = Cursor::new(String::new());
@@ -359,6 +757,8 @@ mod tests {
             correct_answers: 1,
             incorrect_answers: 1,
             retries_consumed: 1,
+            solutions_found: 0,
+            solutions_expected: 0,
         };
 
         port.present_summary(&summary)
@@ -1,9 +1,11 @@
 #![allow(dead_code)]
 
+use chess_training_pgn_import::normalization::quality_glyph;
 use chess_training_pgn_import::parse_games;
 use shakmaty::san::{ParseSanError, San, SanError};
-use shakmaty::{Chess, Position};
+use shakmaty::{Chess, Move, Position};
 
+use crate::engine::parse_board;
 use crate::errors::{QuizError, QuizResult};
 use crate::state::StepMetadata;
 
@@ -16,6 +18,12 @@ pub struct QuizSource {
     pub san_moves: Vec<San>,
     /// Optional metadata captured alongside each SAN move.
     pub step_metadata: Vec<StepMetadata>,
+    /// Comments written directly after each SAN move, one entry per move.
+    pub annotations: Vec<Vec<String>>,
+    /// Board before each move when the moves are unrelated drills rather than one line.
+    ///
+    /// Empty for PGN sources, whose boards follow from `initial_position`.
+    pub step_positions: Vec<Chess>,
 }
 
 impl QuizSource {
@@ -32,10 +40,34 @@ impl QuizSource {
     ///
     /// # Errors
     ///
-    /// Returns a [`QuizError`] when the input includes multiple games, nested
-    /// variations, unsupported annotations, or SAN tokens that cannot be
-    /// converted into legal moves.
+    /// Returns [`QuizError::MultipleGames`], [`QuizError::VariationsUnsupported`], or
+    /// [`QuizError::NoMoves`] when the input is not a single playable main line,
+    /// [`QuizError::Parse`] for a token that is not SAN, and [`QuizError::InvalidStep`]
+    /// with the zero-based index of the first move that is illegal in its position.
+    ///
+    /// Brace (`{...}`) and line (`; ...`) comments are kept as the annotations of the move
+    /// they follow; comments before the first move are ignored.
     pub fn from_pgn(pgn: &str) -> QuizResult<Self> {
+        Self::from_pgn_with(pgn, false)
+    }
+
+    /// Parses PGN like [`QuizSource::from_pgn`], optionally keeping move-quality glyphs.
+    ///
+    /// With `retain_quality_glyphs` set, a `!`/`?` glyph written after a move (for example
+    /// the `!` of `Qxe4!`) becomes the first annotation of that move, ahead of its comments.
+    /// The SAN itself is always parsed without the glyph.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use quiz_core::QuizSource;
+    /// let source = QuizSource::from_pgn_with("1. e4! e5?! *", true).expect("valid PGN");
+    /// assert_eq!(source.annotations, vec![vec!["!".to_string()], vec!["?!".to_string()]]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Same as [`QuizSource::from_pgn`].
+    pub fn from_pgn_with(pgn: &str, retain_quality_glyphs: bool) -> QuizResult<Self> {
         let trimmed = pgn.trim();
         if trimmed.is_empty() {
             return Err(QuizError::NoMoves);
@@ -57,10 +89,6 @@ impl QuizSource {
             return Err(QuizError::VariationsUnsupported);
         }
 
-        if game.saw_comment_markers {
-            return Err(QuizError::WrongFormat);
-        }
-
         if game.tokens_after_result {
             return Err(QuizError::MultipleGames);
         }
@@ -72,13 +100,23 @@ impl QuizSource {
         let mut board = Chess::default();
         let initial_position = board.clone();
         let mut san_moves = Vec::new();
-        for cleaned in game.moves {
-            let san = San::from_ascii(cleaned.as_bytes()).map_err(|err: ParseSanError| {
-                QuizError::unreadable_from_parse(cleaned.clone(), &err)
-            })?;
-            let mv = san
-                .to_move(&board)
-                .map_err(|err: SanError| QuizError::unreadable_from_san(cleaned.clone(), &err))?;
+        let annotations = (0..game.moves.len())
+            .map(|index| {
+                let glyph = retain_quality_glyphs
+                    .then(|| game.raw_move(index).and_then(quality_glyph))
+                    .flatten();
+                glyph
+                    .into_iter()
+                    .chain(
+                        game.trailing_comments(index)
+                            .into_iter()
+                            .map(str::to_string),
+                    )
+                    .collect()
+            })
+            .collect();
+        for (index, cleaned) in game.moves.into_iter().enumerate() {
+            let (san, mv) = parse_legal_san(&cleaned, &board, index)?;
             board.play_unchecked(mv);
             san_moves.push(san);
         }
@@ -91,6 +129,53 @@ impl QuizSource {
             initial_position,
             san_moves,
             step_metadata: Vec::new(),
+            annotations,
+            step_positions: Vec::new(),
+        })
+    }
+
+    /// Builds a source from `(FEN, SAN solution)` pairs, one independent step per pair.
+    ///
+    /// Each step is quizzed on its own FEN rather than the board left by the previous
+    /// move, with no annotations and [`StepMetadata::canonical_for_index`] metadata.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use quiz_core::{QuizSession, QuizSource};
+    /// let start = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1".to_string();
+    /// let source = QuizSource::from_positions(vec![(start, "Nf3".to_string())])
+    ///     .expect("legal solution");
+    /// let session = QuizSession::from_source(&source, 1);
+    /// assert_eq!(session.steps[0].solution_san, "Nf3");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QuizError::NoMoves`] for an empty list, [`QuizError::Parse`] for a solution
+    /// that is not SAN, and [`QuizError::InvalidStep`] with the index of the first pair whose
+    /// FEN does not describe a legal position or whose solution is illegal there.
+    pub fn from_positions(pairs: Vec<(String, String)>) -> QuizResult<Self> {
+        if pairs.is_empty() {
+            return Err(QuizError::NoMoves);
+        }
+
+        let mut san_moves = Vec::with_capacity(pairs.len());
+        let mut step_positions = Vec::with_capacity(pairs.len());
+        for (index, (fen, solution)) in pairs.into_iter().enumerate() {
+            let board = parse_board(&fen).ok_or(QuizError::InvalidStep { index })?;
+            let (san, _) = parse_legal_san(solution.trim(), &board, index)?;
+            san_moves.push(san);
+            step_positions.push(board);
+        }
+
+        Ok(Self {
+            initial_position: step_positions[0].clone(),
+            step_metadata: (0..san_moves.len())
+                .map(StepMetadata::canonical_for_index)
+                .collect(),
+            annotations: vec![Vec::new(); san_moves.len()],
+            san_moves,
+            step_positions,
         })
     }
 
@@ -102,6 +187,19 @@ impl QuizSource {
     }
 }
 
+/// Parses `token` as SAN and resolves it to a legal move on `board`.
+///
+/// Unreadable tokens are [`QuizError::Parse`]; illegal moves are reported as
+/// [`QuizError::InvalidStep`] at `index`.
+fn parse_legal_san(token: &str, board: &Chess, index: usize) -> QuizResult<(San, Move)> {
+    let san = San::from_ascii(token.as_bytes())
+        .map_err(|err: ParseSanError| QuizError::Parse(format!("{token}: {err}")))?;
+    let mv = san
+        .to_move(board)
+        .map_err(|_: SanError| QuizError::InvalidStep { index })?;
+    Ok((san, mv))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -123,19 +221,48 @@ mod tests {
     }
 
     #[test]
-    fn rejects_annotations() {
-        let pgn = "1. e4 e5 { comment } 2. Nf3 Nc6 *";
-        let err = QuizSource::from_pgn(pgn).unwrap_err();
+    fn attaches_brace_comments_to_the_preceding_move() {
+        let pgn = "{Intro} 1. e4 e5 2. Qh5 {Scholar's mate threat} Nc6 *";
+        let source = QuizSource::from_pgn(pgn).expect("comments should be accepted");
+
+        assert_eq!(source.san_moves.len(), 4);
+        assert_eq!(
+            source.annotations,
+            vec![
+                Vec::<String>::new(),
+                Vec::new(),
+                vec!["Scholar's mate threat".to_string()],
+                Vec::new(),
+            ]
+        );
+    }
 
-        assert!(matches!(err, QuizError::WrongFormat));
+    #[test]
+    fn quality_glyphs_are_kept_ahead_of_comments_only_when_requested() {
+        let pgn = "1. e4 e5 2. Qh5?! {Early queen} Nc6 *";
+
+        let plain = QuizSource::from_pgn(pgn).expect("glyphs should be stripped");
+        assert_eq!(plain.annotations[2], vec!["Early queen".to_string()]);
+
+        let kept = QuizSource::from_pgn_with(pgn, true).expect("glyphs should be kept");
+        assert_eq!(kept.san_moves, plain.san_moves);
+        assert_eq!(
+            kept.annotations[2],
+            vec!["?!".to_string(), "Early queen".to_string()]
+        );
+        assert!(kept.annotations[3].is_empty());
     }
 
     #[test]
-    fn rejects_line_comments() {
+    fn line_comments_annotate_the_last_move_on_the_line() {
         let pgn = "1. e4 e5 ; sideline 2. Nf3 Nc6 *";
-        let err = QuizSource::from_pgn(pgn).unwrap_err();
+        let source = QuizSource::from_pgn(pgn).expect("line comments should be accepted");
 
-        assert!(matches!(err, QuizError::WrongFormat));
+        assert_eq!(source.san_moves.len(), 2);
+        assert_eq!(
+            source.annotations[1],
+            vec!["sideline 2. Nf3 Nc6 *".to_string()]
+        );
     }
 
     #[test]
@@ -167,6 +294,51 @@ mod tests {
         assert_eq!(moves, vec!["e4", "e5", "Nf3", "Nc6", "Bb5", "a6"],);
     }
 
+    #[test]
+    fn unreadable_tokens_are_parse_errors() {
+        let err = QuizSource::from_pgn("1. e4 e5 2. Zz9 *").unwrap_err();
+
+        assert_eq!(err, QuizError::Parse("Zz9: invalid san".into()));
+    }
+
+    #[test]
+    fn illegal_moves_report_their_step_index() {
+        let err = QuizSource::from_pgn("1. e4 e5 2. Ke3 Nc6 *").unwrap_err();
+
+        assert_eq!(err, QuizError::InvalidStep { index: 2 });
+    }
+
+    #[test]
+    fn from_positions_keeps_each_board_and_reports_the_bad_index() {
+        let start = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1".to_string();
+        let after_e4 = "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1".to_string();
+
+        let source = QuizSource::from_positions(vec![
+            (start.clone(), " e4 ".into()),
+            (after_e4.clone(), "c5".into()),
+        ])
+        .expect("legal pairs");
+        assert_eq!(source.initial_position, Chess::default());
+        assert_eq!(source.step_positions.len(), 2);
+        assert_eq!(source.annotations, vec![Vec::<String>::new(), Vec::new()]);
+        assert_eq!(
+            source.step_metadata[1],
+            StepMetadata::canonical_for_index(1)
+        );
+
+        let illegal_move =
+            QuizSource::from_positions(vec![(start.clone(), "e4".into()), (after_e4, "e4".into())]);
+        assert_eq!(illegal_move, Err(QuizError::InvalidStep { index: 1 }));
+        let bad_fen = QuizSource::from_positions(vec![("not a fen".into(), "e4".into())]);
+        assert_eq!(bad_fen, Err(QuizError::InvalidStep { index: 0 }));
+        let unreadable = QuizSource::from_positions(vec![(start, "Zz9".into())]);
+        assert_eq!(unreadable, Err(QuizError::Parse("Zz9: invalid san".into())));
+        assert_eq!(
+            QuizSource::from_positions(Vec::new()),
+            Err(QuizError::NoMoves)
+        );
+    }
+
     #[test]
     fn rejects_games_without_moves() {
         let err = QuizSource::from_pgn("*").unwrap_err();
@@ -1,11 +1,23 @@
 #![allow(dead_code)]
 
+use std::collections::BTreeMap;
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
-use crate::errors::QuizResult;
+use crate::errors::{QuizError, QuizResult};
+use crate::ports::PromptContext;
 use crate::source::QuizSource;
+use review_domain::{ChessPosition, Grade};
 use shakmaty::fen::Fen;
-use shakmaty::{EnPassantMode, Position};
+use shakmaty::san::San;
+use shakmaty::{CastlingMode, Chess, EnPassantMode, Position};
+
+/// Version of the serialised [`QuizSession`] layout written into `schema_version`.
+///
+/// Bump this whenever a change to the session, step, or summary structs would make
+/// previously persisted JSON decode into a different meaning.
+pub const QUIZ_SESSION_SCHEMA_VERSION: u32 = 1;
 
 /// Immutable snapshot of a learner's progress through a chess quiz.
 ///
@@ -20,6 +32,9 @@ pub struct StepMetadata {
     pub card_ref: Option<String>,
     /// Thematic tags that describe the tactical or strategic focus of the step.
     pub themes: Vec<String>,
+    /// Free-form content fields such as difficulty or source URL, keyed by name.
+    #[serde(default)]
+    pub extras: BTreeMap<String, String>,
 }
 
 impl StepMetadata {
@@ -32,6 +47,17 @@ impl StepMetadata {
         }
     }
 
+    /// Returns a copy without the internal `step_id` and `card_ref` identifiers, keeping
+    /// themes and extras.
+    #[must_use]
+    pub fn redacted(&self) -> Self {
+        Self {
+            step_id: None,
+            card_ref: None,
+            ..self.clone()
+        }
+    }
+
     /// Returns a copy of the metadata with theme tags sorted and deduplicated.
     #[must_use]
     pub fn normalised(mut self) -> Self {
@@ -43,12 +69,23 @@ impl StepMetadata {
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct QuizSession {
+    /// Serialisation layout version; sessions persisted before versioning decode as `0`.
+    #[serde(default)]
+    pub schema_version: u32,
     /// Ordered collection of prompts and attempts that make up the quiz.
     pub steps: Vec<QuizStep>,
     /// Index of the step currently presented to the learner.
     pub current_index: usize,
     /// Aggregate scoring and retry information captured as the quiz advances.
     pub summary: QuizSummary,
+    /// How many preceding moves each prompt carries in [`PromptContext::context_moves`].
+    /// Sessions persisted without it decode as `1`.
+    #[serde(default = "default_context_moves")]
+    pub context_moves: usize,
+}
+
+fn default_context_moves() -> usize {
+    1
 }
 
 impl QuizSession {
@@ -59,12 +96,30 @@ impl QuizSession {
         let summary = QuizSummary::new(steps.len());
 
         Self {
+            schema_version: QUIZ_SESSION_SCHEMA_VERSION,
             steps,
             current_index: 0,
             summary,
+            context_moves: default_context_moves(),
         }
     }
 
+    /// Sets how many preceding moves each prompt shows as context (default `1`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use quiz_core::QuizSession;
+    /// let session = QuizSession::from_pgn("1. e4 e5 2. Nf3 *", 1)
+    ///     .expect("valid PGN")
+    ///     .with_context_moves(4);
+    /// assert_eq!(session.context_moves, 4);
+    /// ```
+    #[must_use]
+    pub fn with_context_moves(mut self, moves: usize) -> Self {
+        self.context_moves = moves;
+        self
+    }
+
     /// Hydrates a new session from a parsed [`QuizSource`].
     ///
     /// # Parameters
@@ -79,6 +134,167 @@ impl QuizSession {
         Self::new(steps)
     }
 
+    /// Hydrates a session like [`QuizSession::from_source`], keeping only the first
+    /// `max_steps` moves so a long game becomes a short drill.
+    ///
+    /// `summary.total_steps` counts the kept steps. A cap at or above the number of moves
+    /// changes nothing, and a cap of `0` yields a session that is already complete.
+    ///
+    /// # Panics
+    /// Panics if `max_retries` is zero.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use quiz_core::{QuizSession, QuizSource};
+    /// let source = QuizSource::from_pgn("1. e4 e5 2. Nf3 Nc6 3. Bb5 *").expect("valid PGN");
+    /// let session = QuizSession::from_source_capped(&source, 1, 2);
+    /// assert_eq!(session.summary.total_steps, 2);
+    /// ```
+    #[must_use]
+    pub fn from_source_capped(source: &QuizSource, max_retries: u8, max_steps: usize) -> Self {
+        assert!(max_retries > 0, "max_retries must be greater than zero");
+        let mut steps = hydrate_steps(source, max_retries);
+        steps.truncate(max_steps);
+        Self::new(steps)
+    }
+
+    /// Hydrates one session from several lines, presented in the given order.
+    ///
+    /// Generated step ids continue across lines so they stay unique. When
+    /// `collapse_repeated_positions` is set the result is passed through
+    /// [`QuizSession::collapse_repeated_positions`]; leave it unset for ordered quizzes.
+    ///
+    /// # Panics
+    /// Panics if `max_retries` is zero.
+    #[must_use]
+    pub fn from_sources(
+        sources: &[QuizSource],
+        max_retries: u8,
+        collapse_repeated_positions: bool,
+    ) -> Self {
+        assert!(max_retries > 0, "max_retries must be greater than zero");
+        let mut steps = Vec::new();
+        for source in sources {
+            let first_index = steps.len();
+            let mut line = hydrate_steps_from(source, max_retries, first_index);
+            // Later lines start from their own opening position, not the previous line's end.
+            if let Some(first) = line.first_mut().filter(|_| first_index > 0) {
+                first.lead_in_moves = Some(Vec::new());
+            }
+            steps.extend(line);
+        }
+        let session = Self::new(steps);
+        if collapse_repeated_positions {
+            session.collapse_repeated_positions()
+        } else {
+            session
+        }
+    }
+
+    /// Drops steps whose position and solution already appeared earlier in the session.
+    ///
+    /// Positions are compared by [`canonical_fen`], so steps differing only in their move
+    /// clocks are treated as repeats. The first occurrence is kept, the summary totals are
+    /// rebuilt for the remaining steps, and the session restarts at the first step. A kept
+    /// step whose predecessor was dropped pins its line's moves in
+    /// [`QuizStep::lead_in_moves`], so its prompt context still follows its own line.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use quiz_core::QuizSession;
+    /// let open = quiz_core::QuizSource::from_pgn("1. e4 e5 2. Nf3 *").unwrap();
+    /// let other = quiz_core::QuizSource::from_pgn("1. e4 e5 2. Bc4 *").unwrap();
+    /// let session = QuizSession::from_sources(&[open, other], 1, true);
+    /// assert_eq!(session.summary.total_steps, 4);
+    /// ```
+    #[must_use]
+    pub fn collapse_repeated_positions(self) -> Self {
+        let lead_ins: Vec<Vec<String>> = (0..self.steps.len())
+            .map(|index| self.lead_in_moves(index, usize::MAX))
+            .collect();
+        let mut seen = std::collections::HashSet::new();
+        let mut previous_kept = true;
+        let mut steps = Vec::with_capacity(self.steps.len());
+        for (mut step, lead_in) in self.steps.into_iter().zip(lead_ins) {
+            if !seen.insert((canonical_fen(&step.board_fen), step.solution_san.clone())) {
+                previous_kept = false;
+                continue;
+            }
+            if !previous_kept {
+                step.lead_in_moves = Some(lead_in);
+            }
+            previous_kept = true;
+            steps.push(step);
+        }
+        Self::new(steps).with_context_moves(self.context_moves)
+    }
+
+    /// Reorders the steps from easiest to hardest by [`QuizStep::difficulty`].
+    ///
+    /// The sort is stable, and steps without a difficulty go last. Because a reordered step
+    /// no longer follows the move that led to its board, each step first pins its last
+    /// [`context_moves`](Self::context_moves) moves (at least one) in
+    /// [`QuizStep::lead_in_moves`]. The session restarts at the first step with fresh summary
+    /// totals. Sessions in which no step has a difficulty are returned unchanged.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use quiz_core::QuizSession;
+    /// let mut session = QuizSession::from_pgn("1. e4 e5 2. Nf3 *", 1).expect("valid PGN");
+    /// session.steps[0].difficulty = Some(3);
+    /// session.steps[2].difficulty = Some(1);
+    /// let ordered = session.ordered_by_difficulty();
+    /// let order: Vec<_> = ordered.steps.iter().map(|step| step.solution_san.as_str()).collect();
+    /// assert_eq!(order, ["Nf3", "e4", "e5"]);
+    /// assert_eq!(ordered.steps[0].lead_in_moves, Some(vec!["e5".to_string()]));
+    /// ```
+    #[must_use]
+    pub fn ordered_by_difficulty(self) -> Self {
+        if self.steps.iter().all(|step| step.difficulty.is_none()) {
+            return self;
+        }
+        let limit = self.context_moves.max(1);
+        let lead_ins: Vec<Vec<String>> = (0..self.steps.len())
+            .map(|index| self.lead_in_moves(index, limit))
+            .collect();
+        let mut steps: Vec<QuizStep> = self
+            .steps
+            .into_iter()
+            .zip(lead_ins)
+            .map(|(mut step, lead_in)| {
+                step.lead_in_moves = Some(lead_in);
+                step
+            })
+            .collect();
+        steps.sort_by_key(|step| (step.difficulty.is_none(), step.difficulty));
+        Self::new(steps).with_context_moves(self.context_moves)
+    }
+
+    /// Returns up to `limit` SANs played before `steps[step_index]` began, oldest first.
+    ///
+    /// Pinned [`QuizStep::lead_in_moves`] are used as they are; otherwise whole preceding
+    /// steps are walked back, stopping at a multi-solution step.
+    pub(crate) fn lead_in_moves(&self, step_index: usize, limit: usize) -> Vec<String> {
+        let mut history: Vec<&str> = Vec::new();
+        let mut index = step_index;
+        while history.len() < limit {
+            if let Some(pinned) = &self.steps[index].lead_in_moves {
+                history.extend(pinned.iter().rev().map(String::as_str));
+                break;
+            }
+            let Some(previous) = index.checked_sub(1).map(|previous| &self.steps[previous]) else {
+                break;
+            };
+            if previous.is_multi_solution() {
+                break;
+            }
+            history.extend(previous.expected_moves().into_iter().rev());
+            index -= 1;
+        }
+        history.truncate(limit);
+        history.into_iter().rev().map(str::to_string).collect()
+    }
+
     /// Parses PGN text directly into a [`QuizSession`].
     /// This is a convenience method that combines parsing and hydration.
     ///
@@ -92,13 +308,38 @@ impl QuizSession {
     /// ```
     ///
     /// # Errors
-    /// Returns a [`QuizError`] if the PGN cannot be parsed into a valid `QuizSource`.
+    /// Same as [`QuizSource::from_pgn`]: [`QuizError::Parse`] for unreadable move tokens and
+    /// [`QuizError::InvalidStep`] for the first illegal move.
     #[allow(clippy::result_large_err)]
     pub fn from_pgn(pgn: &str, max_retries: u8) -> QuizResult<Self> {
         let source = QuizSource::from_pgn(pgn)?;
         Ok(Self::from_source(&source, max_retries))
     }
 
+    /// Restores a session previously serialised to JSON.
+    ///
+    /// The `schema_version` must equal [`QUIZ_SESSION_SCHEMA_VERSION`], and every step is
+    /// validated so that malformed boards or empty solutions are reported up front rather
+    /// than when the engine reaches them.
+    ///
+    /// # Errors
+    /// Returns [`QuizError::Parse`] when the JSON cannot be decoded or was written with a
+    /// different schema version, and [`QuizError::InvalidStep`] for the first step whose
+    /// board FEN does not parse or whose solution is empty.
+    pub fn from_json(json: &str) -> QuizResult<Self> {
+        let session: Self = serde_json::from_str(json)?;
+        if session.schema_version != QUIZ_SESSION_SCHEMA_VERSION {
+            return Err(QuizError::Parse(format!(
+                "unsupported quiz session schema version {}; expected {QUIZ_SESSION_SCHEMA_VERSION}",
+                session.schema_version
+            )));
+        }
+        if let Some(index) = session.steps.iter().position(|step| !step.is_well_formed()) {
+            return Err(QuizError::InvalidStep { index });
+        }
+        Ok(session)
+    }
+
     /// Returns `true` when all steps have been attempted.
     ///
     /// This is used to determine if the quiz has been completed.
@@ -123,14 +364,41 @@ impl QuizSession {
     pub fn current_step(&self) -> Option<&QuizStep> {
         self.steps.get(self.current_index)
     }
+
+    /// Maps each [`StepMetadata::card_ref`] to the review grade earned by its finished steps,
+    /// so a scheduler can record one review per card.
+    ///
+    /// Grades come from [`AttemptState::to_grade`]; when several steps reference the same card
+    /// the worst grade wins. Steps without a `card_ref` and steps still pending are skipped.
+    #[must_use]
+    pub fn grade_summary_by_card(&self) -> BTreeMap<String, Grade> {
+        let mut grades: BTreeMap<String, Grade> = BTreeMap::new();
+        for step in &self.steps {
+            let (Some(card_ref), Some(grade)) = (&step.metadata.card_ref, step.attempt.to_grade())
+            else {
+                continue;
+            };
+            grades
+                .entry(card_ref.clone())
+                .and_modify(|worst| {
+                    if grade.to_u8() < worst.to_u8() {
+                        *worst = grade;
+                    }
+                })
+                .or_insert(grade);
+        }
+        grades
+    }
 }
 
 /// Encapsulates the context required to prompt the learner for a move.
 ///
 /// Each step stores the board position in Forsyth–Edwards Notation (FEN), the
 /// SAN move the learner is expected to supply, and the [`AttemptState`] tracking
-/// retries and responses. Optional annotations may be surfaced after the step
-/// completes so adapters can display coaching notes.
+/// retries and responses. Steps may instead carry an ordered `solution_line`
+/// when the learner must enter several moves in sequence, or a `solution_set` when
+/// the learner must find every move of the set in any order. Optional annotations
+/// may be surfaced after the step completes so adapters can display coaching notes.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct QuizStep {
     /// Board snapshot before the learner's move, serialised as a FEN string.
@@ -139,12 +407,26 @@ pub struct QuizStep {
     pub prompt_san: String,
     /// The canonical SAN solution revealed after a final attempt.
     pub solution_san: String,
+    /// Ordered SAN moves the learner must enter in turn; empty for single-move steps.
+    #[serde(default)]
+    pub solution_line: Vec<String>,
+    /// SAN moves that must all be found, in any order; empty unless the step is a
+    /// multi-solution step.
+    #[serde(default)]
+    pub solution_set: Vec<String>,
     /// Tracking state for learner attempts, retries, and captured responses.
     pub attempt: AttemptState,
     /// Optional annotations that accompany the step once graded.
     pub annotations: Vec<String>,
     /// Metadata that links the step back to repertoire records or themes.
     pub metadata: StepMetadata,
+    /// Optional difficulty rating; lower values are easier.
+    #[serde(default)]
+    pub difficulty: Option<u8>,
+    /// Moves played before this step's board, oldest first, pinned when the steps were
+    /// reordered. `None` means the preceding steps supply the move context.
+    #[serde(default)]
+    pub lead_in_moves: Option<Vec<String>>,
 }
 
 impl QuizStep {
@@ -163,9 +445,13 @@ impl QuizStep {
             board_fen: board_fen.into(),
             prompt_san: prompt_san.into(),
             solution_san: solution_san.into(),
+            solution_line: Vec::new(),
+            solution_set: Vec::new(),
             attempt: AttemptState::new(max_retries),
             annotations: Vec::new(),
             metadata: StepMetadata::default(),
+            difficulty: None,
+            lead_in_moves: None,
         }
     }
 
@@ -175,6 +461,174 @@ impl QuizStep {
         self.metadata = metadata;
         self
     }
+
+    /// Assigns the notes revealed alongside the solution, returning the updated instance.
+    #[must_use]
+    pub fn with_annotations(mut self, annotations: Vec<String>) -> Self {
+        self.annotations = annotations;
+        self
+    }
+
+    /// Assigns a difficulty rating used by [`QuizSession::ordered_by_difficulty`].
+    #[must_use]
+    pub fn with_difficulty(mut self, difficulty: u8) -> Self {
+        self.difficulty = Some(difficulty);
+        self
+    }
+
+    /// Turns the step into a multi-move drill that expects each SAN in `line` in order.
+    ///
+    /// The revealed `solution_san` becomes the space-separated line.
+    #[must_use]
+    pub fn with_solution_line<I, S>(mut self, line: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.solution_line = line.into_iter().map(Into::into).collect();
+        if !self.solution_line.is_empty() {
+            self.solution_san = self.solution_line.join(" ");
+        }
+        self
+    }
+
+    /// Turns the step into a multi-solution step that requires every SAN in `set`.
+    ///
+    /// Each move is played from `board_fen`, so the set lists alternative answers to the
+    /// same position rather than a line. The revealed `solution_san` becomes the
+    /// comma-separated set.
+    #[must_use]
+    pub fn with_solution_set<I, S>(mut self, set: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.solution_set = set.into_iter().map(Into::into).collect();
+        if !self.solution_set.is_empty() {
+            self.solution_san = self.solution_set.join(", ");
+        }
+        self
+    }
+
+    /// Returns `true` when the step requires every move of a `solution_set`.
+    #[must_use]
+    pub fn is_multi_solution(&self) -> bool {
+        !self.solution_set.is_empty()
+    }
+
+    /// Returns the members of the `solution_set` the learner has not found yet.
+    #[must_use]
+    pub fn missing_solutions(&self) -> Vec<&str> {
+        self.solution_set
+            .iter()
+            .filter(|san| !self.attempt.found_solutions.contains(san))
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Returns the SAN moves the learner must enter: the line in order, or every member
+    /// of a `solution_set`.
+    #[must_use]
+    pub fn expected_moves(&self) -> Vec<&str> {
+        if self.is_multi_solution() {
+            self.solution_set.iter().map(String::as_str).collect()
+        } else if self.solution_line.is_empty() {
+            vec![self.solution_san.as_str()]
+        } else {
+            self.solution_line.iter().map(String::as_str).collect()
+        }
+    }
+
+    /// Returns `true` when the board FEN parses and every expected move is non-empty.
+    #[must_use]
+    pub fn is_well_formed(&self) -> bool {
+        let board_parses = Fen::from_ascii(self.board_fen.as_bytes())
+            .ok()
+            .and_then(|fen| fen.into_position::<Chess>(CastlingMode::Standard).ok())
+            .is_some();
+        board_parses
+            && self
+                .expected_moves()
+                .iter()
+                .all(|san| !san.trim().is_empty())
+    }
+
+    /// Returns the SAN move expected for the learner's next entry.
+    #[must_use]
+    pub fn current_expected_move(&self) -> Option<&str> {
+        self.expected_moves()
+            .get(self.attempt.accepted_moves)
+            .copied()
+    }
+
+    /// Returns the number of moves still to be entered after the current one.
+    #[must_use]
+    pub fn moves_remaining_after_current(&self) -> usize {
+        self.expected_moves()
+            .len()
+            .saturating_sub(self.attempt.accepted_moves + 1)
+    }
+
+    /// Returns the board FEN after replaying every accepted move of the line.
+    ///
+    /// Falls back to the original `board_fen` when the snapshot or a move cannot be
+    /// replayed, so adapters always receive a displayable position.
+    #[must_use]
+    pub fn current_board_fen(&self) -> String {
+        let accepted = self.attempt.accepted_moves;
+        if accepted == 0 {
+            return self.board_fen.clone();
+        }
+
+        let Some(mut board) = Fen::from_ascii(self.board_fen.as_bytes())
+            .ok()
+            .and_then(|fen| fen.into_position::<Chess>(CastlingMode::Standard).ok())
+        else {
+            return self.board_fen.clone();
+        };
+
+        for token in self.expected_moves().into_iter().take(accepted) {
+            let Some(mv) = San::from_ascii(token.as_bytes())
+                .ok()
+                .and_then(|san| san.to_move(&board).ok())
+            else {
+                return self.board_fen.clone();
+            };
+            board.play_unchecked(mv);
+        }
+
+        Fen::from_position(&board, EnPassantMode::Legal).to_string()
+    }
+
+    /// Builds the adapter prompt for this step, carrying its board, prompt, and metadata.
+    #[must_use]
+    pub fn to_prompt_context(
+        &self,
+        step_index: usize,
+        total_steps: usize,
+        previous_move_san: Option<String>,
+        context_moves: Vec<String>,
+        remaining_retries: u8,
+    ) -> PromptContext {
+        let board_fen = self.current_board_fen();
+        // Boards from non-chess JSON sources fall back to the start of the game.
+        let (move_number, side_to_move) = ChessPosition::new(board_fen.clone(), 0)
+            .map_or((1, 'w'), |position| {
+                (position.move_number(), position.side_to_move)
+            });
+        PromptContext {
+            step_index,
+            total_steps,
+            board_fen,
+            move_number,
+            side_to_move,
+            prompt_san: self.prompt_san.clone(),
+            previous_move_san,
+            context_moves,
+            remaining_retries,
+            metadata: self.metadata.clone(),
+        }
+    }
 }
 
 /// Represents the current attempt status for a single quiz step.
@@ -191,6 +645,15 @@ pub struct AttemptState {
     pub retries_used: u8,
     /// History of SAN responses submitted by the learner.
     pub responses: Vec<String>,
+    /// Number of moves of a multi-move line already entered correctly.
+    #[serde(default)]
+    pub accepted_moves: usize,
+    /// Retries consumed on earlier moves of a multi-move line.
+    #[serde(default)]
+    pub prior_retries: usize,
+    /// Members of a multi-solution step's `solution_set` found so far.
+    #[serde(default)]
+    pub found_solutions: Vec<String>,
 }
 
 impl AttemptState {
@@ -202,6 +665,9 @@ impl AttemptState {
             retries_allowed: max_retries,
             retries_used: 0,
             responses: Vec::new(),
+            accepted_moves: 0,
+            prior_retries: 0,
+            found_solutions: Vec::new(),
         }
     }
 
@@ -210,6 +676,55 @@ impl AttemptState {
     pub fn remaining_retries(&self) -> u8 {
         self.retries_allowed.saturating_sub(self.retries_used)
     }
+
+    /// Total retries consumed across every move of the step.
+    #[must_use]
+    pub fn total_retries_used(&self) -> usize {
+        self.prior_retries + usize::from(self.retries_used)
+    }
+
+    /// Returns `true` when the step was ultimately answered correctly.
+    #[must_use]
+    pub fn was_correct(&self) -> bool {
+        self.result == AttemptResult::Correct
+    }
+
+    /// Counts the submitted responses that were graded as wrong.
+    #[must_use]
+    pub fn wrong_attempts(&self) -> usize {
+        let accepted = if self.found_solutions.is_empty() {
+            self.accepted_moves + usize::from(self.was_correct())
+        } else {
+            self.found_solutions.len()
+        };
+        self.responses.len().saturating_sub(accepted)
+    }
+
+    /// Review grade for a finished attempt: [`Grade::Three`] (good) when answered without a
+    /// retry, [`Grade::Two`] (hard) when retries were needed, and [`Grade::Zero`] (again) when
+    /// the step failed. Pending attempts have no grade.
+    #[must_use]
+    pub fn to_grade(&self) -> Option<Grade> {
+        match self.result {
+            AttemptResult::Pending => None,
+            AttemptResult::Incorrect => Some(Grade::Zero),
+            AttemptResult::Correct if self.total_retries_used() == 0 => Some(Grade::Three),
+            AttemptResult::Correct => Some(Grade::Two),
+        }
+    }
+
+    /// Returns the learner's first submitted response, if any.
+    #[must_use]
+    pub fn first_response(&self) -> Option<&str> {
+        self.responses.first().map(String::as_str)
+    }
+
+    /// Records an accepted line move and restores the retry budget for the next one.
+    pub fn accept_line_move(&mut self) {
+        self.accepted_moves += 1;
+        self.prior_retries += usize::from(self.retries_used);
+        self.retries_used = 0;
+    }
 }
 
 /// Final scoring summary produced once the session concludes.
@@ -228,6 +743,12 @@ pub struct QuizSummary {
     pub incorrect_answers: usize,
     /// Total number of retries consumed across all steps.
     pub retries_consumed: usize,
+    /// Solutions found across completed multi-solution steps.
+    #[serde(default)]
+    pub solutions_found: usize,
+    /// Solutions required across completed multi-solution steps.
+    #[serde(default)]
+    pub solutions_expected: usize,
 }
 
 impl QuizSummary {
@@ -239,6 +760,50 @@ impl QuizSummary {
             ..Self::default()
         }
     }
+
+    /// Adds every counter from `other` into this summary.
+    ///
+    /// [`QuizSummary::default`] is the identity, so a day's sessions can be folded into one
+    /// report starting from an empty summary.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use quiz_core::QuizSummary;
+    ///
+    /// let mut day = QuizSummary::default();
+    /// day.merge(&QuizSummary { total_steps: 4, correct_answers: 3, ..QuizSummary::default() });
+    /// day.merge(&QuizSummary { total_steps: 2, correct_answers: 1, ..QuizSummary::default() });
+    /// assert_eq!(day.total_steps, 6);
+    /// assert_eq!(day.correct_answers, 4);
+    /// ```
+    pub fn merge(&mut self, other: &QuizSummary) {
+        self.total_steps += other.total_steps;
+        self.completed_steps += other.completed_steps;
+        self.correct_answers += other.correct_answers;
+        self.incorrect_answers += other.incorrect_answers;
+        self.retries_consumed += other.retries_consumed;
+        self.solutions_found += other.solutions_found;
+        self.solutions_expected += other.solutions_expected;
+    }
+
+    /// Fractional credit (`found / expected`) earned on multi-solution steps.
+    ///
+    /// Returns `None` when no multi-solution step has been completed.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use quiz_core::QuizSummary;
+    ///
+    /// let summary = QuizSummary { solutions_found: 3, solutions_expected: 4, ..QuizSummary::default() };
+    /// assert_eq!(summary.solution_credit(), Some(0.75));
+    /// assert_eq!(QuizSummary::default().solution_credit(), None);
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn solution_credit(&self) -> Option<f64> {
+        (self.solutions_expected > 0)
+            .then(|| self.solutions_found as f64 / self.solutions_expected as f64)
+    }
 }
 
 /// Outcome state for a learner's attempt at a given quiz step.
@@ -252,22 +817,68 @@ pub enum AttemptResult {
     Incorrect,
 }
 
+impl AttemptResult {
+    /// Stable lowercase label (`"pending"`, `"correct"`, `"incorrect"`) for logs and adapters.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Correct => "correct",
+            Self::Incorrect => "incorrect",
+        }
+    }
+}
+
+impl fmt::Display for AttemptResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Returns the placement, side to move, castling, and en passant fields of a FEN.
+///
+/// The halfmove and fullmove clocks are dropped so that transpositions reached at
+/// different move numbers compare equal.
+///
+/// # Examples
+/// ```rust
+/// use quiz_core::canonical_fen;
+/// assert_eq!(
+///     canonical_fen("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1"),
+///     "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq -"
+/// );
+/// ```
+#[must_use]
+pub fn canonical_fen(fen: &str) -> String {
+    fen.split_whitespace().take(4).collect::<Vec<_>>().join(" ")
+}
+
 fn hydrate_steps(source: &QuizSource, max_retries: u8) -> Vec<QuizStep> {
+    hydrate_steps_from(source, max_retries, 0)
+}
+
+fn hydrate_steps_from(source: &QuizSource, max_retries: u8, first_index: usize) -> Vec<QuizStep> {
     let mut board = source.initial_position.clone();
     let mut steps = Vec::with_capacity(source.san_moves.len());
 
     for (index, san) in source.san_moves.iter().enumerate() {
+        if let Some(position) = source.step_positions.get(index) {
+            board = position.clone();
+        }
         let fen = Fen::from_position(&board, EnPassantMode::Legal).to_string();
         let san_text = san.to_string();
         let metadata = source
             .step_metadata
             .get(index)
             .cloned()
-            .unwrap_or_else(|| StepMetadata::canonical_for_index(index))
+            .unwrap_or_else(|| StepMetadata::canonical_for_index(first_index + index))
             .normalised();
 
-        let step =
-            QuizStep::new(fen, san_text.clone(), san_text, max_retries).with_metadata(metadata);
+        let annotations = source.annotations.get(index).cloned().unwrap_or_default();
+
+        let step = QuizStep::new(fen, san_text.clone(), san_text, max_retries)
+            .with_metadata(metadata)
+            .with_annotations(annotations);
         steps.push(step);
 
         let mv = san
@@ -294,6 +905,79 @@ mod tests {
         )
     }
 
+    #[test]
+    fn to_prompt_context_carries_step_fields_and_metadata() {
+        let metadata = StepMetadata {
+            step_id: Some("step-1".into()),
+            card_ref: None,
+            themes: vec!["fork".into()],
+            extras: BTreeMap::from([("difficulty".into(), "easy".into())]),
+        };
+        let step = sample_step(2).with_metadata(metadata.clone());
+
+        let context =
+            step.to_prompt_context(1, 3, Some("e5".into()), vec!["e4".into(), "e5".into()], 2);
+
+        assert_eq!(context.step_index, 1);
+        assert_eq!(context.total_steps, 3);
+        assert_eq!(context.board_fen, step.board_fen);
+        assert_eq!(context.move_number, 1);
+        assert_eq!(context.side_to_move, 'w');
+        assert_eq!(context.prompt_san, "?");
+        assert_eq!(context.previous_move_san.as_deref(), Some("e5"));
+        assert_eq!(context.context_moves, ["e4", "e5"]);
+        assert_eq!(context.remaining_retries, 2);
+        assert_eq!(context.metadata, metadata);
+    }
+
+    #[test]
+    fn move_number_and_side_follow_the_board_and_fall_back_to_the_start() {
+        let move_and_side = |fen: &str| {
+            let context =
+                QuizStep::new(fen, "e4", "e4", 1).to_prompt_context(0, 1, None, Vec::new(), 1);
+            (context.move_number, context.side_to_move)
+        };
+
+        assert_eq!(
+            move_and_side("r3k2r/pp1n1ppp/2p5/3pP3/8/2N5/PPP2PPP/R3K1NR b Kq - 0 12"),
+            (12, 'b')
+        );
+        assert_eq!(move_and_side("8/8/8/8/8/8/8/8"), (1, 'w'));
+        assert_eq!(move_and_side("8/8/8/8/8/8/8/8 b - - 0 x"), (1, 'w'));
+    }
+
+    #[test]
+    fn grade_summary_by_card_keeps_the_worst_grade_per_card() {
+        let finish = |card_ref: Option<&str>, result: AttemptResult, retries_used: u8| {
+            let mut step = sample_step(2).with_metadata(StepMetadata {
+                card_ref: card_ref.map(str::to_string),
+                ..StepMetadata::default()
+            });
+            step.attempt.result = result;
+            step.attempt.retries_used = retries_used;
+            step
+        };
+        let session = QuizSession::new(vec![
+            finish(Some("card-a"), AttemptResult::Correct, 0),
+            finish(Some("card-b"), AttemptResult::Correct, 0),
+            finish(Some("card-a"), AttemptResult::Correct, 1),
+            finish(Some("card-c"), AttemptResult::Incorrect, 2),
+            finish(None, AttemptResult::Incorrect, 2),
+            finish(Some("card-d"), AttemptResult::Pending, 0),
+        ]);
+
+        let grades = session.grade_summary_by_card();
+
+        assert_eq!(
+            grades,
+            BTreeMap::from([
+                ("card-a".to_string(), Grade::Two),
+                ("card-b".to_string(), Grade::Three),
+                ("card-c".to_string(), Grade::Zero),
+            ])
+        );
+    }
+
     #[test]
     fn session_initialises_summary_totals() {
         let steps = vec![sample_step(1), sample_step(2)];
@@ -305,6 +989,29 @@ mod tests {
         assert_eq!(session.summary.completed_steps, 0);
     }
 
+    #[test]
+    fn position_sources_hydrate_each_step_on_its_own_board() {
+        let start = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1".to_string();
+        let after_e4 = "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1".to_string();
+        let source = QuizSource::from_positions(vec![
+            (after_e4.clone(), "c5".into()),
+            (start.clone(), "d4".into()),
+        ])
+        .expect("legal pairs");
+
+        let session = QuizSession::from_source(&source, 2);
+        assert_eq!(session.steps.len(), 2);
+        assert_eq!(session.steps[0].board_fen, after_e4);
+        assert_eq!(session.steps[1].board_fen, start);
+        assert_eq!(session.steps[1].solution_san, "d4");
+        assert!(session.steps[1].annotations.is_empty());
+        assert_eq!(
+            session.steps[1].metadata,
+            StepMetadata::canonical_for_index(1)
+        );
+        assert_eq!(session.steps[1].attempt.retries_allowed, 2);
+    }
+
     #[test]
     fn attempt_state_tracks_remaining_retries() {
         let mut attempt = AttemptState::new(1);
@@ -314,6 +1021,49 @@ mod tests {
         assert_eq!(attempt.remaining_retries(), 0);
     }
 
+    #[test]
+    fn attempt_helpers_report_untouched_attempts() {
+        let attempt = AttemptState::new(1);
+
+        assert!(!attempt.was_correct());
+        assert_eq!(attempt.wrong_attempts(), 0);
+        assert_eq!(attempt.first_response(), None);
+    }
+
+    #[test]
+    fn attempt_helpers_report_success_after_one_retry() {
+        let mut attempt = AttemptState::new(1);
+        attempt.responses = vec!["d4".into(), "e4".into()];
+        attempt.retries_used = 1;
+        attempt.result = AttemptResult::Correct;
+
+        assert!(attempt.was_correct());
+        assert_eq!(attempt.wrong_attempts(), 1);
+        assert_eq!(attempt.first_response(), Some("d4"));
+    }
+
+    #[test]
+    fn attempt_helpers_report_exhausted_retries() {
+        let mut attempt = AttemptState::new(2);
+        attempt.responses = vec!["d4".into(), "c4".into(), "Nf3".into()];
+        attempt.retries_used = 2;
+        attempt.result = AttemptResult::Incorrect;
+
+        assert!(!attempt.was_correct());
+        assert_eq!(attempt.wrong_attempts(), 3);
+        assert_eq!(attempt.first_response(), Some("d4"));
+    }
+
+    #[test]
+    fn attempt_helpers_exclude_accepted_line_moves_from_wrong_attempts() {
+        let mut attempt = AttemptState::new(1);
+        attempt.responses = vec!["e4".into(), "c5".into(), "e5".into()];
+        attempt.accepted_moves = 1;
+        attempt.result = AttemptResult::Correct;
+
+        assert_eq!(attempt.wrong_attempts(), 1);
+    }
+
     #[test]
     fn quiz_step_initialises_attempt_state() {
         let step = sample_step(2);
@@ -326,6 +1076,39 @@ mod tests {
         assert!(step.metadata.themes.is_empty());
     }
 
+    #[test]
+    fn solution_line_drives_expected_moves_and_board_updates() {
+        let mut step = sample_step(1).with_solution_line(["e4", "e5", "Nf3"]);
+
+        assert_eq!(step.solution_san, "e4 e5 Nf3");
+        assert_eq!(step.expected_moves(), vec!["e4", "e5", "Nf3"]);
+        assert_eq!(step.current_expected_move(), Some("e4"));
+        assert_eq!(step.moves_remaining_after_current(), 2);
+        assert_eq!(step.current_board_fen(), step.board_fen);
+
+        step.attempt.retries_used = 1;
+        step.attempt.accept_line_move();
+        step.attempt.accept_line_move();
+
+        assert_eq!(step.current_expected_move(), Some("Nf3"));
+        assert_eq!(step.moves_remaining_after_current(), 0);
+        assert_eq!(step.attempt.retries_used, 0);
+        assert_eq!(step.attempt.total_retries_used(), 1);
+        assert_eq!(
+            step.current_board_fen(),
+            "rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 0 2"
+        );
+    }
+
+    #[test]
+    fn single_move_steps_expect_the_solution_san() {
+        let step = sample_step(1);
+
+        assert!(step.solution_line.is_empty());
+        assert_eq!(step.expected_moves(), vec!["e4"]);
+        assert_eq!(step.moves_remaining_after_current(), 0);
+    }
+
     #[test]
     fn summary_constructor_sets_totals() {
         let summary = QuizSummary::new(5);
@@ -337,6 +1120,102 @@ mod tests {
         assert_eq!(summary.retries_consumed, 0);
     }
 
+    #[test]
+    fn summary_merge_sums_counters_and_default_is_identity() {
+        let morning = QuizSummary {
+            total_steps: 4,
+            completed_steps: 4,
+            correct_answers: 3,
+            incorrect_answers: 1,
+            retries_consumed: 2,
+            solutions_found: 2,
+            solutions_expected: 3,
+        };
+        let evening = QuizSummary {
+            total_steps: 3,
+            completed_steps: 2,
+            correct_answers: 2,
+            incorrect_answers: 0,
+            retries_consumed: 1,
+            solutions_found: 1,
+            solutions_expected: 1,
+        };
+
+        let mut day = morning.clone();
+        day.merge(&evening);
+        assert_eq!(
+            day,
+            QuizSummary {
+                total_steps: 7,
+                completed_steps: 6,
+                correct_answers: 5,
+                incorrect_answers: 1,
+                retries_consumed: 3,
+                solutions_found: 3,
+                solutions_expected: 4,
+            }
+        );
+
+        let mut unchanged = morning.clone();
+        unchanged.merge(&QuizSummary::default());
+        assert_eq!(unchanged, morning);
+        let mut from_identity = QuizSummary::default();
+        from_identity.merge(&morning);
+        assert_eq!(from_identity, morning);
+    }
+
+    #[test]
+    fn summary_round_trips_through_json() {
+        let summary = QuizSummary {
+            total_steps: 5,
+            completed_steps: 4,
+            correct_answers: 3,
+            incorrect_answers: 1,
+            retries_consumed: 2,
+            solutions_found: 1,
+            solutions_expected: 2,
+        };
+
+        let json = serde_json::to_string(&summary).expect("summary serialises");
+        let restored: QuizSummary = serde_json::from_str(&json).expect("summary restores");
+
+        assert_eq!(restored, summary);
+    }
+
+    #[test]
+    fn attempt_result_displays_stable_lowercase_labels() {
+        let labels: Vec<String> = [
+            AttemptResult::Pending,
+            AttemptResult::Correct,
+            AttemptResult::Incorrect,
+        ]
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+
+        assert_eq!(labels, ["pending", "correct", "incorrect"]);
+        assert_eq!(AttemptResult::Correct.as_str(), "correct");
+    }
+
+    #[test]
+    fn capped_sessions_keep_the_opening_steps() {
+        let source = QuizSource::from_pgn("1. e4 e5 2. Nf3 Nc6 3. Bb5 *").expect("valid PGN");
+        let full = QuizSession::from_source(&source, 2);
+
+        let capped = QuizSession::from_source_capped(&source, 2, 3);
+        assert_eq!(capped.steps, full.steps[..3]);
+        assert_eq!(capped.summary, QuizSummary::new(3));
+        assert!(!capped.is_complete());
+
+        assert_eq!(QuizSession::from_source_capped(&source, 2, 5), full);
+        assert_eq!(QuizSession::from_source_capped(&source, 2, 50), full);
+
+        let empty = QuizSession::from_source_capped(&source, 2, 0);
+        assert!(empty.steps.is_empty());
+        assert_eq!(empty.summary.total_steps, 0);
+        assert!(empty.is_complete());
+    }
+
     #[test]
     fn hydration_generates_board_snapshots_and_prompts() {
         let source = QuizSource::from_pgn("1. e4 e5 2. Nf3 Nc6 *").expect("valid PGN");
@@ -387,6 +1266,15 @@ mod tests {
         assert!(matches!(err, QuizError::VariationsUnsupported));
     }
 
+    #[test]
+    fn hydration_from_pgn_reports_unreadable_and_illegal_moves() {
+        let unreadable = QuizSession::from_pgn("1. e4 Qz0 *", 1).unwrap_err();
+        let illegal = QuizSession::from_pgn("1. e4 e5 2. Nf3 Nf3 *", 1).unwrap_err();
+
+        assert!(matches!(unreadable, QuizError::Parse(ref detail) if detail.starts_with("Qz0")));
+        assert_eq!(illegal, QuizError::InvalidStep { index: 3 });
+    }
+
     #[test]
     fn hydration_applies_source_metadata() {
         let source = QuizSource::from_pgn("1. e4 *").expect("valid PGN");
@@ -394,6 +1282,7 @@ mod tests {
             step_id: Some("custom-id".into()),
             card_ref: Some("card-007".into()),
             themes: vec!["fork".into(), "attack".into(), "fork".into()],
+            extras: BTreeMap::new(),
         }]);
 
         let session = QuizSession::from_source(&enriched, 1);
@@ -406,4 +1295,142 @@ mod tests {
             vec!["attack".to_string(), "fork".to_string()]
         );
     }
+
+    #[test]
+    fn hydration_copies_pgn_comments_into_step_annotations() {
+        let session = QuizSession::from_pgn("1. e4 {Claims the centre} e5 *", 1)
+            .expect("commented PGN should parse");
+
+        assert_eq!(
+            session.steps[0].annotations,
+            vec!["Claims the centre".to_string()]
+        );
+        assert!(session.steps[1].annotations.is_empty());
+    }
+
+    #[test]
+    fn ordering_by_difficulty_is_stable_and_puts_unrated_steps_last() {
+        let unrated = QuizSession::from_pgn("1. e4 e5 2. Nf3 Nc6 *", 1).expect("valid PGN");
+        assert_eq!(unrated.clone().ordered_by_difficulty(), unrated);
+
+        let mut session = unrated.with_context_moves(3);
+        session.steps[0].difficulty = Some(2);
+        session.steps[2].difficulty = Some(1);
+        session.steps[3].difficulty = Some(2);
+        session.current_index = 2;
+        let ordered = session.ordered_by_difficulty();
+
+        let order: Vec<&str> = ordered
+            .steps
+            .iter()
+            .map(|step| step.solution_san.as_str())
+            .collect();
+        assert_eq!(order, ["Nf3", "e4", "Nc6", "e5"]);
+        assert_eq!(ordered.current_index, 0);
+        assert_eq!(ordered.context_moves, 3);
+        assert_eq!(ordered.summary.total_steps, 4);
+        let lead_ins: Vec<_> = ordered
+            .steps
+            .iter()
+            .map(|step| step.lead_in_moves.clone().expect("pinned").join(" "))
+            .collect();
+        assert_eq!(lead_ins, ["e4 e5", "", "e4 e5 Nf3", "e4"]);
+        assert_eq!(ordered.lead_in_moves(2, 1), ["Nf3"]);
+    }
+
+    #[test]
+    fn from_sources_keeps_repeats_unless_collapsing_is_requested() {
+        let open = QuizSource::from_pgn("1. e4 e5 2. Nf3 *").expect("valid PGN");
+        let italian = QuizSource::from_pgn("1. e4 e5 2. Bc4 *").expect("valid PGN");
+        let sources = [open, italian];
+
+        let ordered = QuizSession::from_sources(&sources, 1, false);
+        assert_eq!(ordered.summary.total_steps, 6);
+        assert_eq!(
+            ordered.steps[3].metadata.step_id.as_deref(),
+            Some("quiz-step-4")
+        );
+
+        let collapsed = QuizSession::from_sources(&sources, 1, true);
+        let prompts: Vec<&str> = collapsed
+            .steps
+            .iter()
+            .map(|step| step.prompt_san.as_str())
+            .collect();
+        assert_eq!(prompts, vec!["e4", "e5", "Nf3", "Bc4"]);
+        assert_eq!(collapsed.summary.total_steps, 4);
+        assert_eq!(
+            collapsed.steps[3].metadata.step_id.as_deref(),
+            Some("quiz-step-6")
+        );
+    }
+
+    #[test]
+    fn combined_lines_prompt_with_their_own_preceding_moves() {
+        let open = QuizSource::from_pgn("1. e4 e5 2. Nf3 *").expect("valid PGN");
+        let italian = QuizSource::from_pgn("1. e4 e5 2. Bc4 Bc5 *").expect("valid PGN");
+        let sources = [open, italian];
+
+        let ordered = QuizSession::from_sources(&sources, 1, false).with_context_moves(3);
+        assert!(ordered.lead_in_moves(3, 3).is_empty());
+        assert_eq!(ordered.lead_in_moves(4, 1), ["e4"]);
+        assert_eq!(ordered.lead_in_moves(6, 3), ["e4", "e5", "Bc4"]);
+
+        let collapsed = QuizSession::from_sources(&sources, 1, true).with_context_moves(3);
+        let prompts: Vec<&str> = collapsed
+            .steps
+            .iter()
+            .map(|step| step.prompt_san.as_str())
+            .collect();
+        assert_eq!(prompts, ["e4", "e5", "Nf3", "Bc4", "Bc5"]);
+        assert_eq!(collapsed.lead_in_moves(2, 3), ["e4", "e5"]);
+        assert_eq!(collapsed.lead_in_moves(3, 1), ["e5"]);
+        assert_eq!(collapsed.lead_in_moves(4, 3), ["e4", "e5", "Bc4"]);
+    }
+
+    #[test]
+    fn collapsing_ignores_move_clocks_but_not_solutions() {
+        let start = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+        let later_clock = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 4 3";
+        let session = QuizSession::new(vec![
+            QuizStep::new(start, "?", "e4", 1),
+            QuizStep::new(later_clock, "?", "e4", 1),
+            QuizStep::new(later_clock, "?", "d4", 1),
+        ])
+        .collapse_repeated_positions();
+
+        let solutions: Vec<&str> = session
+            .steps
+            .iter()
+            .map(|step| step.solution_san.as_str())
+            .collect();
+        assert_eq!(solutions, vec!["e4", "d4"]);
+        assert_eq!(session.summary.total_steps, 2);
+    }
+
+    #[test]
+    fn from_json_rejects_other_schema_versions() {
+        let session = QuizSession::from_pgn("1. e4 e5 *", 1).expect("PGN should parse");
+        let mut value = serde_json::to_value(&session).expect("session serialises");
+        assert_eq!(value["schema_version"], QUIZ_SESSION_SCHEMA_VERSION);
+        assert_eq!(
+            QuizSession::from_json(&value.to_string()).expect("current version restores"),
+            session
+        );
+
+        value["schema_version"] = (QUIZ_SESSION_SCHEMA_VERSION + 1).into();
+        let newer = QuizSession::from_json(&value.to_string()).expect_err("newer version");
+        assert!(
+            matches!(newer, QuizError::Parse(ref detail) if detail.contains("schema version 2"))
+        );
+
+        value
+            .as_object_mut()
+            .expect("session is an object")
+            .remove("schema_version");
+        let unversioned = QuizSession::from_json(&value.to_string()).expect_err("no version");
+        assert!(
+            matches!(unversioned, QuizError::Parse(ref detail) if detail.contains("version 0"))
+        );
+    }
 }
@@ -0,0 +1,184 @@
+//! Feature-gated SVG rendering of quiz boards for web adapters.
+
+use shakmaty::fen::Fen;
+use shakmaty::{CastlingMode, Chess, Color, File, Piece, Position, Rank, Role, Square};
+
+use crate::errors::{QuizError, QuizResult};
+
+/// Edge length of one square in SVG user units.
+const SQUARE_SIZE: u32 = 45;
+const LIGHT_SQUARE: &str = "#f0d9b5";
+const DARK_SQUARE: &str = "#b58863";
+
+/// Which side of the board is drawn at the bottom of the image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BoardOrientation {
+    /// White's first rank at the bottom.
+    #[default]
+    White,
+    /// Black's first rank at the bottom.
+    Black,
+    /// The side to move at the bottom, so Black-to-move prompts are flipped.
+    SideToMove,
+}
+
+/// Renders the position described by `fen` as an SVG image with White at the bottom.
+///
+/// # Errors
+///
+/// Returns [`QuizError::InvalidFen`] when `fen` does not describe a legal position.
+///
+/// # Examples
+/// ```rust
+/// use quiz_core::render_board_svg;
+///
+/// let svg = render_board_svg("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1")
+///     .expect("valid FEN");
+/// assert!(svg.starts_with("<svg"));
+/// assert_eq!(svg.matches("<text").count(), 32);
+/// ```
+pub fn render_board_svg(fen: &str) -> QuizResult<String> {
+    render_board_svg_with(fen, BoardOrientation::White)
+}
+
+/// Renders the position described by `fen` as an SVG image in the given orientation.
+///
+/// The image is a square `360` units wide: 64 coloured `<rect>` squares followed by one
+/// `<text>` glyph per piece. Each square carries a `data-square` attribute (`e4`) so
+/// adapters can attach click handlers or highlights.
+///
+/// # Errors
+///
+/// Returns [`QuizError::InvalidFen`] when `fen` does not describe a legal position.
+pub fn render_board_svg_with(fen: &str, orientation: BoardOrientation) -> QuizResult<String> {
+    let position: Chess = Fen::from_ascii(fen.as_bytes())
+        .map_err(|err| QuizError::InvalidFen(format!("{fen}: {err}")))?
+        .into_position(CastlingMode::Standard)
+        .map_err(|err| QuizError::InvalidFen(format!("{fen}: {err}")))?;
+    let flipped = match orientation {
+        BoardOrientation::White => false,
+        BoardOrientation::Black => true,
+        BoardOrientation::SideToMove => position.turn() == Color::Black,
+    };
+
+    let size = SQUARE_SIZE * 8;
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {size} {size}\" \
+         width=\"{size}\" height=\"{size}\">"
+    );
+    for index in 0..64 {
+        let square = Square::new(index);
+        let (x, y) = origin(square, flipped);
+        let fill = if square.is_light() {
+            LIGHT_SQUARE
+        } else {
+            DARK_SQUARE
+        };
+        svg.push_str(&format!(
+            "<rect data-square=\"{square}\" x=\"{x}\" y=\"{y}\" width=\"{SQUARE_SIZE}\" \
+             height=\"{SQUARE_SIZE}\" fill=\"{fill}\"/>"
+        ));
+    }
+    for (square, piece) in position.board().iter() {
+        let (x, y) = origin(square, flipped);
+        let (fill, stroke) = match piece.color {
+            Color::White => ("#ffffff", "#000000"),
+            Color::Black => ("#000000", "#000000"),
+        };
+        svg.push_str(&format!(
+            "<text data-square=\"{square}\" x=\"{}\" y=\"{}\" font-size=\"38\" \
+             text-anchor=\"middle\" dominant-baseline=\"central\" fill=\"{fill}\" \
+             stroke=\"{stroke}\" stroke-width=\"1\">{}</text>",
+            x + SQUARE_SIZE / 2,
+            y + SQUARE_SIZE / 2,
+            glyph(piece)
+        ));
+    }
+    svg.push_str("</svg>");
+    Ok(svg)
+}
+
+/// Top-left corner of `square` in SVG coordinates.
+fn origin(square: Square, flipped: bool) -> (u32, u32) {
+    let (file, rank) = square.coords();
+    let (column, row) = if flipped {
+        (
+            File::H.to_u32() - file.to_u32(),
+            rank.to_u32() - Rank::First.to_u32(),
+        )
+    } else {
+        (file.to_u32(), Rank::Eighth.to_u32() - rank.to_u32())
+    };
+    (column * SQUARE_SIZE, row * SQUARE_SIZE)
+}
+
+/// Solid Unicode chess glyph for `piece`; colour comes from the fill attribute.
+fn glyph(piece: Piece) -> char {
+    match piece.role {
+        Role::King => '♚',
+        Role::Queen => '♛',
+        Role::Rook => '♜',
+        Role::Bishop => '♝',
+        Role::Knight => '♞',
+        Role::Pawn => '♟',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AFTER_E4: &str = "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1";
+
+    fn text_origin(svg: &str, square: &str) -> String {
+        let marker = format!("<text data-square=\"{square}\" ");
+        let start = svg.find(&marker).expect("piece on square") + marker.len();
+        svg[start..]
+            .split(" font-size")
+            .next()
+            .expect("attributes")
+            .to_string()
+    }
+
+    #[test]
+    fn white_orientation_draws_rank_one_at_the_bottom() {
+        let svg = render_board_svg(AFTER_E4).expect("valid FEN");
+
+        assert_eq!(text_origin(&svg, "e4"), "x=\"202\" y=\"202\"");
+        assert_eq!(text_origin(&svg, "a8"), "x=\"22\" y=\"22\"");
+        assert!(svg.contains("<rect data-square=\"a1\" x=\"0\" y=\"315\""));
+        assert_eq!(svg.matches("<rect").count(), 64);
+        assert_eq!(svg.matches("<text").count(), 32);
+    }
+
+    #[test]
+    fn side_to_move_orientation_flips_for_black() {
+        let flipped =
+            render_board_svg_with(AFTER_E4, BoardOrientation::SideToMove).expect("valid FEN");
+
+        assert_eq!(
+            flipped,
+            render_board_svg_with(AFTER_E4, BoardOrientation::Black).expect("valid FEN")
+        );
+        assert_eq!(text_origin(&flipped, "a8"), "x=\"337\" y=\"337\"");
+        assert!(flipped.contains("<rect data-square=\"a1\" x=\"315\" y=\"0\""));
+
+        let white_to_move = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+        assert_eq!(
+            render_board_svg_with(white_to_move, BoardOrientation::SideToMove),
+            render_board_svg(white_to_move)
+        );
+    }
+
+    #[test]
+    fn invalid_fen_is_rejected() {
+        assert!(matches!(
+            render_board_svg("not a fen"),
+            Err(QuizError::InvalidFen(message)) if message.starts_with("not a fen")
+        ));
+        assert!(matches!(
+            render_board_svg("8/8/8/8/8/8/8/8 w - - 0 1"),
+            Err(QuizError::InvalidFen(_))
+        ));
+    }
+}
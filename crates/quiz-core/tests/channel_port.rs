@@ -0,0 +1,45 @@
+use std::thread;
+
+use quiz_core::{AttemptResult, ChannelPort, PortEvent, QuizEngine};
+
+#[test]
+fn channel_port_drives_a_session_across_threads() {
+    let (mut port, events, responses) = ChannelPort::connected();
+    let worker = thread::spawn(move || {
+        let mut engine = QuizEngine::from_pgn("1. e4 e5 2. Nf3 *", 1).expect("valid PGN");
+        engine.run(&mut port).cloned()
+    });
+
+    let mut answered_wrong = false;
+    let mut feedback = Vec::new();
+    let summary = loop {
+        match events.recv().expect("engine sends until the summary") {
+            PortEvent::Prompt(prompt) => {
+                let answer = if prompt.step_index == 1 && !answered_wrong {
+                    answered_wrong = true;
+                    "d5".to_string()
+                } else {
+                    prompt.prompt_san
+                };
+                responses.send(answer).expect("engine is waiting");
+            }
+            PortEvent::Feedback(message) => feedback.push(message.result),
+            PortEvent::Summary(summary) => break summary,
+        }
+    };
+
+    let finished = worker.join().expect("worker thread").expect("session");
+    assert_eq!(finished, summary);
+    assert_eq!(
+        feedback,
+        vec![
+            AttemptResult::Correct,
+            AttemptResult::Pending,
+            AttemptResult::Correct,
+            AttemptResult::Correct,
+        ]
+    );
+    assert_eq!(summary.total_steps, 3);
+    assert_eq!(summary.correct_answers, 3);
+    assert_eq!(summary.retries_consumed, 1);
+}
@@ -2,6 +2,7 @@
 
 /// A study card belonging to an owner and tracking custom state.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Card<Id, Owner, Kind, State> {
     /// Stable identifier of the card.
     pub id: Id,
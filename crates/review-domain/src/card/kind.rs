@@ -1,6 +1,6 @@
 //! Generic flashcard classification helpers shared across services.
 
-use std::fmt;
+use core::fmt;
 
 /// Describes the high-level type of a study card.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
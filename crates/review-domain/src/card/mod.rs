@@ -8,3 +8,6 @@ pub use state::CardState;
 
 pub mod stored_state;
 pub use stored_state::StoredCardState;
+
+pub mod new_card_due;
+pub use new_card_due::NewCardDue;
@@ -0,0 +1,58 @@
+use chrono::NaiveDate;
+
+/// When a freshly created card first becomes due.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NewCardDue {
+    /// The card is due on the day it is created.
+    #[default]
+    Today,
+    /// The card first surfaces the day after it is created.
+    Tomorrow,
+}
+
+impl NewCardDue {
+    /// Returns the initial due date for a card created on `created_on`.
+    ///
+    /// `Tomorrow` saturates at the last representable date.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use review_domain::NewCardDue;
+    ///
+    /// let created = NaiveDate::from_ymd_opt(2024, 2, 28).unwrap();
+    /// assert_eq!(NewCardDue::Today.first_due(created), created);
+    /// assert_eq!(
+    ///     NewCardDue::Tomorrow.first_due(created),
+    ///     NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()
+    /// );
+    /// ```
+    #[must_use]
+    pub fn first_due(self, created_on: NaiveDate) -> NaiveDate {
+        match self {
+            Self::Today => created_on,
+            Self::Tomorrow => created_on.succ_opt().unwrap_or(created_on),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_keeps_new_cards_due_today() {
+        let created = NaiveDate::from_ymd_opt(2024, 12, 31).expect("valid date");
+        assert_eq!(NewCardDue::default(), NewCardDue::Today);
+        assert_eq!(NewCardDue::default().first_due(created), created);
+        assert_eq!(
+            NewCardDue::Tomorrow.first_due(created),
+            NaiveDate::from_ymd_opt(2025, 1, 1).expect("valid date")
+        );
+        assert_eq!(
+            NewCardDue::Tomorrow.first_due(NaiveDate::MAX),
+            NaiveDate::MAX
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use chrono::NaiveDate;
-use std::num::NonZeroU8;
+use core::num::NonZeroU8;
 
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -10,3 +10,91 @@ pub struct StoredCardState {
     pub consecutive_correct: u32,
     pub last_reviewed_on: Option<NaiveDate>,
 }
+
+impl StoredCardState {
+    /// Whole days from `today` until the card is due: positive while it is
+    /// upcoming, `0` on the due date, and negative once it is overdue.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use review_domain::StoredCardState;
+    /// use std::num::NonZeroU8;
+    ///
+    /// let state = StoredCardState {
+    ///     due_on: NaiveDate::from_ymd_opt(2024, 3, 10).unwrap(),
+    ///     interval: NonZeroU8::new(1).unwrap(),
+    ///     ease_factor: 2.5,
+    ///     consecutive_correct: 0,
+    ///     last_reviewed_on: None,
+    /// };
+    /// assert_eq!(state.days_until_due(NaiveDate::from_ymd_opt(2024, 3, 7).unwrap()), 3);
+    /// assert_eq!(state.days_until_due(NaiveDate::from_ymd_opt(2024, 3, 12).unwrap()), -2);
+    /// ```
+    #[must_use]
+    pub fn days_until_due(&self, today: NaiveDate) -> i64 {
+        self.due_on.signed_duration_since(today).num_days()
+    }
+
+    /// Returns `true` when the card should be reviewed on `today`, i.e. it is due
+    /// today or overdue.
+    #[must_use]
+    pub fn is_due(&self, today: NaiveDate) -> bool {
+        self.due_on <= today
+    }
+
+    /// Returns `true` when the due date has already passed before `today`.
+    #[must_use]
+    pub fn is_overdue(&self, today: NaiveDate) -> bool {
+        self.due_on < today
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive_date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).expect("valid date")
+    }
+
+    fn state_due_on(due_on: NaiveDate) -> StoredCardState {
+        StoredCardState {
+            due_on,
+            interval: NonZeroU8::new(3).expect("non-zero interval"),
+            ease_factor: 2.5,
+            consecutive_correct: 1,
+            last_reviewed_on: None,
+        }
+    }
+
+    #[test]
+    fn future_due_date_is_neither_due_nor_overdue() {
+        let state = state_due_on(naive_date(2024, 3, 1));
+        let today = naive_date(2024, 2, 27);
+
+        assert_eq!(state.days_until_due(today), 3, "crosses the leap day");
+        assert!(!state.is_due(today));
+        assert!(!state.is_overdue(today));
+    }
+
+    #[test]
+    fn card_due_today_is_due_but_not_overdue() {
+        let today = naive_date(2024, 3, 1);
+        let state = state_due_on(today);
+
+        assert_eq!(state.days_until_due(today), 0);
+        assert!(state.is_due(today));
+        assert!(!state.is_overdue(today));
+    }
+
+    #[test]
+    fn past_due_date_counts_down_negatively() {
+        let state = state_due_on(naive_date(2023, 12, 30));
+        let today = naive_date(2024, 1, 2);
+
+        assert_eq!(state.days_until_due(today), -3);
+        assert!(state.is_due(today));
+        assert!(state.is_overdue(today));
+    }
+}
@@ -0,0 +1,130 @@
+//! SM-2 ease factor arithmetic shared by every crate that grades reviews.
+
+use crate::ReviewGrade;
+
+/// Default lower bound applied to ease factors after a review.
+pub const DEFAULT_EASE_MINIMUM: f32 = 1.3;
+/// Default upper bound applied to ease factors after a review.
+pub const DEFAULT_EASE_MAXIMUM: f32 = 2.8;
+/// Default ease factor given to newly created cards by the scheduler and the card store.
+pub const DEFAULT_INITIAL_EASE_FACTOR: f32 = 2.5;
+
+/// Floor and ceiling that ease factors are clamped to after a review.
+///
+/// The scheduler and the card store both read their bounds through this type, so a
+/// deployment that raises the floor above the SuperMemo default of
+/// [`DEFAULT_EASE_MINIMUM`] gets the same ease from either review path.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EaseBounds {
+    /// Lowest ease a review can leave a card with.
+    pub minimum: f32,
+    /// Highest ease a review can leave a card with.
+    pub maximum: f32,
+}
+
+impl EaseBounds {
+    /// Returns the ease factor after `grade`, clamped to these bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use review_domain::{EaseBounds, ReviewGrade};
+    ///
+    /// let gentle = EaseBounds { minimum: 1.7, ..EaseBounds::default() };
+    /// assert!((gentle.next_ease_factor(1.8, ReviewGrade::Again) - 1.7).abs() < 1e-6);
+    /// ```
+    #[must_use]
+    pub fn next_ease_factor(self, current: f32, grade: ReviewGrade) -> f32 {
+        next_ease_factor(current, grade, self.minimum, self.maximum)
+    }
+}
+
+impl Default for EaseBounds {
+    fn default() -> Self {
+        Self {
+            minimum: DEFAULT_EASE_MINIMUM,
+            maximum: DEFAULT_EASE_MAXIMUM,
+        }
+    }
+}
+
+/// Returns the ease factor after `grade`, clamped to `minimum..=maximum`.
+///
+/// # Examples
+/// ```
+/// use review_domain::{DEFAULT_EASE_MAXIMUM, DEFAULT_EASE_MINIMUM, ReviewGrade, next_ease_factor};
+///
+/// let ease = next_ease_factor(2.5, ReviewGrade::Easy, DEFAULT_EASE_MINIMUM, DEFAULT_EASE_MAXIMUM);
+/// assert!((ease - 2.6).abs() < 1e-6);
+/// ```
+#[must_use]
+pub fn next_ease_factor(current: f32, grade: ReviewGrade, minimum: f32, maximum: f32) -> f32 {
+    let quality = match grade {
+        ReviewGrade::Again => 0.0,
+        ReviewGrade::Hard => 3.0,
+        ReviewGrade::Good => 4.0,
+        ReviewGrade::Easy => 5.0,
+    };
+    let delta = 0.1 - (5.0 - quality) * (0.08 + (5.0 - quality) * 0.02);
+    (current + delta).clamp(minimum, maximum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TEST_EPSILON, assert_is_close};
+
+    #[test]
+    fn ease_moves_with_grade_within_default_bounds() {
+        let next = |grade| next_ease_factor(2.5, grade, DEFAULT_EASE_MINIMUM, DEFAULT_EASE_MAXIMUM);
+        assert_is_close!(next(ReviewGrade::Again), 1.7, TEST_EPSILON);
+        assert_is_close!(next(ReviewGrade::Hard), 2.36, TEST_EPSILON);
+        assert_is_close!(next(ReviewGrade::Good), 2.5, TEST_EPSILON);
+        assert_is_close!(next(ReviewGrade::Easy), 2.6, TEST_EPSILON);
+    }
+
+    #[test]
+    fn bounds_apply_the_same_rule_as_the_free_function() {
+        let raised = EaseBounds {
+            minimum: 1.8,
+            maximum: 2.6,
+        };
+        for grade in [
+            ReviewGrade::Again,
+            ReviewGrade::Hard,
+            ReviewGrade::Good,
+            ReviewGrade::Easy,
+        ] {
+            assert_eq!(
+                raised.next_ease_factor(2.0, grade).to_bits(),
+                next_ease_factor(2.0, grade, 1.8, 2.6).to_bits()
+            );
+        }
+        assert_is_close!(
+            raised.next_ease_factor(2.0, ReviewGrade::Again),
+            1.8,
+            TEST_EPSILON
+        );
+        assert_eq!(
+            EaseBounds::default(),
+            EaseBounds {
+                minimum: DEFAULT_EASE_MINIMUM,
+                maximum: DEFAULT_EASE_MAXIMUM,
+            }
+        );
+    }
+
+    #[test]
+    fn ease_is_clamped_to_the_supplied_bounds() {
+        assert_is_close!(
+            next_ease_factor(1.2, ReviewGrade::Again, 1.5, 2.0),
+            1.5,
+            TEST_EPSILON
+        );
+        assert_is_close!(
+            next_ease_factor(2.0, ReviewGrade::Easy, 1.5, 2.0),
+            2.0,
+            TEST_EPSILON
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use crate::GradeError;
+
+use super::Grade;
+
+/// Lowest confidence, in `0.0..=1.0`, that earns each grade above [`Grade::Zero`].
+///
+/// [`Grade::from_confidence_with`] picks the highest grade whose threshold the confidence
+/// reaches, so a value equal to a threshold belongs to the upper bucket. The default splits
+/// the range into equal fifths:
+///
+/// | Confidence     | Grade            |
+/// |----------------|------------------|
+/// | `0.0` – `<0.2` | [`Grade::Zero`]  |
+/// | `0.2` – `<0.4` | [`Grade::One`]   |
+/// | `0.4` – `<0.6` | [`Grade::Two`]   |
+/// | `0.6` – `<0.8` | [`Grade::Three`] |
+/// | `0.8` – `1.0`  | [`Grade::Four`]  |
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConfidenceThresholds {
+    /// Minimum confidence for [`Grade::One`].
+    pub one: f32,
+    /// Minimum confidence for [`Grade::Two`].
+    pub two: f32,
+    /// Minimum confidence for [`Grade::Three`].
+    pub three: f32,
+    /// Minimum confidence for [`Grade::Four`].
+    pub four: f32,
+}
+
+impl Default for ConfidenceThresholds {
+    fn default() -> Self {
+        Self {
+            one: 0.2,
+            two: 0.4,
+            three: 0.6,
+            four: 0.8,
+        }
+    }
+}
+
+impl Grade {
+    /// Buckets a slider confidence in `0.0..=1.0` into a grade using the default
+    /// [`ConfidenceThresholds`].
+    ///
+    /// # Errors
+    /// Returns `GradeError::InvalidConfidenceError` when `confidence` is NaN or outside
+    /// `0.0..=1.0`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use review_domain::Grade;
+    /// assert_eq!(Grade::from_confidence(0.65), Ok(Grade::Three));
+    /// assert_eq!(Grade::from_confidence(1.0), Ok(Grade::Four));
+    /// assert!(Grade::from_confidence(f32::NAN).is_err());
+    /// ```
+    pub fn from_confidence(confidence: f32) -> Result<Self, GradeError> {
+        Self::from_confidence_with(confidence, &ConfidenceThresholds::default())
+    }
+
+    /// Buckets a slider confidence in `0.0..=1.0` into a grade using `thresholds`.
+    ///
+    /// # Errors
+    /// Returns `GradeError::InvalidConfidenceError` when `confidence` is NaN or outside
+    /// `0.0..=1.0`.
+    pub fn from_confidence_with(
+        confidence: f32,
+        thresholds: &ConfidenceThresholds,
+    ) -> Result<Self, GradeError> {
+        if !(0.0..=1.0).contains(&confidence) {
+            return Err(GradeError::InvalidConfidenceError);
+        }
+        let grade = [
+            (thresholds.four, Grade::Four),
+            (thresholds.three, Grade::Three),
+            (thresholds.two, Grade::Two),
+            (thresholds.one, Grade::One),
+        ]
+        .into_iter()
+        .find(|&(minimum, _)| confidence >= minimum)
+        .map_or(Grade::Zero, |(_, grade)| grade);
+        Ok(grade)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_buckets_switch_grade_exactly_at_each_threshold() {
+        let cases = [
+            (0.0, Grade::Zero),
+            (0.199, Grade::Zero),
+            (0.2, Grade::One),
+            (0.399, Grade::One),
+            (0.4, Grade::Two),
+            (0.599, Grade::Two),
+            (0.6, Grade::Three),
+            (0.799, Grade::Three),
+            (0.8, Grade::Four),
+            (1.0, Grade::Four),
+        ];
+        for (confidence, expected) in cases {
+            assert_eq!(
+                Grade::from_confidence(confidence),
+                Ok(expected),
+                "{confidence}"
+            );
+        }
+    }
+
+    #[test]
+    fn custom_thresholds_move_the_bucket_boundaries() {
+        let strict = ConfidenceThresholds {
+            one: 0.5,
+            two: 0.7,
+            three: 0.9,
+            four: 1.0,
+        };
+        assert_eq!(Grade::from_confidence_with(0.49, &strict), Ok(Grade::Zero));
+        assert_eq!(Grade::from_confidence_with(0.5, &strict), Ok(Grade::One));
+        assert_eq!(Grade::from_confidence_with(0.89, &strict), Ok(Grade::Two));
+        assert_eq!(Grade::from_confidence_with(0.9, &strict), Ok(Grade::Three));
+        assert_eq!(Grade::from_confidence_with(1.0, &strict), Ok(Grade::Four));
+    }
+
+    #[test]
+    fn nan_and_out_of_range_confidence_is_rejected() {
+        for confidence in [f32::NAN, -0.01, 1.01, f32::INFINITY, f32::NEG_INFINITY] {
+            assert_eq!(
+                Grade::from_confidence(confidence),
+                Err(GradeError::InvalidConfidenceError),
+                "{confidence}"
+            );
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use core::fmt;
+
 /// Errors produced when attempting to construct a [`Grade`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum GradeError {
@@ -5,4 +7,43 @@ pub enum GradeError {
     GradeOutsideRangeError { grade: u8 },
     /// The provided grade could not be interpreted as a known review grade.
     InvalidGradeError { grade: u8 },
+    /// The provided text was neither a grade digit nor a recognised grade word.
+    UnrecognizedGradeError,
+    /// The provided confidence was NaN or outside `0.0..=1.0`.
+    InvalidConfidenceError,
+}
+
+impl fmt::Display for GradeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::GradeOutsideRangeError { grade } => {
+                write!(f, "grade {grade} is outside the supported range 0-4")
+            }
+            Self::InvalidGradeError { grade } => write!(f, "grade {grade} is not a review grade"),
+            Self::UnrecognizedGradeError => f.write_str("unrecognised grade"),
+            Self::InvalidConfidenceError => f.write_str("confidence must lie within 0.0-1.0"),
+        }
+    }
+}
+
+impl core::error::Error for GradeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::GradeError;
+
+    #[test]
+    fn grade_errors_display_the_offending_grade() {
+        let error = GradeError::GradeOutsideRangeError { grade: 7 };
+        assert_eq!(
+            error.to_string(),
+            "grade 7 is outside the supported range 0-4"
+        );
+        let boxed: Box<dyn std::error::Error> = Box::new(GradeError::UnrecognizedGradeError);
+        assert_eq!(boxed.to_string(), "unrecognised grade");
+        assert_eq!(
+            GradeError::InvalidConfidenceError.to_string(),
+            "confidence must lie within 0.0-1.0"
+        );
+    }
 }
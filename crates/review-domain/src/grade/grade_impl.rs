@@ -1,3 +1,5 @@
+use core::str::FromStr;
+
 use crate::GradeError;
 
 /// A grade between 0 and 4 inclusive.
@@ -60,4 +62,80 @@ impl Grade {
             Grade::Four => 0.15,
         }
     }
+
+    /// Returns a compact symbol for summary screens: `✗`, `✗`, `~`, `✓`, `✓✓` for grades
+    /// 0 through 4. Failing grades share a symbol so distributions read as pass/fail at a
+    /// glance; pair it with [`Grade::description`] when the grades must stay distinct.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use review_domain::Grade;
+    /// assert_eq!(Grade::Two.short_label(), "~");
+    /// assert_eq!(Grade::Four.short_label(), "✓✓");
+    /// ```
+    #[must_use]
+    pub fn short_label(self) -> &'static str {
+        match self {
+            Grade::Zero | Grade::One => "✗",
+            Grade::Two => "~",
+            Grade::Three => "✓",
+            Grade::Four => "✓✓",
+        }
+    }
+
+    /// Returns a one-line, human-readable description of the grade.
+    #[must_use]
+    pub fn description(self) -> &'static str {
+        match self {
+            Grade::Zero => "Forgot completely",
+            Grade::One => "Forgot, but recognised the answer",
+            Grade::Two => "Recalled with difficulty",
+            Grade::Three => "Recalled correctly",
+            Grade::Four => "Recalled effortlessly",
+        }
+    }
+}
+
+/// Parses a grade from a digit or a review-button word.
+///
+/// Digits `0`-`4` map directly to their grade. The words are matched case-insensitively and
+/// follow the usual four review buttons, which skip [`Grade::One`]:
+///
+/// | Word    | Grade          |
+/// |---------|----------------|
+/// | `again` | [`Grade::Zero`]  |
+/// | `hard`  | [`Grade::Two`]   |
+/// | `good`  | [`Grade::Three`] |
+/// | `easy`  | [`Grade::Four`]  |
+///
+/// Surrounding whitespace is ignored.
+///
+/// # Examples
+/// ```rust
+/// use review_domain::Grade;
+/// assert_eq!("Good".parse::<Grade>(), Ok(Grade::Three));
+/// assert_eq!(" 1 ".parse::<Grade>(), Ok(Grade::One));
+/// ```
+impl FromStr for Grade {
+    type Err = GradeError;
+
+    /// # Errors
+    /// Returns `GradeError::GradeOutsideRangeError` for digits above 4 and
+    /// `GradeError::UnrecognizedGradeError` for any other text.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let input = input.trim();
+        if let Ok(grade) = input.parse::<u8>() {
+            return Self::from_u8(grade);
+        }
+        [
+            ("again", Grade::Zero),
+            ("hard", Grade::Two),
+            ("good", Grade::Three),
+            ("easy", Grade::Four),
+        ]
+        .into_iter()
+        .find(|(word, _)| input.eq_ignore_ascii_case(word))
+        .map(|(_, grade)| grade)
+        .ok_or(GradeError::UnrecognizedGradeError)
+    }
 }
@@ -1,6 +1,9 @@
+mod confidence;
 mod error;
 mod grade_impl;
 
+pub use self::confidence::ConfidenceThresholds;
+
 pub use self::error::GradeError;
 
 pub use self::grade_impl::Grade;
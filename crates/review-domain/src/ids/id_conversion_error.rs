@@ -1,5 +1,5 @@
 use super::IdKind;
-use std::fmt;
+use core::fmt;
 
 /// Error raised when converting into a strongly typed identifier fails.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -39,4 +39,4 @@ impl fmt::Display for IdConversionError {
     }
 }
 
-impl std::error::Error for IdConversionError {}
+impl core::error::Error for IdConversionError {}
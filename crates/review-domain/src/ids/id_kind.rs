@@ -1,4 +1,4 @@
-use std::fmt;
+use core::fmt;
 
 /// Identifies which strongly typed identifier failed to convert.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
@@ -1,44 +1,79 @@
 //! Core domain types shared across the chess training back-end services.
+//!
+//! The grade, review-grade, ease, identifier, hashing, and edge-id modules build without `std`. Disable
+//! the default `std` feature to use just those. The `alloc` feature adds the card, opening,
+//! position, study-stage, and unlock modules; the repertoire, review, and tactic modules
+//! require `std`.
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
 pub mod card;
+pub mod ease;
 pub mod grade;
 pub mod ids;
 pub mod macros;
+#[cfg(feature = "alloc")]
 pub mod opening;
+#[cfg(feature = "alloc")]
 pub mod position;
+#[cfg(feature = "std")]
 pub mod repertoire;
+#[cfg(feature = "std")]
 pub mod review;
 pub mod review_grade;
+#[cfg(feature = "alloc")]
 pub mod study_stage;
+#[cfg(feature = "std")]
 pub mod tactic;
+#[cfg(feature = "alloc")]
 pub mod unlock;
 pub mod utils;
 
+#[cfg(feature = "std")]
 use chrono::NaiveDate;
 
 /// Generic flashcard definition used across services.
-pub use card::{Card, CardKind, StoredCardState};
+#[cfg(feature = "alloc")]
+pub use card::{Card, CardKind, NewCardDue, StoredCardState};
+/// SM-2 ease factor bounds and update rule.
+pub use ease::{
+    DEFAULT_EASE_MAXIMUM, DEFAULT_EASE_MINIMUM, DEFAULT_INITIAL_EASE_FACTOR, EaseBounds,
+    next_ease_factor,
+};
 /// Validated review grades and related errors.
-pub use grade::{Grade, GradeError};
+pub use grade::{ConfidenceThresholds, Grade, GradeError};
 /// Strongly typed identifier wrappers used across the crate.
 pub use ids::{CardId, EdgeId, IdConversionError, IdKind, LearnerId, MoveId, TacticId};
 /// Opening-focused request and payload types.
+#[cfg(feature = "alloc")]
 pub use opening::{EdgeInput, OpeningCard, OpeningEdge, OpeningEdgeHandle};
 /// Normalized chess position representation and related errors.
-pub use position::{Position, PositionError, PositionId};
+#[cfg(feature = "alloc")]
+pub use position::{ChessPosition, Color, Position, PositionError, PositionId};
 /// Opening repertoire store, graph representation, and associated move model.
-pub use repertoire::{OpeningGraph, Repertoire, RepertoireError, RepertoireMove};
+#[cfg(feature = "std")]
+pub use repertoire::{OpeningGraph, Repertoire, RepertoireError, RepertoireMove, RepertoireStats};
 /// Review submission payload capturing user input.
+#[cfg(feature = "std")]
 pub use review::ReviewRequest;
 /// Grading scale for spaced repetition reviews.
 pub use review_grade::ReviewGrade;
 /// Learning stage classification for cards.
+#[cfg(feature = "alloc")]
 pub use study_stage::StudyStage;
 /// Tactic-focused card payloads.
+#[cfg(feature = "std")]
 pub use tactic::TacticCard;
 /// Unlock record details for progressive content releases.
+#[cfg(feature = "alloc")]
 pub use unlock::{UnlockDetail, UnlockRecord};
 
+/// Canonical opening edge identifier shared by every crate that creates edges.
+pub use utils::edge_id_for;
 pub use utils::hash_with_seed;
 
 pub const TEST_EPSILON: f32 = 1e-6;
@@ -54,6 +89,7 @@ pub const TEST_EPSILON: f32 = 1e-6;
 /// let date = naive_date(2024, 5, 15);
 /// assert_eq!(date, NaiveDate::from_ymd_opt(2024, 5, 15).unwrap());
 /// ```
+#[cfg(feature = "std")]
 #[must_use]
 pub fn naive_date(year: i32, month: u32, day: u32) -> NaiveDate {
     NaiveDate::from_ymd_opt(year, month, day).expect("valid date")
@@ -1,5 +1,7 @@
 //! Directed edge in an opening tree.
 
+use alloc::string::String;
+
 /// Directed edge in an opening tree.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -1,7 +1,9 @@
 //! Input payload for inserting or updating an opening edge.
 
+use alloc::string::String;
+
 use crate::opening::OpeningEdge;
-use crate::utils::hash_with_seed;
+use crate::utils::edge_id_for;
 
 /// Input payload for inserting or updating an edge.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -19,12 +21,11 @@ pub struct EdgeInput {
 impl EdgeInput {
     /// Converts the input payload into a canonical [`OpeningEdge`].
     ///
-    /// The canonical form computes a deterministic edge ID from the parent position and move,
-    /// and returns an [`OpeningEdge`] with normalized fields.
+    /// The canonical form derives the edge ID with [`edge_id_for`] from the parent position,
+    /// child position, and move, and returns an [`OpeningEdge`] with normalized fields.
     #[must_use]
     pub fn into_edge(self) -> OpeningEdge {
-        let value_to_hash: String = format!("{}|{}", self.parent_id, self.move_uci);
-        let id = hash_with_seed(&value_to_hash);
+        let id = edge_id_for(self.parent_id, self.child_id, &self.move_uci);
         OpeningEdge {
             id,
             parent_id: self.parent_id,
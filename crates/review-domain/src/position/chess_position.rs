@@ -1,12 +1,28 @@
 //! Shared representation of chess positions used across review services.
 
-use crate::hash::hash64;
+use alloc::string::String;
+use alloc::vec::Vec;
 
+use super::{Color, Position, PositionError, PositionId};
+use crate::hash_with_seed;
 
 /// Chess position represented by a FEN string.
+///
+/// Carries the side to move and ply alongside the FEN that [`Position`] stores alone. The
+/// identifier is hashed the same way, so a `ChessPosition` and the [`Position`] with the same FEN
+/// share an id, and the two convert into each other with [`TryFrom`].
+///
+/// The FEN is validated once on construction, so [`side`](Self::side) and
+/// [`move_number`](Self::move_number) answer without parsing it again. Deserializing goes
+/// through [`ChessPosition::new`] for the same reason.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(try_from = "ChessPositionFields", into = "ChessPositionFields")
+)]
 pub struct ChessPosition {
-    /// Stable identifier derived from the [`fen`](Self::fen).
+    /// Stable identifier derived from the [`fen`](Self::fen); equal to [`Position::id`].
     pub id: u64,
     /// Full FEN string.
     pub fen: String,
@@ -14,6 +30,8 @@ pub struct ChessPosition {
     pub side_to_move: char,
     /// Distance in plies from the start position.
     pub ply: u32,
+    /// Full-move number read from the FEN; always at least one.
+    move_number: u32,
 }
 
 impl ChessPosition {
@@ -29,6 +47,9 @@ impl ChessPosition {
     ///
     /// Returns [`PositionError::InvalidPiecePlacement`] when the FEN contains invalid
     /// characters in the piece placement field.
+    ///
+    /// Returns [`PositionError::MalformedFen`] when the full-move number is not a positive
+    /// integer.
     #[must_use = "inspect the result to detect invalid chess positions"]
     pub fn new(fen: impl Into<String>, ply: u32) -> Result<Self, PositionError> {
         let fen = fen.into();
@@ -49,14 +70,116 @@ impl ChessPosition {
         {
             return Err(PositionError::InvalidPiecePlacement);
         }
-        let id = hash64(&[fen.as_bytes()]);
+        let move_number = parts[5]
+            .parse()
+            .ok()
+            .filter(|number| *number > 0)
+            .ok_or(PositionError::MalformedFen)?;
+        let id = hash_with_seed(&fen);
         Ok(Self {
             id,
             fen,
             side_to_move,
             ply,
+            move_number,
         })
     }
+
+    /// Side to move, as validated by [`ChessPosition::new`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use review_domain::{ChessPosition, Color};
+    /// let position = ChessPosition::new("8/8/8/8/8/8/8/8 b - - 0 12", 23).unwrap();
+    /// assert_eq!(position.side(), Color::Black);
+    /// assert_eq!(position.move_number(), 12);
+    /// ```
+    #[must_use]
+    pub fn side(&self) -> Color {
+        if self.side_to_move == 'b' {
+            Color::Black
+        } else {
+            Color::White
+        }
+    }
+
+    /// Full-move number from the FEN, at least one.
+    #[must_use]
+    pub const fn move_number(&self) -> u32 {
+        self.move_number
+    }
+}
+
+/// Serialized shape of a [`ChessPosition`]; the move number is read back from the FEN.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct ChessPositionFields {
+    id: u64,
+    fen: String,
+    side_to_move: char,
+    ply: u32,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<ChessPositionFields> for ChessPosition {
+    type Error = PositionError;
+
+    fn try_from(fields: ChessPositionFields) -> Result<Self, Self::Error> {
+        let mut position = Self::new(fields.fen, fields.ply)?;
+        position.id = fields.id;
+        position.side_to_move = fields.side_to_move;
+        Ok(position)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<ChessPosition> for ChessPositionFields {
+    fn from(position: ChessPosition) -> Self {
+        Self {
+            id: position.id,
+            fen: position.fen,
+            side_to_move: position.side_to_move,
+            ply: position.ply,
+        }
+    }
+}
+
+impl TryFrom<Position> for ChessPosition {
+    type Error = PositionError;
+
+    /// Validates the FEN and derives `side_to_move` and `ply` from it, the ply coming from the
+    /// side to move and full-move number as in [`Position::ply`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`ChessPosition::new`] errors, and [`PositionError::MalformedFen`] when the
+    /// full-move number is missing, not a number, or zero.
+    fn try_from(position: Position) -> Result<Self, Self::Error> {
+        let mut converted = Self::new(position.fen, 0)?;
+        converted.ply = converted.side().ply_at(converted.move_number);
+        Ok(converted)
+    }
+}
+
+impl TryFrom<ChessPosition> for Position {
+    type Error = PositionError;
+
+    /// Keeps the FEN and id, checking that the stored `side_to_move` matches the FEN.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PositionError::InvalidSideToMove`] when `side_to_move` disagrees with the
+    /// FEN's side-to-move field.
+    fn try_from(position: ChessPosition) -> Result<Self, Self::Error> {
+        let converted = Self {
+            id: PositionId::new(position.id),
+            fen: position.fen,
+        };
+        if converted.side_to_move() != Some(position.side_to_move) {
+            return Err(PositionError::InvalidSideToMove);
+        }
+        Ok(converted)
+    }
 }
 
 #[cfg(test)]
@@ -121,6 +244,83 @@ mod tests {
         assert_eq!(result, Err(PositionError::MalformedFen));
     }
 
+    #[test]
+    fn conversions_round_trip_and_share_the_position_id() {
+        let fen = "r3k2r/pp1n1ppp/2p5/3pP3/8/2N5/PPP2PPP/R3K1NR b Kq - 0 12";
+        let position = Position::new(fen);
+
+        let chess = ChessPosition::try_from(position.clone()).expect("valid FEN");
+        assert_eq!(chess.id, position.id.get());
+        assert_eq!(chess.side_to_move, 'b');
+        assert_eq!(chess.ply, 23);
+        assert_eq!(Position::try_from(chess), Ok(position));
+    }
+
+    #[test]
+    fn position_with_malformed_fen_does_not_convert() {
+        for (fen, error) in [
+            (
+                "8/8/8/8/8/8/8/8 x - - 0 1",
+                PositionError::InvalidSideToMove,
+            ),
+            ("8/8/8/8/8/8/8/8 w - - 0 0", PositionError::MalformedFen),
+            (
+                "8/8/8/8/8/8/8/8x w - - 0 1",
+                PositionError::InvalidPiecePlacement,
+            ),
+        ] {
+            assert_eq!(
+                ChessPosition::try_from(Position::new(fen)),
+                Err(error),
+                "{fen}"
+            );
+        }
+    }
+
+    #[test]
+    fn mismatched_side_to_move_is_rejected() {
+        let mut chess = ChessPosition::new("8/8/8/8/8/8/8/8 w - - 0 1", 0).expect("valid position");
+        chess.side_to_move = 'b';
+
+        assert_eq!(
+            Position::try_from(chess),
+            Err(PositionError::InvalidSideToMove)
+        );
+    }
+
+    #[test]
+    fn side_and_move_number_are_read_once_from_the_fen() {
+        let position = ChessPosition::new(
+            "r3k2r/pp1n1ppp/2p5/3pP3/8/2N5/PPP2PPP/R3K1NR b Kq - 0 12",
+            23,
+        )
+        .expect("valid position");
+        assert_eq!(position.side(), Color::Black);
+        assert_eq!(position.move_number(), 12);
+
+        for fen in ["8/8/8/8/8/8/8/8 w - - 0 0", "8/8/8/8/8/8/8/8 w - - 0 x"] {
+            assert_eq!(
+                ChessPosition::new(fen, 0),
+                Err(PositionError::MalformedFen),
+                "{fen}"
+            );
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserializing_revalidates_the_fen() {
+        let position = ChessPosition::new("8/8/8/8/8/8/8/8 w - - 0 7", 12).expect("valid position");
+        let json = serde_json::to_string(&position).expect("serializes");
+        assert!(!json.contains("move_number"));
+        let restored: ChessPosition = serde_json::from_str(&json).expect("deserializes");
+        assert_eq!(restored, position);
+        assert_eq!(restored.move_number(), 7);
+
+        let broken = json.replace(" 0 7", " 0 0");
+        assert!(serde_json::from_str::<ChessPosition>(&broken).is_err());
+    }
+
     #[test]
     fn valid_position_is_constructed() {
         let fen = "8/8/8/8/8/8/8/8 w - - 0 1";
@@ -0,0 +1,64 @@
+/// Side to move in a chess position.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Color {
+    /// White is to move.
+    White,
+    /// Black is to move.
+    Black,
+}
+
+impl Color {
+    /// Parse the side-to-move field of a FEN (`w` or `b`).
+    #[must_use]
+    pub fn from_fen_field(field: &str) -> Option<Self> {
+        match field {
+            "w" => Some(Self::White),
+            "b" => Some(Self::Black),
+            _ => None,
+        }
+    }
+
+    /// Distance in plies from the initial position when this side is to move on
+    /// `move_number`. Move numbers below one are treated as the first move.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use review_domain::Color;
+    /// assert_eq!(Color::White.ply_at(1), 0);
+    /// assert_eq!(Color::Black.ply_at(1), 1);
+    /// assert_eq!(Color::White.ply_at(2), 2);
+    /// ```
+    #[must_use]
+    pub const fn ply_at(self, move_number: u32) -> u32 {
+        let completed_moves = move_number.saturating_sub(1);
+        let black_offset = match self {
+            Self::White => 0,
+            Self::Black => 1,
+        };
+        completed_moves
+            .saturating_mul(2)
+            .saturating_add(black_offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_only_fen_side_letters() {
+        assert_eq!(Color::from_fen_field("w"), Some(Color::White));
+        assert_eq!(Color::from_fen_field("b"), Some(Color::Black));
+        assert_eq!(Color::from_fen_field("W"), None);
+        assert_eq!(Color::from_fen_field(""), None);
+    }
+
+    #[test]
+    fn ply_at_counts_from_the_initial_position_without_underflow() {
+        assert_eq!(Color::White.ply_at(0), 0);
+        assert_eq!(Color::Black.ply_at(0), 1);
+        assert_eq!(Color::Black.ply_at(12), 23);
+        assert_eq!(Color::Black.ply_at(u32::MAX), u32::MAX);
+    }
+}
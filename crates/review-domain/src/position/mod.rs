@@ -1,7 +1,11 @@
+mod chess_position;
+mod color;
 mod position_error;
 mod position_id;
 mod position_impl;
 
+pub use self::chess_position::ChessPosition;
+pub use self::color::Color;
 pub use self::position_error::PositionError;
 pub use self::position_id::PositionId;
 pub use self::position_impl::Position;
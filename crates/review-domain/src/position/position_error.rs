@@ -1,13 +1,24 @@
-/// Errors encountered while constructing a [`ChessPosition`].
-#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+use core::fmt;
+
+/// Errors encountered while constructing a [`ChessPosition`](super::ChessPosition).
+#[derive(Debug, PartialEq, Eq)]
 pub enum PositionError {
     /// The FEN string did not provide all required fields.
-    #[error("malformed FEN: expected 6 space-delimited fields")]
     MalformedFen,
     /// The FEN string was missing or contained an invalid side-to-move field.
-    #[error("malformed FEN: missing or invalid side-to-move field")]
     InvalidSideToMove,
     /// The FEN string contained an invalid piece placement field.
-    #[error("malformed FEN: invalid piece placement field")]
     InvalidPiecePlacement,
 }
+
+impl fmt::Display for PositionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::MalformedFen => "malformed FEN: expected 6 space-delimited fields",
+            Self::InvalidSideToMove => "malformed FEN: missing or invalid side-to-move field",
+            Self::InvalidPiecePlacement => "malformed FEN: invalid piece placement field",
+        })
+    }
+}
+
+impl core::error::Error for PositionError {}
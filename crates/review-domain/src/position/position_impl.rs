@@ -1,4 +1,8 @@
-use super::PositionId;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use super::{Color, PositionError, PositionId};
 use crate::hash_with_seed;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -25,12 +29,126 @@ impl Position {
     #[must_use]
     pub fn side_to_move(&self) -> Option<char> {
         self.fen
-            .split(' ')
+            .split_whitespace()
             .nth(1)?
             .chars()
             .next()
             .filter(|c| matches!(c, 'w' | 'b'))
     }
+
+    /// Return the side to move, or `None` if the FEN is malformed.
+    #[must_use]
+    pub fn side(&self) -> Option<Color> {
+        Color::from_fen_field(self.fen.split_whitespace().nth(1)?)
+    }
+
+    /// Return the FEN's full-move number, or `None` when it is missing, not a number, or zero.
+    #[must_use]
+    pub fn move_number(&self) -> Option<u32> {
+        self.fen
+            .split_whitespace()
+            .nth(5)?
+            .parse()
+            .ok()
+            .filter(|number| *number > 0)
+    }
+
+    /// Return the distance in plies from the initial position implied by the side to move and
+    /// move number, or `None` if either is malformed.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use review_domain::Position;
+    /// let after_e4 = Position::new("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1");
+    /// assert_eq!(after_e4.ply(), Some(1));
+    /// ```
+    #[must_use]
+    pub fn ply(&self) -> Option<u32> {
+        Some(self.side()?.ply_at(self.move_number()?))
+    }
+
+    /// Return the colour-swapped mirror of this position.
+    ///
+    /// The board is reflected across the middle of the board so that white's pieces take
+    /// black's place and vice versa; the side to move, castling rights, and en passant square
+    /// are swapped to match, while the move clocks are kept. The result has a fresh id derived
+    /// from the mirrored FEN, and flipping twice returns the original position.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PositionError::MalformedFen`] when the FEN does not have six fields or the en
+    /// passant square is not on the third or sixth rank, [`PositionError::InvalidSideToMove`]
+    /// for a side other than `w` or `b`, and [`PositionError::InvalidPiecePlacement`] when the
+    /// placement does not describe eight ranks of known pieces.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use review_domain::Position;
+    /// let after_e4 = Position::new("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1");
+    /// let mirrored = after_e4.flip().unwrap();
+    /// assert_eq!(mirrored.fen, "rnbqkbnr/pppp1ppp/8/4p3/8/8/PPPPPPPP/RNBQKBNR w KQkq e6 0 1");
+    /// ```
+    pub fn flip(&self) -> Result<Self, PositionError> {
+        let fields: Vec<&str> = self.fen.split_whitespace().collect();
+        let [placement, side, castling, en_passant, halfmove, fullmove] = fields[..] else {
+            return Err(PositionError::MalformedFen);
+        };
+
+        let ranks: Vec<&str> = placement.split('/').collect();
+        if ranks.len() != 8
+            || !placement
+                .chars()
+                .all(|c| "/12345678KQRBNPkqrbnp".contains(c))
+        {
+            return Err(PositionError::InvalidPiecePlacement);
+        }
+        let placement = ranks
+            .iter()
+            .rev()
+            .map(|rank| swap_case(rank))
+            .collect::<Vec<_>>()
+            .join("/");
+
+        let side = match side {
+            "w" => "b",
+            "b" => "w",
+            _ => return Err(PositionError::InvalidSideToMove),
+        };
+
+        let castling = if castling == "-" {
+            castling.to_string()
+        } else {
+            let swapped = swap_case(castling);
+            let (white, black): (String, String) =
+                swapped.chars().partition(char::is_ascii_uppercase);
+            white + &black
+        };
+
+        let en_passant = match en_passant.as_bytes() {
+            b"-" => en_passant.to_string(),
+            [file @ b'a'..=b'h', rank @ (b'3' | b'6')] => {
+                let rank = if *rank == b'3' { '6' } else { '3' };
+                format!("{}{rank}", char::from(*file))
+            }
+            _ => return Err(PositionError::MalformedFen),
+        };
+
+        Ok(Self::new(&format!(
+            "{placement} {side} {castling} {en_passant} {halfmove} {fullmove}"
+        )))
+    }
+}
+
+fn swap_case(text: &str) -> String {
+    text.chars()
+        .map(|c| {
+            if c.is_ascii_uppercase() {
+                c.to_ascii_lowercase()
+            } else {
+                c.to_ascii_uppercase()
+            }
+        })
+        .collect()
 }
 #[cfg(test)]
 mod tests {
@@ -111,6 +229,28 @@ mod tests {
         assert_eq!(pos.side_to_move(), Some('w'));
     }
 
+    #[test]
+    fn side_and_move_number_come_from_the_fen() {
+        let pos = make_position("r3k2r/pp1n1ppp/2p5/3pP3/8/2N5/PPP2PPP/R3K1NR b Kq - 0 12");
+        assert_eq!(pos.side(), Some(Color::Black));
+        assert_eq!(pos.move_number(), Some(12));
+        assert_eq!(pos.ply(), Some(23));
+    }
+
+    #[test]
+    fn move_number_rejects_missing_zero_and_non_numeric_fields() {
+        for fen in [
+            "8/8/8/8/8/8/8/8 w - - 0",
+            "8/8/8/8/8/8/8/8 w - - 0 0",
+            "8/8/8/8/8/8/8/8 w - - 0 x",
+        ] {
+            let pos = make_position(fen);
+            assert_eq!(pos.move_number(), None, "{fen}");
+            assert_eq!(pos.ply(), None, "{fen}");
+        }
+        assert_eq!(make_position("8/8/8/8/8/8/8/8 x - - 0 1").side(), None);
+    }
+
     #[test]
     fn test_clone_and_eq() {
         let fen = "8/8/8/8/8/8/8/8 w - - 0 1";
@@ -127,4 +267,61 @@ mod tests {
         assert!(debug_str.contains("Position"));
         assert!(debug_str.contains("fen"));
     }
+
+    #[test]
+    fn flip_of_start_position_is_the_start_position_with_black_to_move() {
+        let start = make_position("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1");
+        let flipped = start.flip().expect("start position flips");
+        assert_eq!(
+            flipped.fen,
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR b KQkq - 0 1"
+        );
+        assert_eq!(flipped.side_to_move(), Some('b'));
+        assert_ne!(flipped.id, start.id);
+    }
+
+    #[test]
+    fn flip_is_an_involution() {
+        for fen in [
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "r3k2r/pp1n1ppp/2p5/3pP3/8/2N5/PPP2PPP/R3K1NR w Kq d6 0 12",
+            "8/8/4k3/8/8/3K4/8/8 b - - 40 80",
+        ] {
+            let position = make_position(fen);
+            let flipped = position.flip().expect("valid FEN flips");
+            assert_ne!(flipped.fen, position.fen);
+            assert_eq!(flipped.flip().expect("flipped FEN flips"), position);
+        }
+    }
+
+    #[test]
+    fn flip_swaps_castling_rights_and_en_passant_rank() {
+        let flipped = make_position("r3k2r/pp1n1ppp/2p5/3pP3/8/2N5/PPP2PPP/R3K1NR w Kq d6 0 12")
+            .flip()
+            .expect("valid FEN flips");
+        assert_eq!(
+            flipped.fen,
+            "r3k1nr/ppp2ppp/2n5/8/3Pp3/2P5/PP1N1PPP/R3K2R b Qk d3 0 12"
+        );
+    }
+
+    #[test]
+    fn flip_rejects_malformed_fens() {
+        assert_eq!(
+            make_position("8/8/8/8/8/8/8/8 w - -").flip(),
+            Err(PositionError::MalformedFen)
+        );
+        assert_eq!(
+            make_position("8/8/8/8/8/8/8/8 x - - 0 1").flip(),
+            Err(PositionError::InvalidSideToMove)
+        );
+        assert_eq!(
+            make_position("8/8/8/8/8/8/8 w - - 0 1").flip(),
+            Err(PositionError::InvalidPiecePlacement)
+        );
+        assert_eq!(
+            make_position("8/8/8/8/8/8/8/8 w - e4 0 1").flip(),
+            Err(PositionError::MalformedFen)
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use crate::{EdgeId, PositionId};
 
@@ -263,11 +263,130 @@ impl OpeningGraph {
         self.by_edge.get(&edge_id).map(|&idx| &self.moves[idx])
     }
 
+    /// Returns `true` when a move with `edge_id` is stored in the graph.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use review_domain::{OpeningGraph, RepertoireMove, EdgeId, PositionId};
+    /// let graph = OpeningGraph::from_moves(vec![RepertoireMove::new(
+    ///     EdgeId::new(1),
+    ///     PositionId::new(10),
+    ///     PositionId::new(11),
+    ///     "e2e4",
+    /// )]);
+    /// assert!(graph.contains_edge(EdgeId::new(1)));
+    /// assert!(!graph.contains_edge(EdgeId::new(2)));
+    /// ```
+    #[must_use]
+    pub fn contains_edge(&self, edge_id: EdgeId) -> bool {
+        self.by_edge.contains_key(&edge_id)
+    }
+
+    /// Returns `true` when `position` is the parent or child of any stored move.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use review_domain::{OpeningGraph, RepertoireMove, EdgeId, PositionId};
+    /// let graph = OpeningGraph::from_moves(vec![RepertoireMove::new(
+    ///     EdgeId::new(1),
+    ///     PositionId::new(10),
+    ///     PositionId::new(11),
+    ///     "e2e4",
+    /// )]);
+    /// assert!(graph.contains_position(PositionId::new(10)));
+    /// assert!(graph.contains_position(PositionId::new(11)));
+    /// assert!(!graph.contains_position(PositionId::new(12)));
+    /// ```
+    #[must_use]
+    pub fn contains_position(&self, position: PositionId) -> bool {
+        self.outgoing.contains_key(&position) || self.incoming.contains_key(&position)
+    }
+
     /// Iterates over all moves contained in the graph in insertion order.
     pub fn iter(&self) -> impl Iterator<Item = &RepertoireMove> + '_ {
         self.moves.iter()
     }
 
+    /// Number of distinct positions appearing as the parent or child of any move.
+    #[must_use]
+    pub fn position_count(&self) -> usize {
+        self.outgoing
+            .keys()
+            .chain(self.incoming.keys())
+            .collect::<BTreeSet<_>>()
+            .len()
+    }
+
+    /// Positions with outgoing moves but no incoming ones, in id order; where a rendered
+    /// tree starts. Positions that are only reached around a cycle are not roots.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use review_domain::{OpeningGraph, RepertoireMove, EdgeId, PositionId};
+    /// let graph = OpeningGraph::from_moves(vec![
+    ///     RepertoireMove::new(EdgeId(1), PositionId(10), PositionId(11), "e2e4"),
+    ///     RepertoireMove::new(EdgeId(2), PositionId(10), PositionId(12), "d2d4"),
+    ///     RepertoireMove::new(EdgeId(3), PositionId(11), PositionId(13), "e7e5"),
+    /// ]);
+    /// assert_eq!(graph.roots(), vec![PositionId(10)]);
+    /// assert_eq!(graph.leaves(), vec![PositionId(12), PositionId(13)]);
+    /// ```
+    #[must_use]
+    pub fn roots(&self) -> Vec<PositionId> {
+        self.outgoing
+            .keys()
+            .filter(|position| !self.incoming.contains_key(position))
+            .copied()
+            .collect()
+    }
+
+    /// Positions with incoming moves but no outgoing ones, in id order; the ends of lines.
+    #[must_use]
+    pub fn leaves(&self) -> Vec<PositionId> {
+        self.incoming
+            .keys()
+            .filter(|position| !self.outgoing.contains_key(position))
+            .copied()
+            .collect()
+    }
+
+    /// Iterates over positions reached from more than one parent position, in id order.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use review_domain::{OpeningGraph, RepertoireMove, EdgeId, PositionId};
+    /// // 1.d4 Nf6 2.c4 and 1.c4 Nf6 2.d4 reach the same position.
+    /// let graph = OpeningGraph::from_moves(vec![
+    ///     RepertoireMove::new(EdgeId(1), PositionId(1), PositionId(2), "d2d4"),
+    ///     RepertoireMove::new(EdgeId(2), PositionId(2), PositionId(3), "g8f6"),
+    ///     RepertoireMove::new(EdgeId(3), PositionId(3), PositionId(4), "c2c4"),
+    ///     RepertoireMove::new(EdgeId(4), PositionId(1), PositionId(5), "c2c4"),
+    ///     RepertoireMove::new(EdgeId(5), PositionId(5), PositionId(6), "g8f6"),
+    ///     RepertoireMove::new(EdgeId(6), PositionId(6), PositionId(4), "d2d4"),
+    /// ]);
+    /// assert_eq!(graph.transpositions().collect::<Vec<_>>(), vec![PositionId(4)]);
+    /// ```
+    pub fn transpositions(&self) -> impl Iterator<Item = PositionId> + '_ {
+        self.incoming.keys().copied().filter(|&child| {
+            self.parents(child)
+                .map(|mv| mv.parent_id)
+                .collect::<BTreeSet<_>>()
+                .len()
+                > 1
+        })
+    }
+
+    /// Length in moves of the longest line in the graph, ignoring edges that close a cycle.
+    #[must_use]
+    pub fn max_depth(&self) -> usize {
+        let mut height_below = BTreeMap::new();
+        self.outgoing
+            .keys()
+            .map(|&position| self.longest_path(position, Direction::Down, &mut height_below))
+            .max()
+            .unwrap_or(0)
+    }
+
     /// Extracts a subgraph beginning from the specified position and including all
     /// descendant moves.
     ///
@@ -314,6 +433,98 @@ impl OpeningGraph {
 
         subgraph
     }
+
+    /// Removes every edge whose longest root-to-leaf path is shorter than `min_depth` moves,
+    /// returning how many edges were dropped.
+    ///
+    /// Roots are positions without parents and leaves are positions without children. An
+    /// edge survives as long as at least one path through it reaches `min_depth`, so shared
+    /// prefixes of a long line are kept while the stubs branching off them are pruned. The
+    /// adjacency indices are rebuilt from the surviving moves in their original order.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use review_domain::{OpeningGraph, RepertoireMove, EdgeId, PositionId};
+    /// let mut graph = OpeningGraph::from_moves(vec![
+    ///     RepertoireMove::new(EdgeId(1), PositionId(10), PositionId(11), "e2e4"),
+    ///     RepertoireMove::new(EdgeId(2), PositionId(11), PositionId(12), "e7e5"),
+    ///     RepertoireMove::new(EdgeId(3), PositionId(11), PositionId(13), "c7c5"),
+    ///     RepertoireMove::new(EdgeId(4), PositionId(13), PositionId(14), "g1f3"),
+    /// ]);
+    ///
+    /// // The 1.e4 e5 stub is two moves long; the Sicilian line is three.
+    /// assert_eq!(graph.prune_shorter_than(3), 1);
+    /// assert_eq!(graph.edge(EdgeId(2)), None);
+    /// assert_eq!(graph.children(PositionId(11)).count(), 1);
+    /// ```
+    pub fn prune_shorter_than(&mut self, min_depth: usize) -> usize {
+        let mut depth_above = BTreeMap::new();
+        let mut height_below = BTreeMap::new();
+        let keep: Vec<bool> = self
+            .moves
+            .iter()
+            .map(|mv| {
+                let above = self.longest_path(mv.parent_id, Direction::Up, &mut depth_above);
+                let below = self.longest_path(mv.child_id, Direction::Down, &mut height_below);
+                above + 1 + below >= min_depth
+            })
+            .collect();
+
+        let before = self.moves.len();
+        let survivors = std::mem::take(&mut self.moves)
+            .into_iter()
+            .zip(keep)
+            .filter_map(|(mv, keep)| keep.then_some(mv));
+        *self = Self::from_moves(survivors.collect());
+        before - self.moves.len()
+    }
+
+    /// Length in edges of the longest path from `position` to a root (`Up`) or leaf (`Down`).
+    ///
+    /// Edges that close a cycle are ignored so repeated positions cannot recurse forever.
+    fn longest_path(
+        &self,
+        position: PositionId,
+        direction: Direction,
+        memo: &mut BTreeMap<PositionId, usize>,
+    ) -> usize {
+        let mut visiting = BTreeSet::new();
+        self.longest_path_inner(position, direction, memo, &mut visiting)
+    }
+
+    fn longest_path_inner(
+        &self,
+        position: PositionId,
+        direction: Direction,
+        memo: &mut BTreeMap<PositionId, usize>,
+        visiting: &mut BTreeSet<PositionId>,
+    ) -> usize {
+        if let Some(&length) = memo.get(&position) {
+            return length;
+        }
+        if !visiting.insert(position) {
+            return 0;
+        }
+        let next: Vec<PositionId> = match direction {
+            Direction::Up => self.parents(position).map(|mv| mv.parent_id).collect(),
+            Direction::Down => self.children(position).map(|mv| mv.child_id).collect(),
+        };
+        let length = next
+            .into_iter()
+            .map(|neighbour| 1 + self.longest_path_inner(neighbour, direction, memo, visiting))
+            .max()
+            .unwrap_or(0);
+        visiting.remove(&position);
+        memo.insert(position, length);
+        length
+    }
+}
+
+/// Traversal direction used when measuring path lengths.
+#[derive(Clone, Copy)]
+enum Direction {
+    Up,
+    Down,
 }
 
 #[cfg(test)]
@@ -352,4 +563,138 @@ mod tests {
         let fetched = graph.edge(mv.edge_id).expect("edge present");
         assert_eq!(fetched.move_uci, mv.move_uci);
     }
+
+    #[test]
+    fn membership_queries_track_pruned_moves() {
+        let mut graph = OpeningGraph::from_moves(vec![
+            sample_move(1, 1, 2),
+            sample_move(2, 2, 3),
+            sample_move(3, 1, 4),
+        ]);
+        assert!(graph.contains_edge(EdgeId::new(3)));
+        assert!(graph.contains_position(PositionId::new(4)));
+
+        assert_eq!(graph.prune_shorter_than(2), 1);
+
+        assert!(!graph.contains_edge(EdgeId::new(3)));
+        assert!(!graph.contains_position(PositionId::new(4)));
+        assert!(graph.contains_position(PositionId::new(1)));
+        assert!(graph.contains_position(PositionId::new(3)));
+    }
+
+    #[test]
+    fn empty_graph_has_no_positions_depth_or_transpositions() {
+        let graph = OpeningGraph::new();
+        assert_eq!(graph.position_count(), 0);
+        assert_eq!(graph.max_depth(), 0);
+        assert_eq!(graph.transpositions().count(), 0);
+    }
+
+    #[test]
+    fn branching_graph_reports_every_root_and_leaf_in_id_order() {
+        // Two separate trees (roots 7 and 1) that transpose into 4, plus a cycle 5 <-> 6.
+        let graph = OpeningGraph::from_moves(vec![
+            sample_move(1, 7, 9),
+            sample_move(2, 1, 2),
+            sample_move(3, 1, 3),
+            sample_move(4, 2, 4),
+            sample_move(5, 9, 4),
+            sample_move(6, 4, 8),
+            sample_move(7, 3, 5),
+            sample_move(8, 5, 6),
+            sample_move(9, 6, 5),
+        ]);
+
+        assert_eq!(graph.roots(), vec![PositionId::new(1), PositionId::new(7)]);
+        assert_eq!(graph.leaves(), vec![PositionId::new(8)]);
+
+        let empty = OpeningGraph::new();
+        assert!(empty.roots().is_empty());
+        assert!(empty.leaves().is_empty());
+    }
+
+    #[test]
+    fn pruning_updates_roots_and_leaves() {
+        let mut graph = OpeningGraph::from_moves(vec![
+            sample_move(1, 1, 2),
+            sample_move(2, 2, 3),
+            sample_move(3, 1, 4),
+            sample_move(4, 10, 11),
+        ]);
+        assert_eq!(graph.roots(), vec![PositionId::new(1), PositionId::new(10)]);
+        assert_eq!(
+            graph.leaves(),
+            vec![PositionId::new(3), PositionId::new(4), PositionId::new(11)]
+        );
+
+        graph.prune_shorter_than(2);
+
+        assert_eq!(graph.roots(), vec![PositionId::new(1)]);
+        assert_eq!(graph.leaves(), vec![PositionId::new(3)]);
+    }
+
+    #[test]
+    fn parallel_moves_between_the_same_positions_are_not_transpositions() {
+        let graph = OpeningGraph::from_moves(vec![sample_move(1, 1, 2), sample_move(2, 1, 2)]);
+        assert_eq!(graph.transpositions().count(), 0);
+        assert_eq!(graph.position_count(), 2);
+        assert_eq!(graph.max_depth(), 1);
+    }
+
+    #[test]
+    fn prune_shorter_than_drops_stubs_but_keeps_the_main_line() {
+        // Main line 1-2-3-4-5 with stubs hanging off the root and off the second move.
+        let mut graph = OpeningGraph::from_moves(vec![
+            sample_move(1, 1, 2),
+            sample_move(2, 2, 3),
+            sample_move(3, 3, 4),
+            sample_move(4, 4, 5),
+            sample_move(5, 1, 6),
+            sample_move(6, 3, 7),
+            sample_move(7, 6, 8),
+        ]);
+
+        assert_eq!(graph.prune_shorter_than(4), 3);
+        let remaining: Vec<_> = graph.iter().map(|mv| mv.edge_id).collect();
+        assert_eq!(remaining, (1..=4).map(EdgeId::new).collect::<Vec<_>>());
+        assert_eq!(graph.children(PositionId::new(1)).count(), 1);
+        assert_eq!(graph.children(PositionId::new(3)).count(), 1);
+        assert_eq!(graph.parents(PositionId::new(7)).count(), 0);
+        assert_eq!(graph.edge(EdgeId::new(6)), None);
+        assert_eq!(
+            graph.edge(EdgeId::new(4)).map(|mv| mv.child_id),
+            Some(PositionId::new(5))
+        );
+    }
+
+    #[test]
+    fn prune_shorter_than_keeps_edges_shared_with_longer_paths() {
+        // Two roots transpose into position 3; only the long continuation keeps both.
+        let mut graph = OpeningGraph::from_moves(vec![
+            sample_move(1, 1, 3),
+            sample_move(2, 2, 3),
+            sample_move(3, 3, 4),
+            sample_move(4, 4, 5),
+            sample_move(5, 3, 6),
+        ]);
+
+        assert_eq!(graph.prune_shorter_than(3), 1);
+        assert_eq!(graph.len(), 4);
+        assert_eq!(graph.parents(PositionId::new(3)).count(), 2);
+        assert_eq!(graph.prune_shorter_than(3), 0);
+        assert_eq!(graph.prune_shorter_than(0), 0);
+        assert_eq!(graph.prune_shorter_than(4), 4);
+        assert!(graph.is_empty());
+    }
+
+    #[test]
+    fn prune_shorter_than_terminates_on_cycles() {
+        let mut graph = OpeningGraph::from_moves(vec![
+            sample_move(1, 1, 2),
+            sample_move(2, 2, 3),
+            sample_move(3, 3, 2),
+        ]);
+
+        assert_eq!(graph.prune_shorter_than(10), 3);
+    }
 }
@@ -4,8 +4,10 @@ pub mod graph;
 pub mod move_;
 pub mod repertoire_;
 pub mod repertoire_error;
+pub mod stats;
 
 pub use graph::OpeningGraph;
 pub use move_::RepertoireMove;
 pub use repertoire_::Repertoire;
 pub use repertoire_error::RepertoireError;
+pub use stats::RepertoireStats;
@@ -2,7 +2,7 @@ use derive_builder::Builder;
 use std::iter::FromIterator;
 
 use crate::ids::EdgeId;
-use crate::{OpeningGraph, RepertoireError, RepertoireMove};
+use crate::{OpeningGraph, RepertoireError, RepertoireMove, RepertoireStats};
 
 /// Aggregated store for the opening moves a student has committed to memory.
 #[derive(Clone, Debug, PartialEq, Eq, Default, Builder)]
@@ -51,6 +51,17 @@ impl Repertoire {
         &self.graph
     }
 
+    /// Summarises the repertoire's size and shape for dashboards.
+    #[must_use]
+    pub fn stats(&self) -> RepertoireStats {
+        RepertoireStats {
+            total_moves: self.graph.len(),
+            distinct_positions: self.graph.position_count(),
+            max_depth: self.graph.max_depth(),
+            transpositions: self.graph.transpositions().count(),
+        }
+    }
+
     /// Placeholder stub for inserting a move into the repertoire.
     ///
     /// The implementation will later enforce business rules around duplicates and merge
@@ -0,0 +1,13 @@
+/// At-a-glance counts describing the shape of a [`Repertoire`](crate::Repertoire).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RepertoireStats {
+    /// Number of moves (edges) in the repertoire.
+    pub total_moves: usize,
+    /// Number of distinct positions the moves connect.
+    pub distinct_positions: usize,
+    /// Length in moves of the longest line.
+    pub max_depth: usize,
+    /// Number of positions reached from more than one parent position.
+    pub transpositions: usize,
+}
@@ -11,6 +11,9 @@ pub struct ReviewRequest {
     pub reviewed_on: NaiveDate,
     /// Grade (0-4) awarded by the learner.
     pub grade: u8,
+    /// Client-chosen key that makes retried submissions safe: a store that already applied
+    /// this key to the card returns the earlier result instead of reviewing it again.
+    pub idempotency_key: Option<String>,
 }
 
 #[cfg(test)]
@@ -23,6 +26,7 @@ mod tests {
             card_id: 42,
             reviewed_on: NaiveDate::from_ymd_opt(2023, 1, 1).expect("valid date"),
             grade: 4,
+            idempotency_key: None,
         };
         assert_eq!(request.card_id, 42);
         assert_eq!(request.grade, 4);
@@ -1,7 +1,22 @@
 //! Review grades supported by the review domain.
+//!
+//! [`ReviewGrade`] is the four-button view of the numeric [`Grade`] scale. Numeric input is
+//! validated once, by [`Grade::from_u8`], and converted with the `From` impls below:
+//!
+//! | `Grade`         | `ReviewGrade` |
+//! |-----------------|---------------|
+//! | `Zero`, `One`   | `Again`       |
+//! | `Two`           | `Hard`        |
+//! | `Three`         | `Good`        |
+//! | `Four`          | `Easy`        |
+//!
+//! Converting back yields the lowest matching grade, so `Again` becomes `Grade::Zero`.
+
+use crate::{Grade, GradeError};
 
 /// Possible outcomes of a learner's review session.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ReviewGrade {
     /// The user failed to recall the item; schedule for immediate relearning.
     Again,
@@ -13,13 +28,77 @@ pub enum ReviewGrade {
     Easy,
 }
 
+impl ReviewGrade {
+    /// Parses a numeric 0-4 grade using the shared [`Grade`] validation.
+    ///
+    /// # Errors
+    /// Returns `GradeError::GradeOutsideRangeError` for values above 4.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use review_domain::ReviewGrade;
+    /// assert_eq!(ReviewGrade::from_u8(1), Ok(ReviewGrade::Again));
+    /// assert!(ReviewGrade::from_u8(5).is_err());
+    /// ```
+    pub fn from_u8(grade: u8) -> Result<Self, GradeError> {
+        Grade::from_u8(grade).map(Self::from)
+    }
+}
+
+impl From<Grade> for ReviewGrade {
+    fn from(grade: Grade) -> Self {
+        match grade {
+            Grade::Zero | Grade::One => ReviewGrade::Again,
+            Grade::Two => ReviewGrade::Hard,
+            Grade::Three => ReviewGrade::Good,
+            Grade::Four => ReviewGrade::Easy,
+        }
+    }
+}
+
+impl From<ReviewGrade> for Grade {
+    fn from(grade: ReviewGrade) -> Self {
+        match grade {
+            ReviewGrade::Again => Grade::Zero,
+            ReviewGrade::Hard => Grade::Two,
+            ReviewGrade::Good => Grade::Three,
+            ReviewGrade::Easy => Grade::Four,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::ReviewGrade;
+    use super::*;
 
     #[test]
     fn grades_are_comparable() {
         assert_eq!(ReviewGrade::Again, ReviewGrade::Again);
         assert_ne!(ReviewGrade::Hard, ReviewGrade::Easy);
     }
+
+    #[test]
+    fn conversions_follow_the_documented_table() {
+        for (value, expected) in [
+            (0, ReviewGrade::Again),
+            (1, ReviewGrade::Again),
+            (2, ReviewGrade::Hard),
+            (3, ReviewGrade::Good),
+            (4, ReviewGrade::Easy),
+        ] {
+            assert_eq!(ReviewGrade::from_u8(value), Ok(expected));
+        }
+        for grade in [
+            ReviewGrade::Again,
+            ReviewGrade::Hard,
+            ReviewGrade::Good,
+            ReviewGrade::Easy,
+        ] {
+            assert_eq!(ReviewGrade::from(Grade::from(grade)), grade);
+        }
+        assert_eq!(
+            ReviewGrade::from_u8(5),
+            Err(GradeError::GradeOutsideRangeError { grade: 5 })
+        );
+    }
 }
@@ -2,6 +2,7 @@
 
 /// High level progress state of a review card.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum StudyStage {
     /// The card has never been studied; it is new to the learner.
     New,
@@ -14,6 +15,8 @@ pub enum StudyStage {
     /// The card is temporarily inactive and will not be scheduled for review.
     /// (Not currently used in scheduling logic.)
     Suspended,
+    /// The card is mastered and no longer scheduled until it is reactivated.
+    Retired,
 }
 
 impl StudyStage {
@@ -26,6 +29,7 @@ impl StudyStage {
             'R' | 'r' => Some(Self::Review),
             'E' | 'e' => Some(Self::Relearning),
             'S' | 's' => Some(Self::Suspended),
+            'T' | 't' => Some(Self::Retired),
             _ => None,
         }
     }
@@ -68,6 +72,12 @@ impl StudyStage {
     pub fn is_suspended(&self) -> bool {
         matches!(self, StudyStage::Suspended)
     }
+
+    /// Returns true if the card is in the Retired stage.
+    #[must_use]
+    pub fn is_retired(&self) -> bool {
+        matches!(self, StudyStage::Retired)
+    }
 }
 
 #[cfg(test)]
@@ -90,6 +100,9 @@ mod tests {
         assert!(!StudyStage::New.is_active());
         assert!(StudyStage::Suspended.is_suspended());
         assert!(!StudyStage::New.is_suspended());
+        assert!(StudyStage::Retired.is_retired());
+        assert!(!StudyStage::Retired.is_active());
+        assert!(!StudyStage::Suspended.is_retired());
     }
 
     #[test]
@@ -99,6 +112,7 @@ mod tests {
         assert_eq!(StudyStage::from_char('R'), Some(StudyStage::Review));
         assert_eq!(StudyStage::from_char('e'), Some(StudyStage::Relearning));
         assert_eq!(StudyStage::from_char('S'), Some(StudyStage::Suspended));
+        assert_eq!(StudyStage::from_char('t'), Some(StudyStage::Retired));
         assert_eq!(StudyStage::from_char('x'), None);
     }
 
@@ -1,11 +1,13 @@
 //! Shared unlock record representation.
 
+use alloc::string::String;
 use chrono::NaiveDate;
 
 use crate::{EdgeId, OpeningEdgeHandle};
 
 /// Represents a record of new study material being unlocked for a learner.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UnlockRecord<Owner, Detail> {
     /// Identifier of the learner receiving the unlock.
     pub owner_id: Owner,
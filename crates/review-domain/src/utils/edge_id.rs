@@ -0,0 +1,42 @@
+use super::hash_with_seed::hash_parts_with_seed;
+
+/// Derives the canonical identifier of the opening edge from `parent_id` to `child_id` via
+/// `move_uci`.
+///
+/// Every crate that creates opening edges goes through this function, so an edge imported from
+/// PGN and the same edge created directly in a card store share one identifier. The child is
+/// part of the key, so two edges that play the same move from the same parent but record
+/// different children get distinct ids; stores detect that conflict by looking the edge up by
+/// parent and move instead.
+///
+/// # Examples
+/// ```rust
+/// use review_domain::edge_id_for;
+///
+/// assert_eq!(edge_id_for(7, 8, "g1f3"), edge_id_for(7, 8, "g1f3"));
+/// assert_ne!(edge_id_for(7, 8, "g1f3"), edge_id_for(7, 9, "g1f3"));
+/// assert_ne!(edge_id_for(7, 8, "g1f3"), edge_id_for(7, 8, "d2d4"));
+/// ```
+#[must_use]
+pub fn edge_id_for(parent_id: u64, child_id: u64, move_uci: &str) -> u64 {
+    hash_parts_with_seed(&[
+        &parent_id.to_le_bytes(),
+        &child_id.to_le_bytes(),
+        move_uci.as_bytes(),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn id_depends_on_parent_child_and_move() {
+        let base = edge_id_for(1, 2, "e2e4");
+
+        assert_eq!(base, edge_id_for(1, 2, "e2e4"));
+        assert_ne!(base, edge_id_for(2, 2, "e2e4"));
+        assert_ne!(base, edge_id_for(1, 3, "e2e4"));
+        assert_ne!(base, edge_id_for(1, 2, "d2d4"));
+    }
+}
@@ -9,11 +9,17 @@ const HASH_NAMESPACE: u64 = 0x0123_4567_89ab_cdef;
 /// Panics if the hash output cannot be converted to a u64 (should never happen with blake3).
 #[must_use]
 pub fn hash_with_seed(input: &str) -> u64 {
-    let mut seed_bytes = HASH_NAMESPACE.to_le_bytes().to_vec();
-    seed_bytes.push(SCHEMA_VERSION);
+    hash_parts_with_seed(&[input.as_bytes()])
+}
+
+/// Hashes `parts` in order like [`hash_with_seed`] hashes their concatenation.
+pub(crate) fn hash_parts_with_seed(parts: &[&[u8]]) -> u64 {
     let mut hasher = Hasher::new();
-    hasher.update(&seed_bytes);
-    hasher.update(input.as_bytes());
+    hasher.update(&HASH_NAMESPACE.to_le_bytes());
+    hasher.update(&[SCHEMA_VERSION]);
+    for part in parts {
+        hasher.update(part);
+    }
     let hash = hasher.finalize();
     u64::from_le_bytes(hash.as_bytes()[..8].try_into().unwrap())
 }
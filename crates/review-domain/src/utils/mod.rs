@@ -1,3 +1,5 @@
+pub mod edge_id;
 pub mod hash_with_seed;
 
+pub use edge_id::edge_id_for;
 pub use hash_with_seed::hash_with_seed;
@@ -1,3 +1,5 @@
+#![cfg(feature = "alloc")]
+
 use review_domain::ids::UnlockId;
 use review_domain::{CardId, EdgeId, IdConversionError, LearnerId, MoveId, PositionId, TacticId};
 
@@ -1,5 +1,7 @@
+#![cfg(feature = "std")]
+
 use review_domain::{
-    repertoire::{Repertoire, RepertoireError, RepertoireMove},
+    repertoire::{Repertoire, RepertoireError, RepertoireMove, RepertoireStats},
     {EdgeId, PositionId},
 };
 
@@ -107,3 +109,39 @@ fn repertoire_serializes_to_json() {
     let json = serde_json::to_string(&repertoire).expect("serialization succeeds");
     assert!(json.contains("catalan"));
 }
+
+#[test]
+fn stats_count_moves_positions_depth_and_transpositions() {
+    let mv = |edge, parent, child, uci| {
+        RepertoireMove::new(
+            EdgeId::new(edge),
+            PositionId::new(parent),
+            PositionId::new(child),
+            uci,
+        )
+    };
+    // 1.d4 Nf6 2.c4 and 1.c4 Nf6 2.d4 transpose into position 4, which continues 2...e6.
+    let repertoire = Repertoire::builder("Indian")
+        .extend([
+            mv(1, 1, 2, "d2d4"),
+            mv(2, 2, 3, "g8f6"),
+            mv(3, 3, 4, "c2c4"),
+            mv(4, 1, 5, "c2c4"),
+            mv(5, 5, 6, "g8f6"),
+            mv(6, 6, 4, "d2d4"),
+            mv(7, 4, 7, "e7e6"),
+        ])
+        .build()
+        .expect("repertoire builds");
+
+    assert_eq!(
+        repertoire.stats(),
+        RepertoireStats {
+            total_moves: 7,
+            distinct_positions: 7,
+            max_depth: 4,
+            transpositions: 1,
+        }
+    );
+    assert_eq!(Repertoire::new("empty").stats(), RepertoireStats::default());
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "alloc")]
+
 use review_domain::study_stage;
 
 #[test]
@@ -72,6 +72,27 @@ fn to_grade_delta_returns_supermemo_values() {
     assert_is_close!(Grade::Four.to_grade_delta(), 0.15, TEST_EPSILON);
 }
 
+#[test]
+fn short_labels_and_descriptions_are_stable() {
+    let table: Vec<_> = (0..=4)
+        .map(|value| {
+            let grade = Grade::from_u8(value).expect("valid grade");
+            (grade.short_label(), grade.description())
+        })
+        .collect();
+
+    assert_eq!(
+        table,
+        [
+            ("✗", "Forgot completely"),
+            ("✗", "Forgot, but recognised the answer"),
+            ("~", "Recalled with difficulty"),
+            ("✓", "Recalled correctly"),
+            ("✓✓", "Recalled effortlessly"),
+        ]
+    );
+}
+
 #[test]
 fn try_from_accepts_valid_grades() {
     for (value, expected) in [
@@ -95,6 +116,39 @@ fn try_from_rejects_invalid_grades() {
     }
 }
 
+#[test]
+fn from_str_accepts_digits_and_review_words() {
+    for (input, expected) in [
+        ("0", Grade::Zero),
+        ("1", Grade::One),
+        (" 4\n", Grade::Four),
+        ("again", Grade::Zero),
+        ("HARD", Grade::Two),
+        ("Good", Grade::Three),
+        (" easy ", Grade::Four),
+    ] {
+        assert_eq!(input.parse::<Grade>(), Ok(expected), "input {input:?}");
+    }
+}
+
+#[test]
+fn from_str_rejects_unknown_text_and_out_of_range_digits() {
+    assert_eq!(
+        err_variant("5".parse::<Grade>().expect_err("digit above range")),
+        ErrVariant::OutsideRange(5)
+    );
+    for input in ["", "fail", "perfect", "300", "-1"] {
+        let err = input
+            .parse::<Grade>()
+            .expect_err("unknown text should fail");
+        assert_eq!(
+            err_variant(err),
+            ErrVariant::Unrecognized,
+            "input {input:?}"
+        );
+    }
+}
+
 #[test]
 fn grade_error_equality_distinguishes_variants() {
     let outside = GradeError::GradeOutsideRangeError { grade: 7 };
@@ -113,12 +167,16 @@ fn grade_error_equality_matches_invalid_variant() {
 enum ErrVariant {
     OutsideRange(u8),
     Invalid(u8),
+    Unrecognized,
+    InvalidConfidence,
 }
 
 fn err_variant(error: GradeError) -> ErrVariant {
     match error {
         GradeError::GradeOutsideRangeError { grade } => ErrVariant::OutsideRange(grade),
         GradeError::InvalidGradeError { grade } => ErrVariant::Invalid(grade),
+        GradeError::UnrecognizedGradeError => ErrVariant::Unrecognized,
+        GradeError::InvalidConfidenceError => ErrVariant::InvalidConfidence,
     }
 }
 
@@ -126,5 +184,7 @@ fn err_label(error: GradeError) -> &'static str {
     match error {
         GradeError::GradeOutsideRangeError { .. } => "GradeOutsideRangeError",
         GradeError::InvalidGradeError { .. } => "InvalidGradeError",
+        GradeError::UnrecognizedGradeError => "UnrecognizedGradeError",
+        GradeError::InvalidConfidenceError => "InvalidConfidenceError",
     }
 }
@@ -0,0 +1,116 @@
+//! Per-owner log of the days on which reviews were recorded, used for streak tracking.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::{Days, NaiveDate};
+use uuid::Uuid;
+
+/// Days with at least one recorded review, grouped by owner.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ActivityLog {
+    days: BTreeMap<Uuid, BTreeSet<NaiveDate>>,
+}
+
+impl ActivityLog {
+    /// Creates an empty log.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Marks `day` as active for `owner_id`. Recording the same day again has no effect.
+    pub fn record(&mut self, owner_id: Uuid, day: NaiveDate) {
+        self.days.entry(owner_id).or_default().insert(day);
+    }
+
+    /// Returns `true` when `owner_id` recorded at least one review on `day`.
+    #[must_use]
+    pub fn did_review_on(&self, owner_id: Uuid, day: NaiveDate) -> bool {
+        self.days
+            .get(&owner_id)
+            .is_some_and(|days| days.contains(&day))
+    }
+
+    /// Every active day recorded for `owner_id`, oldest first.
+    #[must_use]
+    pub fn active_days(&self, owner_id: Uuid) -> BTreeSet<NaiveDate> {
+        self.days.get(&owner_id).cloned().unwrap_or_default()
+    }
+
+    /// Counts consecutive active days for `owner_id`, ending today or yesterday.
+    ///
+    /// A streak whose last review was yesterday is still current, because today's review
+    /// may not have happened yet.
+    #[must_use]
+    pub fn current_streak(&self, owner_id: Uuid, today: NaiveDate) -> u32 {
+        self.days
+            .get(&owner_id)
+            .map_or(0, |days| streak_ending(days, today))
+    }
+}
+
+/// Counts consecutive days in `days` ending today, or yesterday when today is absent.
+pub(crate) fn streak_ending(days: &BTreeSet<NaiveDate>, today: NaiveDate) -> u32 {
+    let mut cursor = if days.contains(&today) {
+        today
+    } else {
+        match today.checked_sub_days(Days::new(1)) {
+            Some(yesterday) => yesterday,
+            None => return 0,
+        }
+    };
+    let mut streak = 0;
+    while days.contains(&cursor) {
+        streak += 1;
+        match cursor.checked_sub_days(Days::new(1)) {
+            Some(previous) => cursor = previous,
+            None => break,
+        }
+    }
+    streak
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive_date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).expect("valid date")
+    }
+
+    #[test]
+    fn continuing_streak_counts_back_from_today_or_yesterday() {
+        let owner = Uuid::new_v4();
+        let mut log = ActivityLog::new();
+        for day in [7, 8, 9, 9] {
+            log.record(owner, naive_date(2024, 1, day));
+        }
+
+        assert!(log.did_review_on(owner, naive_date(2024, 1, 8)));
+        assert_eq!(log.current_streak(owner, naive_date(2024, 1, 9)), 3);
+        assert_eq!(log.current_streak(owner, naive_date(2024, 1, 10)), 3);
+        log.record(owner, naive_date(2024, 1, 10));
+        assert_eq!(log.current_streak(owner, naive_date(2024, 1, 10)), 4);
+    }
+
+    #[test]
+    fn broken_streak_resets_and_owners_are_independent() {
+        let owner = Uuid::new_v4();
+        let other = Uuid::new_v4();
+        let mut log = ActivityLog::new();
+        for day in [1, 2, 3, 5] {
+            log.record(owner, naive_date(2024, 1, day));
+        }
+        log.record(other, naive_date(2024, 1, 6));
+
+        assert_eq!(log.current_streak(owner, naive_date(2024, 1, 5)), 1);
+        assert_eq!(log.current_streak(owner, naive_date(2024, 1, 7)), 0);
+        assert!(!log.did_review_on(owner, naive_date(2024, 1, 4)));
+        assert_eq!(log.current_streak(other, naive_date(2024, 1, 6)), 1);
+        assert_eq!(
+            log.current_streak(Uuid::new_v4(), naive_date(2024, 1, 6)),
+            0
+        );
+    }
+}
@@ -1,24 +1,99 @@
 //! Scheduler configuration values governing SM-2 calculations and unlock policy.
 
+use num_traits::ToPrimitive;
+use review_domain::{DEFAULT_INITIAL_EASE_FACTOR, EaseBounds, NewCardDue};
+
+/// Ordering applied when merging newly unlocked cards with cards already in rotation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QueueLayout {
+    /// All due learning and review cards, followed by newly unlocked cards.
+    #[default]
+    ReviewsFirst,
+    /// Newly unlocked cards, followed by all due learning and review cards.
+    NewFirst,
+    /// One newly unlocked card after every `new_every` due cards. Leftover new cards are
+    /// appended once the due cards run out; a value of zero is treated as one.
+    Interleaved {
+        /// Number of due cards presented between consecutive new cards.
+        new_every: usize,
+    },
+}
+
+/// How a fractional `interval * ease` product is converted to whole days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RoundingPolicy {
+    /// Nearest whole day, with halves rounded away from zero (`12.5` becomes `13`).
+    #[default]
+    Round,
+    /// Always round down, so spacing grows more slowly.
+    Floor,
+    /// Always round up, so spacing grows faster.
+    Ceil,
+}
+
+impl RoundingPolicy {
+    /// Products this close to a whole day count as that day, so `f32` ease noise
+    /// (`10 * 2.3` evaluating to `22.9999995`) does not move `Floor` or `Ceil` by a day.
+    const WHOLE_DAY_TOLERANCE: f64 = 1e-3;
+
+    /// Converts a fractional number of days to a whole number of days under this policy.
+    ///
+    /// Negative and NaN inputs give zero days; results beyond `u32::MAX` saturate.
+    #[must_use]
+    pub fn apply(self, days: f64) -> u32 {
+        let nearest = days.round();
+        let whole = if (days - nearest).abs() <= Self::WHOLE_DAY_TOLERANCE {
+            nearest
+        } else {
+            match self {
+                Self::Round => nearest,
+                Self::Floor => days.floor(),
+                Self::Ceil => days.ceil(),
+            }
+        };
+        whole
+            .clamp(0.0, f64::from(u32::MAX))
+            .to_u32()
+            .unwrap_or_default()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct SchedulerConfig {
     /// Ease factor assigned to new cards on creation.
     pub initial_ease_factor: f32,
-    /// Lower bound applied to the ease factor after reviews.
-    pub ease_minimum: f32,
-    /// Upper bound applied to the ease factor after reviews.
-    pub ease_maximum: f32,
+    /// Floor and ceiling applied to the ease factor after reviews. Defaults to
+    /// [`EaseBounds::default`]; card-store's `StorageConfig::from_scheduler` copies it so the
+    /// store clamps to the same bounds.
+    pub ease_bounds: EaseBounds,
     /// Minutes before each successive learning step becomes due.
     pub learning_steps_minutes: Vec<u32>,
+    /// How newly unlocked cards are merged into the daily queue.
+    pub queue_layout: QueueLayout,
+    /// Whether cards built by [`new_card`](crate::new_card) are due today or tomorrow.
+    pub new_card_due: NewCardDue,
+    /// Cap on cards an owner may introduce per day, counting unlocks and cards promoted out
+    /// of `New` by review. `None` leaves unlocks unlimited.
+    pub max_new_per_day: Option<usize>,
+    /// Interval, in days, at which a `Review` card counts as mastered and
+    /// [`Scheduler::retire_mastered_cards`](crate::Scheduler::retire_mastered_cards) retires it.
+    pub mastery_interval_days: u32,
+    /// How scaled review intervals (`interval * ease`) are converted to whole days.
+    /// Defaults to [`RoundingPolicy::Round`].
+    pub interval_rounding: RoundingPolicy,
 }
 
 impl Default for SchedulerConfig {
     fn default() -> Self {
         Self {
-            initial_ease_factor: 2.5,
-            ease_minimum: 1.3,
-            ease_maximum: 2.8,
+            initial_ease_factor: DEFAULT_INITIAL_EASE_FACTOR,
+            ease_bounds: EaseBounds::default(),
             learning_steps_minutes: vec![1, 10],
+            queue_layout: QueueLayout::default(),
+            new_card_due: NewCardDue::default(),
+            max_new_per_day: None,
+            mastery_interval_days: 180,
+            interval_rounding: RoundingPolicy::default(),
         }
     }
 }
@@ -35,8 +110,48 @@ mod tests {
     fn default_configuration_matches_expected_values() {
         let config = SchedulerConfig::default();
         assert!(approx_eq(config.initial_ease_factor, 2.5));
-        assert!(approx_eq(config.ease_minimum, 1.3));
-        assert!(approx_eq(config.ease_maximum, 2.8));
+        assert!(approx_eq(config.ease_bounds.minimum, 1.3));
+        assert!(approx_eq(config.ease_bounds.maximum, 2.8));
+        assert_eq!(config.ease_bounds, EaseBounds::default());
         assert_eq!(config.learning_steps_minutes, vec![1, 10]);
+        assert_eq!(config.queue_layout, QueueLayout::ReviewsFirst);
+        assert_eq!(config.new_card_due, NewCardDue::Today);
+        assert_eq!(config.max_new_per_day, None);
+        assert_eq!(config.mastery_interval_days, 180);
+        assert_eq!(config.interval_rounding, RoundingPolicy::Round);
+    }
+
+    #[test]
+    fn rounding_policies_agree_on_whole_products() {
+        for policy in [
+            RoundingPolicy::Round,
+            RoundingPolicy::Floor,
+            RoundingPolicy::Ceil,
+        ] {
+            assert_eq!(policy.apply(6.0 * 2.5), 15, "{policy:?}");
+            let noisy = 10.0 * f64::from(2.3_f32);
+            assert_eq!(policy.apply(noisy), 23, "{policy:?}");
+        }
+    }
+
+    #[test]
+    fn rounding_policies_differ_on_fractional_products() {
+        let cases = [
+            (RoundingPolicy::Round, [12, 13, 13]),
+            (RoundingPolicy::Floor, [12, 12, 12]),
+            (RoundingPolicy::Ceil, [13, 13, 13]),
+        ];
+        for (policy, expected) in cases {
+            let actual = [12.2, 12.5, 12.8].map(|days| policy.apply(days));
+            assert_eq!(actual, expected, "{policy:?}");
+        }
+    }
+
+    #[test]
+    fn rounding_saturates_out_of_range_products() {
+        let policy = RoundingPolicy::Ceil;
+        assert_eq!(policy.apply(-3.5), 0);
+        assert_eq!(policy.apply(f64::NAN), 0);
+        assert_eq!(policy.apply(1e12), u32::MAX);
     }
 }
@@ -22,7 +22,11 @@ pub fn new_card(
         id: Uuid::new_v4(),
         owner_id,
         kind,
-        state: Sm2State::new(CardState::New, today, config.initial_ease_factor),
+        state: Sm2State::new(
+            CardState::New,
+            config.new_card_due.first_due(today),
+            config.initial_ease_factor,
+        ),
     }
 }
 
@@ -37,9 +41,13 @@ mod tests {
         let today = NaiveDate::from_ymd_opt(2024, 6, 1).expect("valid fixture date");
         let config = SchedulerConfig {
             initial_ease_factor: 2.5,
-            ease_minimum: 1.3,
-            ease_maximum: 2.8,
+            ease_bounds: review_domain::EaseBounds::default(),
             learning_steps_minutes: vec![1, 10],
+            queue_layout: crate::config::QueueLayout::default(),
+            new_card_due: review_domain::NewCardDue::Today,
+            max_new_per_day: None,
+            mastery_interval_days: 180,
+            interval_rounding: crate::config::RoundingPolicy::default(),
         };
         (owner_id, kind, today, config)
     }
@@ -102,6 +110,17 @@ mod tests {
         assert_eq!(card.state.due, today);
     }
 
+    #[test]
+    fn card_new_should_set_due_to_tomorrow_when_configured() {
+        let (owner_id, kind, today, config) = common_setup();
+        let config = SchedulerConfig {
+            new_card_due: review_domain::NewCardDue::Tomorrow,
+            ..config
+        };
+        let card = new_card(owner_id, kind, today, &config);
+        assert_eq!(card.state.due, today.succ_opt().expect("valid date"));
+    }
+
     #[test]
     fn card_new_should_set_lapses_to_zero() {
         let card = common_card();
@@ -3,6 +3,7 @@ use std::hash::Hash;
 
 /// Payload describing an opening-based card within the scheduler.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SchedulerOpeningCard {
     /// Identifier prefix tying the card back to its parent opening line.
     pub parent_prefix: String,
@@ -27,6 +28,7 @@ impl SchedulerOpeningCard {
 
 /// Marker struct representing tactic cards. Kept as a struct to allow future metadata.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SchedulerTacticCard;
 
 impl SchedulerTacticCard {
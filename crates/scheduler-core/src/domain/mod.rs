@@ -19,11 +19,15 @@ pub use card_kind::{CardKind, SchedulerOpeningCard, SchedulerTacticCard};
 pub use card_state::CardState;
 /// SM-2 state tracked for each scheduled card.
 pub use sm2_state::Sm2State;
+/// Conversions between stored card state and [`Sm2State`], plus their error type.
 pub use state_bridge::{
-    BridgeError as CardStateBridgeError, Sm2Runtime, StoredSnapshot, hydrate_sm2_state,
-    persist_sm2_state,
+    BridgeError, Sm2Runtime, Sm2WithSnapshot, StoredSnapshot, hydrate_sm2_state, persist_sm2_state,
 };
 
+/// Former name of [`BridgeError`].
+#[deprecated(note = "use `BridgeError`")]
+pub type CardStateBridgeError = BridgeError;
+
 use chrono::NaiveDate;
 use uuid::Uuid;
 
@@ -31,13 +35,51 @@ use review_domain::ReviewGrade;
 
 use review_domain::UnlockRecord as GenericUnlockRecord;
 
+/// Policy rule that caused a card to be unlocked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UnlockReason {
+    /// Unlocked by the daily queue as the first card of its parent line; the queue admits
+    /// one new card per parent prefix per day.
+    DailyQuota,
+    /// Unlocked by the daily queue after an earlier card of the same parent line was
+    /// introduced.
+    PrerequisiteMet,
+    /// Unlocked outside the scheduler's policy; also assumed for records predating reasons.
+    #[default]
+    Manual,
+}
+
 /// Domain-specific payload stored for scheduler unlock events.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SchedulerUnlockDetail {
     /// Identifier of the card that became available for study.
     pub card_id: Uuid,
     /// Optional prefix used to group unlocks by their parent line.
     pub parent_prefix: Option<String>,
+    /// Policy rule responsible for the unlock.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub reason: UnlockReason,
+}
+
+impl SchedulerUnlockDetail {
+    /// Creates a detail with the default [`UnlockReason::Manual`] reason.
+    #[must_use]
+    pub fn new(card_id: Uuid, parent_prefix: Option<String>) -> Self {
+        Self {
+            card_id,
+            parent_prefix,
+            reason: UnlockReason::default(),
+        }
+    }
+
+    /// Returns the detail with the provided unlock reason.
+    #[must_use]
+    pub fn with_reason(mut self, reason: UnlockReason) -> Self {
+        self.reason = reason;
+        self
+    }
 }
 
 /// Unlock events emitted by the scheduler.
@@ -45,6 +87,7 @@ pub type UnlockRecord = GenericUnlockRecord<Uuid, SchedulerUnlockDetail>;
 
 /// Result of recording a review, including the updated card state.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReviewOutcome {
     /// Card after applying the review outcome.
     pub card: Card,
@@ -52,6 +95,10 @@ pub struct ReviewOutcome {
     pub previous_due: NaiveDate,
     /// Grade provided by the learner for the review.
     pub grade: ReviewGrade,
+    /// Interval, in days, the review scheduled the card for.
+    pub new_interval_days: u32,
+    /// Change in ease factor caused by the review (new ease minus previous ease).
+    pub ease_delta: f32,
 }
 
 #[cfg(test)]
@@ -77,4 +124,15 @@ mod tests {
         assert_eq!(card.state.due, today);
         assert_eq!(card.state.reviews, 0);
     }
+
+    #[test]
+    fn unlock_detail_defaults_to_manual_reason() {
+        let card_id = Uuid::new_v4();
+        let detail = SchedulerUnlockDetail::new(card_id, Some("e4".into()));
+        assert_eq!(detail.reason, UnlockReason::Manual);
+        assert_eq!(
+            detail.with_reason(UnlockReason::PrerequisiteMet).reason,
+            UnlockReason::PrerequisiteMet
+        );
+    }
 }
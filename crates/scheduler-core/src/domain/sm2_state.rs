@@ -1,8 +1,9 @@
 use super::CardState;
-use chrono::NaiveDate;
+use chrono::{Days, NaiveDate};
 
 /// Mutable SM-2 scheduling data tracked for a card.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Sm2State {
     /// Conceptual study stage for the card.
     pub stage: CardState,
@@ -31,6 +32,18 @@ impl Sm2State {
             reviews: 0,
         }
     }
+
+    /// Earliest day on which the current schedule could have been set.
+    ///
+    /// Reviews set `due` to the review day plus `interval_days`, so this is the last
+    /// review day. Cards that have not been reviewed were created on their first due date
+    /// or the day before, so the day before `due` is returned.
+    #[must_use]
+    pub fn scheduled_on(&self) -> NaiveDate {
+        self.due
+            .checked_sub_days(Days::new(u64::from(self.interval_days.max(1))))
+            .unwrap_or(self.due)
+    }
 }
 
 impl Default for Sm2State {
@@ -70,6 +83,18 @@ mod tests {
         assert_eq!(test_state.reviews, 0);
     }
 
+    #[test]
+    fn scheduled_on_is_the_last_review_or_the_day_before_the_first_due_date() {
+        let mut state = Sm2State::new(CardState::New, today(), 2.5);
+        assert_eq!(state.scheduled_on(), today().pred_opt().unwrap());
+
+        state.interval_days = 6;
+        assert_eq!(
+            state.scheduled_on(),
+            NaiveDate::from_ymd_opt(2024, 5, 26).unwrap()
+        );
+    }
+
     #[test]
     fn test_new_sm2_state_with_different_stages() {
         let stages = [
@@ -1,11 +1,54 @@
+//! Reversible conversions between persisted [`StoredCardState`] and scheduler [`Sm2State`].
+//!
+//! The scheduler tracks counters the store does not persist ([`Sm2Runtime`]) and the store
+//! tracks fields the scheduler does not ([`StoredSnapshot`]). Each direction takes the
+//! missing half explicitly. Because [`StoredCardState`] lives in `review-domain`, the
+//! persisting direction pairs its inputs in the local [`Sm2WithSnapshot`] rather than a tuple:
+//!
+//! ```
+//! use std::num::NonZeroU8;
+//!
+//! use chrono::NaiveDate;
+//! use review_domain::StoredCardState;
+//! use scheduler_core::domain::{
+//!     BridgeError, CardState, Sm2Runtime, Sm2State, Sm2WithSnapshot, StoredSnapshot,
+//! };
+//!
+//! let stored = StoredCardState {
+//!     due_on: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+//!     interval: NonZeroU8::new(3).unwrap(),
+//!     ease_factor: 2.5,
+//!     consecutive_correct: 1,
+//!     last_reviewed_on: None,
+//! };
+//! let runtime = Sm2Runtime { stage: CardState::Review, lapses: 0, reviews: 1 };
+//! let mut sm2 = Sm2State::from((&stored, runtime));
+//!
+//! let snapshot = StoredSnapshot { consecutive_correct: 1, last_reviewed_on: None };
+//! assert_eq!(StoredCardState::try_from(Sm2WithSnapshot(&sm2, &snapshot)), Ok(stored));
+//!
+//! sm2.interval_days = 0;
+//! assert_eq!(
+//!     StoredCardState::try_from(Sm2WithSnapshot(&sm2, &snapshot)),
+//!     Err(BridgeError::IntervalTooSmall)
+//! );
+//! ```
+
+use std::num::NonZeroU8;
+
 use review_domain::StoredCardState;
 
 use chrono::NaiveDate;
+use thiserror::Error;
 
 /// Error type for state bridge conversions.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
 pub enum BridgeError {
+    /// The scheduler interval is zero, which the store cannot represent.
+    #[error("interval must be at least one day")]
     IntervalTooSmall,
+    /// The scheduler interval exceeds the largest interval the store can persist.
+    #[error("interval of {interval_days} days exceeds the stored maximum of {max}")]
     IntervalOverflow { interval_days: u32, max: u8 },
 }
 
@@ -26,10 +69,11 @@ pub struct StoredSnapshot {
 
 use super::Sm2State;
 
+/// Scheduler state paired with the store-only fields needed to persist it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sm2WithSnapshot<'a>(pub &'a Sm2State, pub &'a StoredSnapshot);
+
 /// Convert a persisted [`StoredCardState`] plus runtime counters into an [`Sm2State`].
-///
-/// # Panics
-/// Panics if the conversion from stored state is not infallible (should never happen).
 #[must_use]
 pub fn hydrate_sm2_state(stored: StoredCardState, runtime: Sm2Runtime) -> Sm2State {
     Sm2State::from((stored, runtime))
@@ -43,28 +87,17 @@ pub fn persist_sm2_state(
     sm2: &Sm2State,
     snapshot: &StoredSnapshot,
 ) -> Result<StoredCardState, BridgeError> {
-    use std::num::NonZeroU8;
-    if sm2.interval_days == 0 {
-        return Err(BridgeError::IntervalTooSmall);
-    }
-    let interval_u8 =
-        u8::try_from(sm2.interval_days).map_err(|_| BridgeError::IntervalOverflow {
-            interval_days: sm2.interval_days,
-            max: u8::MAX,
-        })?;
-    let interval = NonZeroU8::new(interval_u8).ok_or(BridgeError::IntervalTooSmall)?;
-    Ok(StoredCardState {
-        due_on: sm2.due,
-        interval,
-        ease_factor: sm2.ease_factor,
-        consecutive_correct: snapshot.consecutive_correct,
-        last_reviewed_on: snapshot.last_reviewed_on,
-    })
+    StoredCardState::try_from(Sm2WithSnapshot(sm2, snapshot))
 }
 
 impl From<(StoredCardState, Sm2Runtime)> for Sm2State {
-    fn from(value: (StoredCardState, Sm2Runtime)) -> Self {
-        let (stored, runtime) = value;
+    fn from((stored, runtime): (StoredCardState, Sm2Runtime)) -> Self {
+        Self::from((&stored, runtime))
+    }
+}
+
+impl From<(&StoredCardState, Sm2Runtime)> for Sm2State {
+    fn from((stored, runtime): (&StoredCardState, Sm2Runtime)) -> Self {
         Self {
             stage: runtime.stage,
             ease_factor: stored.ease_factor,
@@ -76,6 +109,26 @@ impl From<(StoredCardState, Sm2Runtime)> for Sm2State {
     }
 }
 
+impl TryFrom<Sm2WithSnapshot<'_>> for StoredCardState {
+    type Error = BridgeError;
+
+    fn try_from(Sm2WithSnapshot(sm2, snapshot): Sm2WithSnapshot<'_>) -> Result<Self, Self::Error> {
+        let interval_u8 =
+            u8::try_from(sm2.interval_days).map_err(|_| BridgeError::IntervalOverflow {
+                interval_days: sm2.interval_days,
+                max: u8::MAX,
+            })?;
+        let interval = NonZeroU8::new(interval_u8).ok_or(BridgeError::IntervalTooSmall)?;
+        Ok(Self {
+            due_on: sm2.due,
+            interval,
+            ease_factor: sm2.ease_factor,
+            consecutive_correct: snapshot.consecutive_correct,
+            last_reviewed_on: snapshot.last_reviewed_on,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -161,4 +214,41 @@ mod tests {
         let err = persist_sm2_state(&sm2, &snapshot).expect_err("zero interval");
         assert!(matches!(err, BridgeError::IntervalTooSmall));
     }
+
+    #[test]
+    fn borrowed_conversions_match_free_functions() {
+        let stored = sample_stored_state();
+        let runtime = Sm2Runtime {
+            stage: CardState::Relearning,
+            lapses: 1,
+            reviews: 4,
+        };
+        let sm2 = Sm2State::from((&stored, runtime.clone()));
+        assert_eq!(sm2, hydrate_sm2_state(stored.clone(), runtime));
+
+        let snapshot = StoredSnapshot {
+            consecutive_correct: stored.consecutive_correct,
+            last_reviewed_on: stored.last_reviewed_on,
+        };
+        assert_eq!(
+            StoredCardState::try_from(Sm2WithSnapshot(&sm2, &snapshot)),
+            Ok(stored)
+        );
+    }
+
+    #[test]
+    fn bridge_errors_describe_the_rejected_interval() {
+        assert_eq!(
+            BridgeError::IntervalTooSmall.to_string(),
+            "interval must be at least one day"
+        );
+        assert_eq!(
+            BridgeError::IntervalOverflow {
+                interval_days: 300,
+                max: u8::MAX,
+            }
+            .to_string(),
+            "interval of 300 days exceeds the stored maximum of 255"
+        );
+    }
 }
@@ -1,5 +1,6 @@
 //! Error types returned by the scheduler.
 
+use chrono::NaiveDate;
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -7,19 +8,82 @@ use uuid::Uuid;
 #[derive(Debug, Error)]
 pub enum SchedulerError {
     /// Raised when a requested card is not present in the backing store.
-    #[error("card not found: {0}")]
-    CardNotFound(Uuid),
+    #[error("card not found: {id}")]
+    CardNotFound {
+        /// Identifier of the card that was requested.
+        id: Uuid,
+    },
+    /// Raised when a review is dated before the card's current schedule was set.
+    #[error("review on {reviewed_on} for card {id} precedes its schedule start {earliest}")]
+    InvalidDate {
+        /// Identifier of the card that was reviewed.
+        id: Uuid,
+        /// Date supplied for the review.
+        reviewed_on: NaiveDate,
+        /// Earliest date a review of the card is accepted.
+        earliest: NaiveDate,
+    },
+    /// Raised when an owner's unlock of a card was already recorded for the same day.
+    #[error("card {card_id} was already unlocked for owner {owner_id} on {day}")]
+    DuplicateUnlock {
+        /// Owner whose unlock log already holds the record.
+        owner_id: Uuid,
+        /// Identifier of the card unlocked twice.
+        card_id: Uuid,
+        /// Day of both unlocks.
+        day: NaiveDate,
+    },
+    /// Raised when a numeric review grade is outside the supported 0-4 range.
+    #[error("grade {grade} is outside the supported range 0-4")]
+    InvalidGrade {
+        /// Raw grade supplied by the caller.
+        grade: u8,
+    },
 }
 
 #[cfg(test)]
 mod tests {
     use super::SchedulerError;
+    use chrono::NaiveDate;
     use uuid::Uuid;
 
     #[test]
     fn card_not_found_displays_identifier() {
         let id = Uuid::nil();
-        let err = SchedulerError::CardNotFound(id);
+        let err = SchedulerError::CardNotFound { id };
         assert!(err.to_string().contains(&id.to_string()));
     }
+
+    #[test]
+    fn invalid_date_displays_both_dates() {
+        let day = |d| NaiveDate::from_ymd_opt(2024, 1, d).expect("valid date");
+        let err = SchedulerError::InvalidDate {
+            id: Uuid::nil(),
+            reviewed_on: day(2),
+            earliest: day(5),
+        };
+        let message = err.to_string();
+        assert!(message.contains("2024-01-02") && message.contains("2024-01-05"));
+    }
+
+    #[test]
+    fn duplicate_unlock_displays_card_and_day() {
+        let card_id = Uuid::new_v4();
+        let err = SchedulerError::DuplicateUnlock {
+            owner_id: Uuid::nil(),
+            card_id,
+            day: NaiveDate::from_ymd_opt(2024, 3, 9).expect("valid date"),
+        };
+        let message = err.to_string();
+        assert!(message.contains(&card_id.to_string()) && message.contains("2024-03-09"));
+    }
+
+    #[test]
+    fn invalid_grade_displays_the_raw_value() {
+        let err = SchedulerError::InvalidGrade { grade: 7 };
+        assert_eq!(
+            err.to_string(),
+            "grade 7 is outside the supported range 0-4"
+        );
+    }
 }
@@ -0,0 +1,178 @@
+//! Diagnostics that flag stored cards whose SM-2 state breaks the scheduler's invariants.
+
+use std::fmt;
+
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::config::SchedulerConfig;
+use crate::domain::{Card, CardState};
+
+/// Earliest due date a healthy card can carry. Anything before it points to a zeroed or
+/// underflowed date rather than a real study day.
+pub const EARLIEST_VALID_DUE: NaiveDate = match NaiveDate::from_ymd_opt(2000, 1, 1) {
+    Some(day) => day,
+    None => panic!("2000-01-01 is a valid date"),
+};
+
+/// An invariant violation found by [`Scheduler::health_check`](crate::Scheduler::health_check).
+#[derive(Debug, Clone, PartialEq)]
+pub enum SchedulerWarning {
+    /// A `Review` or `Relearning` card has a zero-day interval, which no review produces.
+    ZeroInterval {
+        /// Identifier of the offending card.
+        card_id: Uuid,
+        /// Stage the card is in.
+        stage: CardState,
+    },
+    /// The ease factor is not finite or lies outside the configured clamp.
+    EaseOutOfBounds {
+        /// Identifier of the offending card.
+        card_id: Uuid,
+        /// Stored ease factor.
+        ease_factor: f32,
+        /// Minimum of [`SchedulerConfig::ease_bounds`] at the time of the check.
+        minimum: f32,
+        /// Maximum of [`SchedulerConfig::ease_bounds`] at the time of the check.
+        maximum: f32,
+    },
+    /// The due date precedes [`EARLIEST_VALID_DUE`].
+    DueBeforeFloor {
+        /// Identifier of the offending card.
+        card_id: Uuid,
+        /// Stored due date.
+        due: NaiveDate,
+        /// The floor it was compared against.
+        floor: NaiveDate,
+    },
+}
+
+impl SchedulerWarning {
+    /// Identifier of the card the warning is about.
+    #[must_use]
+    pub fn card_id(&self) -> Uuid {
+        match self {
+            Self::ZeroInterval { card_id, .. }
+            | Self::EaseOutOfBounds { card_id, .. }
+            | Self::DueBeforeFloor { card_id, .. } => *card_id,
+        }
+    }
+}
+
+impl fmt::Display for SchedulerWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ZeroInterval { card_id, stage } => {
+                write!(f, "card {card_id} is in {stage:?} with a zero-day interval")
+            }
+            Self::EaseOutOfBounds {
+                card_id,
+                ease_factor,
+                minimum,
+                maximum,
+            } => write!(
+                f,
+                "card {card_id} has ease factor {ease_factor} outside {minimum}..={maximum}"
+            ),
+            Self::DueBeforeFloor {
+                card_id,
+                due,
+                floor,
+            } => write!(f, "card {card_id} is due on {due}, before {floor}"),
+        }
+    }
+}
+
+/// Lists every invariant `card` violates under `config`, in declaration order of
+/// [`SchedulerWarning`]. A healthy card yields an empty list.
+#[must_use]
+pub fn card_warnings(card: &Card, config: &SchedulerConfig) -> Vec<SchedulerWarning> {
+    let state = &card.state;
+    let mut warnings = Vec::new();
+    if state.interval_days == 0 && matches!(state.stage, CardState::Review | CardState::Relearning)
+    {
+        warnings.push(SchedulerWarning::ZeroInterval {
+            card_id: card.id,
+            stage: state.stage,
+        });
+    }
+    let bounds = config.ease_bounds;
+    if !(bounds.minimum..=bounds.maximum).contains(&state.ease_factor) {
+        warnings.push(SchedulerWarning::EaseOutOfBounds {
+            card_id: card.id,
+            ease_factor: state.ease_factor,
+            minimum: bounds.minimum,
+            maximum: bounds.maximum,
+        });
+    }
+    if state.due < EARLIEST_VALID_DUE {
+        warnings.push(SchedulerWarning::DueBeforeFloor {
+            card_id: card.id,
+            due: state.due,
+            floor: EARLIEST_VALID_DUE,
+        });
+    }
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{CardKind, SchedulerTacticCard, new_card};
+
+    fn review_card(config: &SchedulerConfig) -> Card {
+        let mut card = new_card(
+            Uuid::new_v4(),
+            CardKind::Tactic(SchedulerTacticCard::new()),
+            NaiveDate::from_ymd_opt(2024, 3, 1).expect("valid date"),
+            config,
+        );
+        card.state.stage = CardState::Review;
+        card.state.interval_days = 3;
+        card
+    }
+
+    #[test]
+    fn healthy_and_freshly_unlocked_cards_raise_no_warnings() {
+        let config = SchedulerConfig::default();
+        assert!(card_warnings(&review_card(&config), &config).is_empty());
+
+        let mut learning = review_card(&config);
+        learning.state.stage = CardState::Learning;
+        learning.state.interval_days = 0;
+        assert!(card_warnings(&learning, &config).is_empty());
+    }
+
+    #[test]
+    fn each_broken_invariant_is_reported() {
+        let config = SchedulerConfig::default();
+        let mut card = review_card(&config);
+        card.state.interval_days = 0;
+        card.state.ease_factor = f32::NAN;
+        card.state.due = NaiveDate::MIN;
+
+        let warnings = card_warnings(&card, &config);
+
+        assert_eq!(warnings.len(), 3);
+        assert!(warnings.iter().all(|warning| warning.card_id() == card.id));
+        assert_eq!(
+            warnings[0],
+            SchedulerWarning::ZeroInterval {
+                card_id: card.id,
+                stage: CardState::Review,
+            }
+        );
+        assert!(matches!(
+            warnings[1],
+            SchedulerWarning::EaseOutOfBounds { ease_factor, .. } if ease_factor.is_nan()
+        ));
+        assert_eq!(
+            warnings[2].to_string(),
+            format!(
+                "card {} is due on {}, before 2000-01-01",
+                card.id,
+                NaiveDate::MIN
+            )
+        );
+    }
+}
@@ -1,34 +1,52 @@
 //! scheduler-core — SM-2 scheduling, unlock policy, and supporting types.
 
+/// Per-owner review activity used for streak tracking.
+pub mod activity;
 /// Scheduler configuration options governing SM-2 behavior.
 pub mod config;
 /// Domain-specific data structures exposed by the scheduler.
 pub mod domain;
 /// Error type returned by scheduler operations.
 pub mod errors;
+/// Store invariant diagnostics.
+pub mod health;
 /// Review queue construction helpers.
 pub mod queue;
 /// High-level scheduler façade orchestrating reviews.
 pub mod scheduler;
 /// SM-2 calculation utilities.
 pub mod sm2;
+/// Daily review snapshots derived from the store and review history.
+pub mod snapshot;
 /// Storage abstractions consumed by the scheduler.
 pub mod store;
 
+/// Daily review activity log maintained by the scheduler.
+pub use activity::ActivityLog;
 /// Configuration values used to tune the scheduler.
-pub use config::SchedulerConfig;
+pub use config::{QueueLayout, RoundingPolicy, SchedulerConfig};
 /// Domain exports for cards, unlocks, and helper constructors.
 pub use domain::{
     Card, CardKind, CardState, ReviewOutcome, SchedulerOpeningCard, SchedulerTacticCard,
-    SchedulerUnlockDetail, UnlockRecord, new_card,
+    SchedulerUnlockDetail, UnlockReason, UnlockRecord, new_card,
 };
 /// Error returned when scheduling operations fail.
 pub use errors::SchedulerError;
+/// Invariant violations reported by `Scheduler::health_check`.
+pub use health::SchedulerWarning;
 /// Build the review queue for a given study day.
-pub use queue::build_queue_for_day;
+pub use queue::{QueueBreakdown, QueueSelection, build_queue_breakdown, build_queue_for_day};
+/// Ease factor clamp shared with review-domain consumers.
+pub use review_domain::EaseBounds;
+/// New-card due policy shared with review-domain consumers.
+pub use review_domain::NewCardDue;
 /// Review grade shared with review-domain consumers.
 pub use review_domain::ReviewGrade;
 /// Scheduler façade orchestrating queue building and review processing.
 pub use scheduler::Scheduler;
+/// Preview of the due date each review grade would produce.
+pub use sm2::preview_intervals;
+/// Planner-facing snapshot of a learner's day.
+pub use snapshot::{ReviewHistoryEntry, ReviewSnapshot, UpcomingUnlock};
 /// Storage trait and in-memory implementation used by the scheduler.
 pub use store::{InMemoryStore, SchedulerStore};
@@ -5,23 +5,120 @@ use std::collections::BTreeSet;
 use chrono::NaiveDate;
 use uuid::Uuid;
 
-use crate::config::SchedulerConfig;
-use crate::domain::{Card, CardKind, CardState, SchedulerUnlockDetail, UnlockRecord};
+use crate::config::{QueueLayout, SchedulerConfig};
+use crate::domain::{Card, CardKind, CardState, SchedulerUnlockDetail, UnlockReason, UnlockRecord};
 use crate::store::SchedulerStore;
 
+/// Cards for one study day split by why they are in the queue.
+///
+/// Each bucket keeps the queue's ordering of due date, then id.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct QueueBreakdown {
+    /// Review and learning cards whose due date is before today.
+    pub overdue: Vec<Card>,
+    /// Review and learning cards due exactly today.
+    pub due_today: Vec<Card>,
+    /// Due cards unlocked today, whether by this call or an earlier one.
+    pub new: Vec<Card>,
+}
+
+/// Which cards [`build_queue_for_day`] puts in the queue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QueueSelection {
+    /// Due cards plus the cards unlocked today.
+    #[default]
+    All,
+    /// Only due learning and review cards that were not unlocked today; nothing is unlocked,
+    /// and `New` cards never appear.
+    OnlyDue,
+    /// Only the due cards unlocked today, including this call's unlocks, which are still
+    /// capped by [`SchedulerConfig::max_new_per_day`] and taken in candidate order.
+    OnlyNew,
+}
+
 /// Build the study queue for the given owner on the provided day.
+///
+/// Due cards unlocked today, by this call or an earlier one, count as new. The remaining due
+/// learning and review cards and the new cards are each ordered by due date and id, then
+/// merged according to [`SchedulerConfig::queue_layout`], so repeated calls on the same day
+/// keep the same grouping. `selection` limits the queue to one of the two groups; otherwise
+/// every due card is always included.
 #[must_use]
 pub fn build_queue_for_day<S: SchedulerStore>(
     store: &mut S,
     config: &SchedulerConfig,
     owner_id: Uuid,
     today: NaiveDate,
+    selection: QueueSelection,
 ) -> Vec<Card> {
-    let mut queue = store.due_cards(owner_id, today);
+    let (due, fresh) = collect_due_and_fresh(store, config, owner_id, today, selection);
+    merge_by_layout(config.queue_layout, due, fresh)
+}
+
+/// Build the same cards as [`build_queue_for_day`], grouped into overdue, due-today, and new
+/// buckets instead of merged by [`SchedulerConfig::queue_layout`].
+#[must_use]
+pub fn build_queue_breakdown<S: SchedulerStore>(
+    store: &mut S,
+    config: &SchedulerConfig,
+    owner_id: Uuid,
+    today: NaiveDate,
+) -> QueueBreakdown {
+    let (due, new) = collect_due_and_fresh(store, config, owner_id, today, QueueSelection::All);
+    let (overdue, due_today) = due.into_iter().partition(|card| card.state.due < today);
+    QueueBreakdown {
+        overdue,
+        due_today,
+        new,
+    }
+}
+
+fn collect_due_and_fresh<S: SchedulerStore>(
+    store: &mut S,
+    config: &SchedulerConfig,
+    owner_id: Uuid,
+    today: NaiveDate,
+    selection: QueueSelection,
+) -> (Vec<Card>, Vec<Card>) {
     let prior_unlocks = store.unlocked_on(owner_id, today);
     let mut unlocked = ExistingUnlocks::from_records(&prior_unlocks);
-    extend_queue_with_unlocks(store, config, owner_id, today, &mut queue, &mut unlocked);
-    queue.sort_by(|a, b| (a.state.due, a.id).cmp(&(b.state.due, b.id)));
+    let (mut fresh, mut due): (Vec<Card>, Vec<Card>) = store
+        .due_cards(owner_id, today)
+        .into_iter()
+        .partition(|card| unlocked.contains_card(&card.id));
+    match selection {
+        QueueSelection::All => {}
+        QueueSelection::OnlyDue => fresh.clear(),
+        QueueSelection::OnlyNew => due.clear(),
+    }
+    if selection != QueueSelection::OnlyDue {
+        extend_queue_with_unlocks(store, config, owner_id, today, &mut fresh, &mut unlocked);
+    }
+    due.sort_by_key(|card| (card.state.due, card.id));
+    fresh.sort_by_key(|card| (card.state.due, card.id));
+    (due, fresh)
+}
+
+fn merge_by_layout(layout: QueueLayout, due: Vec<Card>, fresh: Vec<Card>) -> Vec<Card> {
+    let (mut first, second) = match layout {
+        QueueLayout::ReviewsFirst => (due, fresh),
+        QueueLayout::NewFirst => (fresh, due),
+        QueueLayout::Interleaved { new_every } => return interleave(due, fresh, new_every.max(1)),
+    };
+    first.extend(second);
+    first
+}
+
+fn interleave(due: Vec<Card>, fresh: Vec<Card>, new_every: usize) -> Vec<Card> {
+    let mut queue = Vec::with_capacity(due.len() + fresh.len());
+    let mut fresh = fresh.into_iter();
+    for (position, card) in due.into_iter().enumerate() {
+        queue.push(card);
+        if (position + 1) % new_every == 0 {
+            queue.extend(fresh.next());
+        }
+    }
+    queue.extend(fresh);
     queue
 }
 
@@ -64,26 +161,60 @@ fn extend_queue_with_unlocks<S: SchedulerStore>(
     queue: &mut Vec<Card>,
     unlocked: &mut ExistingUnlocks,
 ) {
+    let mut remaining = config
+        .max_new_per_day
+        .map(|cap| cap.saturating_sub(store.cards_introduced_on(owner_id, today)));
+    let started_prefixes = started_prefixes(store, owner_id);
     for mut candidate in store.unlock_candidates(owner_id) {
         if skip_candidate(&candidate, unlocked) {
             continue;
         }
+        if remaining == Some(0) {
+            break;
+        }
         let parent_prefix = extract_prefix(&candidate);
+        let reason = match &parent_prefix {
+            Some(prefix) if started_prefixes.contains(prefix) => UnlockReason::PrerequisiteMet,
+            _ => UnlockReason::DailyQuota,
+        };
         unlock_card(&mut candidate, config, today);
-        store.record_unlock(UnlockRecord {
+        let recorded = store.record_unlock(UnlockRecord {
             owner_id,
             detail: SchedulerUnlockDetail {
                 card_id: candidate.id,
                 parent_prefix: parent_prefix.clone(),
+                reason,
             },
             unlocked_on: today,
         });
+        if recorded.is_err() {
+            continue;
+        }
         unlocked.track_new_unlock(parent_prefix, candidate.id);
+        store.record_introduction(owner_id, candidate.id, today);
+        if let Some(left) = remaining.as_mut() {
+            *left -= 1;
+        }
         store.upsert_card(candidate.clone());
         queue.push(candidate);
     }
 }
 
+/// Parent prefixes of the owner's opening cards that have already left `New`, retired cards
+/// included.
+///
+/// A candidate from one of these lines is unlocked because its line was started, so it is
+/// tagged [`UnlockReason::PrerequisiteMet`]; the first card of a line is a
+/// [`UnlockReason::DailyQuota`] unlock.
+fn started_prefixes<S: SchedulerStore>(store: &S, owner_id: Uuid) -> BTreeSet<String> {
+    store
+        .cards_for_owner(owner_id)
+        .into_iter()
+        .filter(|card| card.state.stage != CardState::New)
+        .filter_map(|card| extract_prefix(&card))
+        .collect()
+}
+
 fn skip_candidate(candidate: &Card, unlocked: &ExistingUnlocks) -> bool {
     if unlocked.contains_card(&candidate.id) {
         return true;
@@ -137,21 +268,111 @@ mod tests {
         let owner = Uuid::new_v4();
         let existing = sample_opening(owner, "e4");
         store.upsert_card(existing.clone());
-        store.record_unlock(UnlockRecord {
-            owner_id: owner,
-            detail: SchedulerUnlockDetail {
-                card_id: existing.id,
-                parent_prefix: Some("e4".into()),
-            },
-            unlocked_on: naive_date(2023, 1, 1),
-        });
+        store
+            .record_unlock(UnlockRecord {
+                owner_id: owner,
+                detail: SchedulerUnlockDetail {
+                    card_id: existing.id,
+                    parent_prefix: Some("e4".into()),
+                    reason: UnlockReason::DailyQuota,
+                },
+                unlocked_on: naive_date(2023, 1, 1),
+            })
+            .expect("unlock recorded");
         let new_candidate = sample_opening(owner, "e4");
         store.upsert_card(new_candidate.clone());
 
-        let queue = build_queue_for_day(&mut store, &config, owner, naive_date(2023, 1, 1));
+        let queue = build_queue_for_day(
+            &mut store,
+            &config,
+            owner,
+            naive_date(2023, 1, 1),
+            QueueSelection::All,
+        );
         assert_eq!(queue.len(), 0);
     }
 
+    #[test]
+    fn build_queue_records_daily_quota_reason() {
+        let mut store = InMemoryStore::new();
+        let config = SchedulerConfig::default();
+        let owner = Uuid::new_v4();
+        let candidate = sample_opening(owner, "c4");
+        store.upsert_card(candidate.clone());
+
+        let _ = build_queue_for_day(
+            &mut store,
+            &config,
+            owner,
+            naive_date(2023, 1, 1),
+            QueueSelection::All,
+        );
+        let records = store.unlocked_on(owner, naive_date(2023, 1, 1));
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].detail.card_id, candidate.id);
+        assert_eq!(records[0].detail.reason, UnlockReason::DailyQuota);
+    }
+
+    #[test]
+    fn build_queue_records_prerequisite_met_for_started_lines() {
+        let mut store = InMemoryStore::new();
+        let config = SchedulerConfig::default();
+        let owner = Uuid::new_v4();
+        let first = naive_date(2023, 1, 1);
+        let second = naive_date(2023, 1, 2);
+        for _ in 0..2 {
+            store.upsert_card(sample_opening(owner, "e4"));
+        }
+        store.upsert_card(sample_opening(owner, "d4"));
+
+        let _ = build_queue_for_day(&mut store, &config, owner, first, QueueSelection::All);
+        let _ = build_queue_for_day(&mut store, &config, owner, second, QueueSelection::All);
+
+        let reasons = |day| {
+            store
+                .unlocked_on(owner, day)
+                .into_iter()
+                .map(|record| {
+                    (
+                        record.detail.parent_prefix.expect("opening"),
+                        record.detail.reason,
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            reasons(first),
+            vec![
+                ("d4".to_string(), UnlockReason::DailyQuota),
+                ("e4".to_string(), UnlockReason::DailyQuota),
+            ]
+        );
+        assert_eq!(
+            reasons(second),
+            vec![("e4".to_string(), UnlockReason::PrerequisiteMet)]
+        );
+    }
+
+    #[test]
+    fn retired_cards_mark_their_line_as_started() {
+        let mut store = InMemoryStore::new();
+        let config = SchedulerConfig::default();
+        let owner = Uuid::new_v4();
+        let today = naive_date(2023, 1, 1);
+        let mut mastered = sample_opening(owner, "e4");
+        mastered.state.stage = CardState::Retired;
+        mastered.state.due = naive_date(2023, 6, 1);
+        store.upsert_card(mastered);
+        store.upsert_card(sample_opening(owner, "e4"));
+
+        let _ = build_queue_for_day(&mut store, &config, owner, today, QueueSelection::All);
+
+        let records = store.unlocked_on(owner, today);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].detail.reason, UnlockReason::PrerequisiteMet);
+    }
+
     #[test]
     fn build_queue_unlocks_new_opening() {
         let mut store = InMemoryStore::new();
@@ -160,11 +381,46 @@ mod tests {
         let candidate = sample_opening(owner, "c4");
         store.upsert_card(candidate.clone());
 
-        let queue = build_queue_for_day(&mut store, &config, owner, naive_date(2023, 1, 1));
+        let queue = build_queue_for_day(
+            &mut store,
+            &config,
+            owner,
+            naive_date(2023, 1, 1),
+            QueueSelection::All,
+        );
         assert_eq!(queue.len(), 1);
         assert_eq!(queue[0].state.stage, CardState::Learning);
     }
 
+    #[test]
+    fn build_queue_stops_unlocking_at_the_daily_cap() {
+        let mut store = InMemoryStore::new();
+        let config = SchedulerConfig {
+            max_new_per_day: Some(2),
+            ..SchedulerConfig::default()
+        };
+        let owner = Uuid::new_v4();
+        let today = naive_date(2023, 1, 1);
+        for prefix in ["a3", "b3", "c4"] {
+            store.upsert_card(sample_opening(owner, prefix));
+        }
+
+        let first = build_queue_for_day(&mut store, &config, owner, today, QueueSelection::All);
+        let _ = build_queue_for_day(&mut store, &config, owner, today, QueueSelection::All);
+        let _ = build_queue_for_day(
+            &mut store,
+            &config,
+            owner,
+            naive_date(2023, 1, 2),
+            QueueSelection::All,
+        );
+
+        assert_eq!(first.len(), 2);
+        assert_eq!(store.unlocked_on(owner, today).len(), 2);
+        assert_eq!(store.cards_introduced_on(owner, today), 2);
+        assert_eq!(store.unlocked_on(owner, naive_date(2023, 1, 2)).len(), 1);
+    }
+
     #[test]
     fn skip_candidate_blocks_previously_seen_card() {
         let mut store = InMemoryStore::new();
@@ -175,14 +431,17 @@ mod tests {
         let duplicate = sample_opening(owner, "d4");
         store.upsert_card(prior_unlock.clone());
         store.upsert_card(duplicate);
-        store.record_unlock(UnlockRecord {
-            owner_id: owner,
-            detail: SchedulerUnlockDetail {
-                card_id: prior_unlock.id,
-                parent_prefix: Some("d4".into()),
-            },
-            unlocked_on: naive_date(2023, 1, 2),
-        });
+        store
+            .record_unlock(UnlockRecord {
+                owner_id: owner,
+                detail: SchedulerUnlockDetail {
+                    card_id: prior_unlock.id,
+                    parent_prefix: Some("d4".into()),
+                    reason: UnlockReason::DailyQuota,
+                },
+                unlocked_on: naive_date(2023, 1, 2),
+            })
+            .expect("unlock recorded");
 
         let mut existing =
             ExistingUnlocks::from_records(&store.unlocked_on(owner, naive_date(2023, 1, 2)));
@@ -240,6 +499,142 @@ mod tests {
         assert!(extract_prefix(&tactic_card).is_none());
     }
 
+    fn seed_reviews_and_openings(
+        store: &mut InMemoryStore,
+        owner: Uuid,
+        reviews: usize,
+        openings: &[&str],
+    ) -> Vec<Uuid> {
+        let config = SchedulerConfig::default();
+        let mut review_ids = Vec::new();
+        for offset in 0..reviews {
+            let mut card = new_card(
+                owner,
+                CardKind::Tactic(SchedulerTacticCard::new()),
+                naive_date(2023, 1, 1),
+                &config,
+            );
+            card.state.stage = CardState::Review;
+            card.state.due = naive_date(2023, 1, 1) + chrono::Days::new(offset as u64 % 3);
+            review_ids.push(card.id);
+            store.upsert_card(card);
+        }
+        for prefix in openings {
+            store.upsert_card(sample_opening(owner, prefix));
+        }
+        review_ids
+    }
+
+    fn is_new(card: &Card) -> bool {
+        card.state.stage == CardState::Learning
+    }
+
+    #[test]
+    fn reviews_first_layout_places_unlocks_after_due_cards() {
+        let mut store = InMemoryStore::new();
+        let owner = Uuid::new_v4();
+        seed_reviews_and_openings(&mut store, owner, 3, &["c4", "d4"]);
+        let config = SchedulerConfig::default();
+
+        let queue = build_queue_for_day(
+            &mut store,
+            &config,
+            owner,
+            naive_date(2023, 1, 5),
+            QueueSelection::All,
+        );
+        let pattern: Vec<bool> = queue.iter().map(is_new).collect();
+
+        assert_eq!(pattern, vec![false, false, false, true, true]);
+    }
+
+    #[test]
+    fn new_first_layout_places_unlocks_before_due_cards() {
+        let mut store = InMemoryStore::new();
+        let owner = Uuid::new_v4();
+        seed_reviews_and_openings(&mut store, owner, 2, &["c4"]);
+        let config = SchedulerConfig {
+            queue_layout: QueueLayout::NewFirst,
+            ..SchedulerConfig::default()
+        };
+
+        let queue = build_queue_for_day(
+            &mut store,
+            &config,
+            owner,
+            naive_date(2023, 1, 5),
+            QueueSelection::All,
+        );
+        let pattern: Vec<bool> = queue.iter().map(is_new).collect();
+
+        assert_eq!(pattern, vec![true, false, false]);
+    }
+
+    #[test]
+    fn interleaved_layout_inserts_one_new_card_per_ratio_and_keeps_all_reviews() {
+        let mut store = InMemoryStore::new();
+        let owner = Uuid::new_v4();
+        let review_ids =
+            seed_reviews_and_openings(&mut store, owner, 11, &["a3", "b3", "c4", "d4"]);
+        let config = SchedulerConfig {
+            queue_layout: QueueLayout::Interleaved { new_every: 5 },
+            ..SchedulerConfig::default()
+        };
+
+        let queue = build_queue_for_day(
+            &mut store,
+            &config,
+            owner,
+            naive_date(2023, 1, 5),
+            QueueSelection::All,
+        );
+        let pattern: String = queue
+            .iter()
+            .map(|card| if is_new(card) { 'N' } else { 'R' })
+            .collect();
+
+        assert_eq!(pattern, "RRRRRNRRRRRNRNN");
+        let mut queued_reviews: Vec<Uuid> = queue
+            .iter()
+            .filter(|card| !is_new(card))
+            .map(|card| card.id)
+            .collect();
+        let mut expected_reviews = review_ids;
+        queued_reviews.sort();
+        expected_reviews.sort();
+        assert_eq!(queued_reviews, expected_reviews);
+        let review_dues: Vec<NaiveDate> = queue
+            .iter()
+            .filter(|card| !is_new(card))
+            .map(|card| card.state.due)
+            .collect();
+        assert!(review_dues.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    #[test]
+    fn interleave_treats_zero_ratio_as_one() {
+        let owner = Uuid::new_v4();
+        let config = SchedulerConfig::default();
+        let review = |_: usize| {
+            new_card(
+                owner,
+                CardKind::Tactic(SchedulerTacticCard::new()),
+                naive_date(2023, 1, 1),
+                &config,
+            )
+        };
+        let due: Vec<Card> = (0..2).map(review).collect();
+        let fresh: Vec<Card> = (0..3).map(review).collect();
+        let expected = vec![due[0].id, fresh[0].id, due[1].id, fresh[1].id, fresh[2].id];
+
+        let merged = merge_by_layout(QueueLayout::Interleaved { new_every: 0 }, due, fresh);
+
+        assert_eq!(
+            merged.iter().map(|card| card.id).collect::<Vec<_>>(),
+            expected
+        );
+    }
+
     #[test]
     fn track_new_unlock_records_ids_without_prefixes() {
         let mut unlocks = ExistingUnlocks::from_records(&[]);
@@ -250,4 +645,150 @@ mod tests {
         assert!(unlocks.contains_card(&tactic_id));
         assert!(!unlocks.contains_prefix("unused"));
     }
+
+    #[test]
+    fn only_due_selection_skips_unlocks_and_new_cards() {
+        let mut store = InMemoryStore::new();
+        let owner = Uuid::new_v4();
+        let review_ids = seed_reviews_and_openings(&mut store, owner, 3, &["c4", "d4"]);
+        let today = naive_date(2023, 1, 5);
+
+        let queue = build_queue_for_day(
+            &mut store,
+            &SchedulerConfig::default(),
+            owner,
+            today,
+            QueueSelection::OnlyDue,
+        );
+
+        let mut ids: Vec<Uuid> = queue.iter().map(|card| card.id).collect();
+        ids.sort_unstable();
+        let mut expected = review_ids;
+        expected.sort_unstable();
+        assert_eq!(ids, expected);
+        assert!(store.unlocked_on(owner, today).is_empty());
+        assert_eq!(store.unlock_candidates(owner).len(), 2);
+    }
+
+    #[test]
+    fn only_new_selection_respects_the_daily_cap_and_candidate_order() {
+        let mut store = InMemoryStore::new();
+        let owner = Uuid::new_v4();
+        seed_reviews_and_openings(&mut store, owner, 3, &["a3", "b3", "c4"]);
+        let config = SchedulerConfig {
+            max_new_per_day: Some(2),
+            ..SchedulerConfig::default()
+        };
+        let today = naive_date(2023, 1, 5);
+        let expected: Vec<Uuid> = store
+            .unlock_candidates(owner)
+            .iter()
+            .take(2)
+            .map(|card| card.id)
+            .collect();
+
+        let queue = build_queue_for_day(&mut store, &config, owner, today, QueueSelection::OnlyNew);
+
+        assert!(queue.iter().all(is_new));
+        let mut ids: Vec<Uuid> = queue.iter().map(|card| card.id).collect();
+        ids.sort_unstable();
+        let mut expected = expected;
+        expected.sort_unstable();
+        assert_eq!(ids, expected);
+        assert_eq!(store.cards_introduced_on(owner, today), 2);
+        assert_eq!(store.due_cards(owner, today).len(), 5);
+    }
+
+    #[test]
+    fn all_selection_returns_due_and_new_cards() {
+        let mut store = InMemoryStore::new();
+        let owner = Uuid::new_v4();
+        seed_reviews_and_openings(&mut store, owner, 3, &["c4", "d4"]);
+
+        let queue = build_queue_for_day(
+            &mut store,
+            &SchedulerConfig::default(),
+            owner,
+            naive_date(2023, 1, 5),
+            QueueSelection::default(),
+        );
+
+        assert_eq!(queue.iter().filter(|card| is_new(card)).count(), 2);
+        assert_eq!(queue.len(), 5);
+    }
+
+    #[test]
+    fn cards_unlocked_earlier_today_stay_new_on_later_calls() {
+        let mut store = InMemoryStore::new();
+        let owner = Uuid::new_v4();
+        seed_reviews_and_openings(&mut store, owner, 2, &["c4"]);
+        let config = SchedulerConfig {
+            queue_layout: QueueLayout::NewFirst,
+            ..SchedulerConfig::default()
+        };
+        let today = naive_date(2023, 1, 5);
+
+        let first = build_queue_for_day(&mut store, &config, owner, today, QueueSelection::All);
+        let second = build_queue_for_day(&mut store, &config, owner, today, QueueSelection::All);
+        let ids = |queue: &[Card]| queue.iter().map(|card| card.id).collect::<Vec<_>>();
+        assert_eq!(ids(&second), ids(&first));
+        assert_eq!(second[0].state.stage, CardState::Learning);
+
+        let only_new =
+            build_queue_for_day(&mut store, &config, owner, today, QueueSelection::OnlyNew);
+        assert_eq!(ids(&only_new), ids(&first[..1]));
+        let only_due =
+            build_queue_for_day(&mut store, &config, owner, today, QueueSelection::OnlyDue);
+        assert_eq!(ids(&only_due), ids(&first[1..]));
+
+        let breakdown = build_queue_breakdown(&mut store, &config, owner, today);
+        assert_eq!(ids(&breakdown.new), ids(&first[..1]));
+        assert_eq!(breakdown.overdue.len() + breakdown.due_today.len(), 2);
+    }
+
+    #[test]
+    fn breakdown_separates_overdue_due_today_and_new_cards() {
+        let mut store = InMemoryStore::new();
+        let owner = Uuid::new_v4();
+        let today = naive_date(2023, 1, 3);
+        let review_ids = seed_reviews_and_openings(&mut store, owner, 6, &["c4", "d4"]);
+        let config = SchedulerConfig::default();
+        let mut flat_store = InMemoryStore::from_cards(
+            store
+                .due_cards(owner, naive_date(2023, 12, 31))
+                .into_iter()
+                .chain(store.unlock_candidates(owner)),
+        );
+
+        let breakdown = build_queue_breakdown(&mut store, &config, owner, today);
+        let flat = build_queue_for_day(&mut flat_store, &config, owner, today, QueueSelection::All);
+
+        assert!(breakdown.overdue.iter().all(|card| card.state.due < today));
+        assert!(
+            breakdown
+                .due_today
+                .iter()
+                .all(|card| card.state.due == today)
+        );
+        assert_eq!(
+            breakdown.overdue.len() + breakdown.due_today.len(),
+            review_ids.len()
+        );
+        assert_eq!(breakdown.due_today.len(), 2);
+        assert!(breakdown.new.iter().all(is_new));
+        assert_eq!(breakdown.new.len(), 2);
+        for bucket in [&breakdown.overdue, &breakdown.due_today, &breakdown.new] {
+            assert!(bucket.is_sorted_by_key(|card| (card.state.due, card.id)));
+        }
+
+        let regrouped: Vec<Uuid> = breakdown
+            .overdue
+            .iter()
+            .chain(&breakdown.due_today)
+            .chain(&breakdown.new)
+            .map(|card| card.id)
+            .collect();
+        let flat_ids: Vec<Uuid> = flat.iter().map(|card| card.id).collect();
+        assert_eq!(regrouped, flat_ids);
+    }
 }
@@ -3,12 +3,15 @@
 use chrono::NaiveDate;
 use uuid::Uuid;
 
+use crate::activity::streak_ending;
 use crate::config::SchedulerConfig;
-use crate::domain::{Card, ReviewOutcome};
+use crate::domain::{Card, CardKind, CardState, ReviewOutcome};
 use crate::errors::SchedulerError;
-use crate::queue::build_queue_for_day;
-use crate::sm2::apply_sm2;
-use crate::store::SchedulerStore;
+use crate::health::{SchedulerWarning, card_warnings};
+use crate::queue::{QueueBreakdown, QueueSelection, build_queue_breakdown, build_queue_for_day};
+use crate::sm2::{apply_sm2, preview_intervals};
+use crate::snapshot::ReviewHistoryEntry;
+use crate::store::{SchedulerStore, candidate_ordering};
 use review_domain::ReviewGrade;
 
 /// High-level façade coordinating scheduling operations for a single store implementation.
@@ -26,10 +29,17 @@ impl<S: SchedulerStore> Scheduler<S> {
 
     /// Applies the provided review grade to the specified card and updates the store.
     ///
+    /// Callers must only grade cards they obtained from the store, typically via
+    /// [`Scheduler::build_queue`]. Grading while the due queue is empty is a caller error:
+    /// there is no "current" card to fall back to, so the request is rejected rather than
+    /// silently ignored.
+    ///
     /// # Errors
     ///
     /// Returns [`SchedulerError::CardNotFound`] when the requested card cannot be retrieved
-    /// from the underlying store.
+    /// from the underlying store, and [`SchedulerError::InvalidDate`] when `today` is before
+    /// the card's [`scheduled_on`](crate::domain::Sm2State::scheduled_on) date. The store is
+    /// left untouched in both cases; same-day reviews are allowed.
     #[must_use = "handle potential errors when reviewing a card"]
     pub fn review(
         &mut self,
@@ -37,24 +47,293 @@ impl<S: SchedulerStore> Scheduler<S> {
         grade: ReviewGrade,
         today: NaiveDate,
     ) -> Result<ReviewOutcome, SchedulerError> {
-        let mut card = self
-            .store
-            .get_card(card_id)
-            .ok_or(SchedulerError::CardNotFound(card_id))?;
+        let mut card = self.reviewable_card(card_id, today)?;
         let previous_due = card.state.due;
+        let previous_ease = card.state.ease_factor;
+        let was_new = card.state.stage == CardState::New;
         apply_sm2(&mut card, grade, &self.config, today);
         self.store.upsert_card(card.clone());
+        if was_new && card.state.stage != CardState::New {
+            self.store
+                .record_introduction(card.owner_id, card.id, today);
+        }
+        self.store.record_review(
+            card.owner_id,
+            ReviewHistoryEntry {
+                card_id,
+                reviewed_on: today,
+                grade,
+            },
+        );
+        self.store.record_activity(card.owner_id, today);
         Ok(ReviewOutcome {
+            new_interval_days: card.state.interval_days,
+            ease_delta: card.state.ease_factor - previous_ease,
             card,
             previous_due,
             grade,
         })
     }
 
+    /// Applies a review like [`Scheduler::review`], skipping it when `idempotency_key` was
+    /// already applied to the same card.
+    ///
+    /// A repeated key returns the outcome the store recorded for the first successful review
+    /// without rescheduling the card or recording activity, so a client resubmitting after a
+    /// dropped connection does not advance the card twice. Keys live in the store next to the
+    /// review history, through [`SchedulerStore::applied_review`], so they survive
+    /// [`into_store`](Self::into_store) and go away when the store is cleared. Reviews without
+    /// a key, and reviews that fail, are not recorded.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Scheduler::review`].
+    #[must_use = "handle potential errors when reviewing a card"]
+    pub fn review_with_key(
+        &mut self,
+        card_id: Uuid,
+        grade: ReviewGrade,
+        today: NaiveDate,
+        idempotency_key: Option<&str>,
+    ) -> Result<ReviewOutcome, SchedulerError> {
+        let Some(key) = idempotency_key else {
+            return self.review(card_id, grade, today);
+        };
+        if let Some(prior) = self.store.applied_review(card_id, key) {
+            return Ok(prior);
+        }
+        let outcome = self.review(card_id, grade, today)?;
+        self.store
+            .record_applied_review(card_id, key, outcome.clone());
+        Ok(outcome)
+    }
+
+    /// Applies a raw numeric grade (0-4), as received from an HTTP or CLI boundary.
+    ///
+    /// The value is validated with [`Grade::from_u8`](review_domain::Grade::from_u8) and
+    /// mapped to a [`ReviewGrade`] before delegating to [`Scheduler::review`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SchedulerError::InvalidGrade`] for values above 4 without touching the
+    /// store, and otherwise the same errors as [`Scheduler::review`].
+    #[must_use = "handle potential errors when reviewing a card"]
+    pub fn record_review_u8(
+        &mut self,
+        card_id: Uuid,
+        grade: u8,
+        today: NaiveDate,
+    ) -> Result<ReviewOutcome, SchedulerError> {
+        let grade =
+            ReviewGrade::from_u8(grade).map_err(|_| SchedulerError::InvalidGrade { grade })?;
+        self.review(card_id, grade, today)
+    }
+
+    /// Due date each grade would give `card` if it were reviewed `today`, using this
+    /// scheduler's configuration. See [`preview_intervals`].
+    #[must_use]
+    pub fn preview_intervals(
+        &self,
+        card: &Card,
+        today: NaiveDate,
+    ) -> [(ReviewGrade, NaiveDate); 4] {
+        preview_intervals(card, &self.config, today)
+    }
+
+    /// Records a practice review of a card before it is due, without rescheduling it.
+    ///
+    /// Only the card's `reviews` counter, the owner's activity log, and the store's review
+    /// history change; the stage, ease, interval, and due date stay put, so early practice
+    /// does not count toward spaced repetition but still feeds accuracy statistics. The
+    /// recorded entry is also returned.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Scheduler::review`].
+    pub fn review_ahead(
+        &mut self,
+        card_id: Uuid,
+        grade: ReviewGrade,
+        today: NaiveDate,
+    ) -> Result<ReviewHistoryEntry, SchedulerError> {
+        let mut card = self.reviewable_card(card_id, today)?;
+        card.state.reviews = card.state.reviews.saturating_add(1);
+        let owner_id = card.owner_id;
+        self.store.upsert_card(card);
+        let entry = ReviewHistoryEntry {
+            card_id,
+            reviewed_on: today,
+            grade,
+        };
+        self.store.record_review(owner_id, entry);
+        self.store.record_activity(owner_id, today);
+        Ok(entry)
+    }
+
+    /// Returns every opening card of `owner_id` whose `parent_prefix` starts with
+    /// `parent_prefix`, whether or not it is due, for cramming a whole line.
+    ///
+    /// Cards in every stage, `New` and `Retired` included, are ordered by prefix and id.
+    /// Grade crammed cards with
+    /// [`review_ahead`](Self::review_ahead) so their real due dates are not disturbed.
+    #[must_use]
+    pub fn cram(&self, owner_id: Uuid, parent_prefix: &str) -> Vec<Card> {
+        let mut cards: Vec<Card> = self
+            .store
+            .cards_for_owner(owner_id)
+            .into_iter()
+            .filter(|card| match &card.kind {
+                CardKind::Opening(opening) => opening.parent_prefix.starts_with(parent_prefix),
+                CardKind::Tactic(_) => false,
+            })
+            .collect();
+        cards.sort_by(candidate_ordering);
+        cards
+    }
+
+    /// Scans `owner_id`'s cards for broken invariants and returns one warning per violation.
+    ///
+    /// Checks Review and Relearning cards for zero intervals, every card's ease against
+    /// [`SchedulerConfig::ease_bounds`], and due dates before
+    /// [`EARLIEST_VALID_DUE`](crate::health::EARLIEST_VALID_DUE). Every card, retired ones
+    /// included, is scanned in due-date order, and nothing is modified, so it is safe to run
+    /// over generated data in CI.
+    #[must_use]
+    pub fn health_check(&self, owner_id: Uuid) -> Vec<SchedulerWarning> {
+        self.store
+            .cards_for_owner(owner_id)
+            .into_iter()
+            .flat_map(|card| card_warnings(&card, &self.config))
+            .collect()
+    }
+
+    /// Fetches `card_id`, rejecting review dates before the card's schedule was set.
+    fn reviewable_card(&self, card_id: Uuid, today: NaiveDate) -> Result<Card, SchedulerError> {
+        let card = self
+            .store
+            .get_card(card_id)
+            .ok_or(SchedulerError::CardNotFound { id: card_id })?;
+        let earliest = card.state.scheduled_on();
+        if today < earliest {
+            return Err(SchedulerError::InvalidDate {
+                id: card_id,
+                reviewed_on: today,
+                earliest,
+            });
+        }
+        Ok(card)
+    }
+
+    /// Retires `owner_id`'s mastered cards and returns their ids, sorted.
+    ///
+    /// A card qualifies when it is in `Review`, its interval has reached
+    /// [`SchedulerConfig::mastery_interval_days`], and its last review is not after `today`.
+    /// Retired cards keep their SM-2 state but drop out of
+    /// [`due_cards`](SchedulerStore::due_cards) until [`reactivate_card`](Self::reactivate_card)
+    /// brings them back.
+    pub fn retire_mastered_cards(&mut self, owner_id: Uuid, today: NaiveDate) -> Vec<Uuid> {
+        let threshold = self.config.mastery_interval_days;
+        let mut retired = Vec::new();
+        for mut card in self.store.cards_for_owner(owner_id) {
+            let state = &card.state;
+            if state.stage == CardState::Review
+                && state.interval_days >= threshold
+                && state.scheduled_on() <= today
+            {
+                card.state.stage = CardState::Retired;
+                retired.push(card.id);
+                self.store.upsert_card(card);
+            }
+        }
+        retired.sort_unstable();
+        retired
+    }
+
+    /// Returns a retired or suspended card to `Review`, due on `today`.
+    ///
+    /// Cards in any other stage are returned unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SchedulerError::CardNotFound`] when `card_id` is not stored.
+    pub fn reactivate_card(
+        &mut self,
+        card_id: Uuid,
+        today: NaiveDate,
+    ) -> Result<Card, SchedulerError> {
+        let mut card = self
+            .store
+            .get_card(card_id)
+            .ok_or(SchedulerError::CardNotFound { id: card_id })?;
+        if matches!(card.state.stage, CardState::Retired | CardState::Suspended) {
+            card.state.stage = CardState::Review;
+            card.state.due = today;
+            self.store.upsert_card(card.clone());
+        }
+        Ok(card)
+    }
+
     /// Build the review and unlock queue for the specified owner on a given day.
     #[must_use]
     pub fn build_queue(&mut self, owner_id: Uuid, today: NaiveDate) -> Vec<Card> {
-        build_queue_for_day(&mut self.store, &self.config, owner_id, today)
+        self.build_queue_with(owner_id, today, QueueSelection::All)
+    }
+
+    /// Build the queue like [`Scheduler::build_queue`], limited to the cards `selection` asks
+    /// for.
+    #[must_use]
+    pub fn build_queue_with(
+        &mut self,
+        owner_id: Uuid,
+        today: NaiveDate,
+        selection: QueueSelection,
+    ) -> Vec<Card> {
+        build_queue_for_day(&mut self.store, &self.config, owner_id, today, selection)
+    }
+
+    /// Build the same queue as [`Scheduler::build_queue`], grouped into overdue, due-today,
+    /// and new cards.
+    #[must_use]
+    pub fn build_queue_breakdown(&mut self, owner_id: Uuid, today: NaiveDate) -> QueueBreakdown {
+        build_queue_breakdown(&mut self.store, &self.config, owner_id, today)
+    }
+
+    /// Returns `owner_id`'s cards that became due after `previous_check` and by `now`.
+    ///
+    /// Cards due in `(previous_check, now]` that have left `New` are returned, sorted by due
+    /// date and id, so a reminder job can notify only about cards that turned due since its
+    /// last run. Read-only; an empty window yields no cards.
+    #[must_use]
+    pub fn newly_due_since(
+        &self,
+        owner_id: Uuid,
+        previous_check: NaiveDate,
+        now: NaiveDate,
+    ) -> Vec<Card> {
+        let Some(start) = previous_check.succ_opt() else {
+            return Vec::new();
+        };
+        self.store.cards_due_between(owner_id, start, now)
+    }
+
+    /// Counts the cards `owner_id` introduced on `day`, whether unlocked or promoted out of
+    /// `New` by a review. [`SchedulerConfig::max_new_per_day`] caps this total.
+    #[must_use]
+    pub fn cards_introduced_on(&self, owner_id: Uuid, day: NaiveDate) -> usize {
+        self.store.cards_introduced_on(owner_id, day)
+    }
+
+    /// Returns `true` when `owner_id` recorded at least one review on `day`, according to
+    /// the store's [`active_days`](SchedulerStore::active_days).
+    #[must_use]
+    pub fn did_review_on(&self, owner_id: Uuid, day: NaiveDate) -> bool {
+        self.store.active_days(owner_id).contains(&day)
+    }
+
+    /// Counts consecutive days with at least one review, ending today or yesterday.
+    #[must_use]
+    pub fn current_streak(&self, owner_id: Uuid, today: NaiveDate) -> u32 {
+        streak_ending(&self.store.active_days(owner_id), today)
     }
 
     /// Consume the scheduler and return the inner store for reuse.
@@ -67,7 +346,7 @@ impl<S: SchedulerStore> Scheduler<S> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::{CardKind, CardState, SchedulerTacticCard, new_card};
+    use crate::domain::{CardKind, SchedulerOpeningCard, SchedulerTacticCard, new_card};
     use crate::errors::SchedulerError;
     use crate::store::InMemoryStore;
 
@@ -96,6 +375,530 @@ mod tests {
         assert!(outcome.card.state.due >= naive_date(2023, 1, 2));
     }
 
+    fn card_with_interval(owner: Uuid, stage: CardState, interval_days: u32) -> Card {
+        let mut card = new_card(
+            owner,
+            CardKind::Tactic(SchedulerTacticCard::new()),
+            naive_date(2023, 1, 1),
+            &SchedulerConfig::default(),
+        );
+        card.state.stage = stage;
+        card.state.interval_days = interval_days;
+        card.state.due = naive_date(2023, 1, 1) + chrono::Days::new(u64::from(interval_days));
+        card
+    }
+
+    #[test]
+    fn retire_mastered_cards_applies_the_interval_threshold_inclusively() {
+        let owner = Uuid::new_v4();
+        let config = SchedulerConfig {
+            mastery_interval_days: 90,
+            ..SchedulerConfig::default()
+        };
+        let at_threshold = card_with_interval(owner, CardState::Review, 90);
+        let above = card_with_interval(owner, CardState::Review, 200);
+        let below = card_with_interval(owner, CardState::Review, 89);
+        let relearning = card_with_interval(owner, CardState::Relearning, 120);
+        let other_owner = card_with_interval(Uuid::new_v4(), CardState::Review, 120);
+        let store = InMemoryStore::from_cards([
+            at_threshold.clone(),
+            above.clone(),
+            below.clone(),
+            relearning.clone(),
+            other_owner.clone(),
+        ]);
+        let mut scheduler = Scheduler::new(store, config);
+
+        let retired = scheduler.retire_mastered_cards(owner, naive_date(2023, 1, 1));
+
+        let mut expected = vec![at_threshold.id, above.id];
+        expected.sort_unstable();
+        assert_eq!(retired, expected);
+        let stage = |id| scheduler.store.get_card(id).expect("stored").state.stage;
+        assert_eq!(stage(at_threshold.id), CardState::Retired);
+        assert_eq!(stage(below.id), CardState::Review);
+        assert_eq!(stage(relearning.id), CardState::Relearning);
+        assert_eq!(stage(other_owner.id), CardState::Review);
+        let due = scheduler.store.due_cards(owner, NaiveDate::MAX);
+        assert!(due.iter().all(|card| !retired.contains(&card.id)));
+        assert!(
+            scheduler
+                .retire_mastered_cards(owner, naive_date(2023, 1, 1))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn retire_mastered_cards_skips_cards_reviewed_after_today() {
+        let owner = Uuid::new_v4();
+        let card = card_with_interval(owner, CardState::Review, 180);
+        let mut scheduler = Scheduler::new(
+            InMemoryStore::from_cards([card.clone()]),
+            SchedulerConfig::default(),
+        );
+
+        assert!(
+            scheduler
+                .retire_mastered_cards(owner, naive_date(2022, 12, 31))
+                .is_empty()
+        );
+        assert_eq!(
+            scheduler.retire_mastered_cards(owner, naive_date(2023, 1, 1)),
+            vec![card.id]
+        );
+    }
+
+    #[test]
+    fn reactivate_card_returns_retired_cards_to_review_due_today() {
+        let owner = Uuid::new_v4();
+        let card = card_with_interval(owner, CardState::Review, 365);
+        let learning = card_with_interval(owner, CardState::Learning, 1);
+        let mut scheduler = Scheduler::new(
+            InMemoryStore::from_cards([card.clone(), learning.clone()]),
+            SchedulerConfig::default(),
+        );
+        scheduler.retire_mastered_cards(owner, naive_date(2023, 1, 1));
+        let today = naive_date(2023, 3, 1);
+
+        let reactivated = scheduler.reactivate_card(card.id, today).expect("stored");
+
+        assert_eq!(reactivated.state.stage, CardState::Review);
+        assert_eq!(reactivated.state.due, today);
+        assert_eq!(reactivated.state.interval_days, 365);
+        assert!(
+            scheduler
+                .store
+                .due_cards(owner, today)
+                .contains(&reactivated)
+        );
+        assert_eq!(
+            scheduler
+                .reactivate_card(learning.id, today)
+                .expect("stored"),
+            learning
+        );
+        assert!(matches!(
+            scheduler.reactivate_card(Uuid::new_v4(), today),
+            Err(SchedulerError::CardNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn review_with_a_repeated_key_returns_the_first_outcome_without_rescheduling() {
+        let config = SchedulerConfig::default();
+        let mut card = new_card(
+            Uuid::new_v4(),
+            CardKind::Tactic(SchedulerTacticCard::new()),
+            naive_date(2023, 1, 1),
+            &config,
+        );
+        card.state.stage = CardState::Review;
+        let mut other = card.clone();
+        other.id = Uuid::new_v4();
+        let mut scheduler = Scheduler::new(
+            InMemoryStore::from_cards([card.clone(), other.clone()]),
+            config,
+        );
+        let today = naive_date(2023, 1, 1);
+
+        let first = scheduler
+            .review_with_key(card.id, ReviewGrade::Good, today, Some("attempt-1"))
+            .expect("card exists");
+        let retried = scheduler
+            .review_with_key(card.id, ReviewGrade::Easy, today, Some("attempt-1"))
+            .expect("card exists");
+
+        let other_outcome = scheduler
+            .review_with_key(other.id, ReviewGrade::Good, today, Some("attempt-1"))
+            .expect("card exists");
+
+        assert_eq!(retried, first);
+        assert_eq!(other_outcome.card.id, other.id);
+        let store = scheduler.into_store();
+        for id in [card.id, other.id] {
+            let stored = store.get_card(id).expect("card kept");
+            assert_eq!(stored.state.reviews, card.state.reviews + 1);
+        }
+        assert_eq!(store.get_card(card.id), Some(first.card));
+    }
+
+    #[test]
+    fn review_keys_survive_a_store_reload_and_are_dropped_on_clear() {
+        let config = SchedulerConfig::default();
+        let owner = Uuid::new_v4();
+        let mut card = new_card(
+            owner,
+            CardKind::Tactic(SchedulerTacticCard::new()),
+            naive_date(2023, 1, 1),
+            &config,
+        );
+        card.state.stage = CardState::Review;
+        let today = naive_date(2023, 1, 1);
+        let mut scheduler =
+            Scheduler::new(InMemoryStore::from_cards([card.clone()]), config.clone());
+        let first = scheduler
+            .review_with_key(card.id, ReviewGrade::Good, today, Some("attempt-1"))
+            .expect("card exists");
+
+        let mut reloaded = Scheduler::new(scheduler.into_store(), config);
+        let retried = reloaded
+            .review_with_key(card.id, ReviewGrade::Again, today, Some("attempt-1"))
+            .expect("card exists");
+        assert_eq!(retried, first);
+
+        let mut store = reloaded.into_store();
+        assert_eq!(store.get_card(card.id), Some(first.card));
+        assert_eq!(store.review_history(owner).len(), 1);
+
+        store.clear();
+        assert_eq!(store.applied_review(card.id, "attempt-1"), None);
+        assert!(store.review_history(owner).is_empty());
+    }
+
+    #[test]
+    fn review_outcome_reports_the_applied_interval_and_ease_change() {
+        let config = SchedulerConfig::default();
+        let mut card = new_card(
+            Uuid::new_v4(),
+            CardKind::Tactic(SchedulerTacticCard::new()),
+            naive_date(2023, 1, 1),
+            &config,
+        );
+        card.state.stage = CardState::Review;
+        card.state.reviews = 2;
+        card.state.interval_days = 6;
+        card.state.due = naive_date(2023, 1, 7);
+        let ease_before = card.state.ease_factor;
+        let mut scheduler = Scheduler::new(InMemoryStore::from_cards([card.clone()]), config);
+
+        let outcome = scheduler
+            .review(card.id, ReviewGrade::Hard, naive_date(2023, 1, 7))
+            .expect("card exists");
+
+        assert_eq!(outcome.new_interval_days, outcome.card.state.interval_days);
+        assert_eq!(
+            outcome.card.state.due,
+            naive_date(2023, 1, 7) + chrono::Days::new(u64::from(outcome.new_interval_days))
+        );
+        assert!((outcome.ease_delta - (outcome.card.state.ease_factor - ease_before)).abs() < 1e-6);
+        assert!(outcome.ease_delta < 0.0);
+    }
+
+    #[test]
+    fn review_ahead_leaves_the_schedule_unchanged_while_history_grows() {
+        let config = SchedulerConfig::default();
+        let owner = Uuid::new_v4();
+        let today = naive_date(2023, 1, 1);
+        let mut card = new_card(
+            owner,
+            CardKind::Tactic(SchedulerTacticCard::new()),
+            naive_date(2023, 1, 10),
+            &config,
+        );
+        card.state.stage = CardState::Review;
+        card.state.interval_days = 9;
+        let before = card.state.clone();
+        let mut scheduler = Scheduler::new(InMemoryStore::from_cards([card.clone()]), config);
+        let mut history = Vec::new();
+
+        for grade in [ReviewGrade::Again, ReviewGrade::Easy] {
+            history.push(
+                scheduler
+                    .review_ahead(card.id, grade, today)
+                    .expect("card exists"),
+            );
+        }
+
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[1].grade, ReviewGrade::Easy);
+        assert!(history.iter().all(|entry| entry.card_id == card.id));
+        assert!(scheduler.did_review_on(owner, today));
+        let store = scheduler.into_store();
+        assert_eq!(store.review_history(owner), history);
+        let stored = store.get_card(card.id).expect("card stored");
+        assert_eq!(stored.state.due, before.due);
+        assert_eq!(stored.state.interval_days, before.interval_days);
+        assert_eq!(stored.state.stage, before.stage);
+        assert_eq!(stored.state.lapses, before.lapses);
+        assert_eq!(stored.state.reviews, before.reviews + 2);
+    }
+
+    #[test]
+    fn record_review_u8_validates_before_delegating() {
+        let config = SchedulerConfig::default();
+        let card = new_card(
+            Uuid::new_v4(),
+            CardKind::Tactic(SchedulerTacticCard::new()),
+            naive_date(2023, 1, 1),
+            &config,
+        );
+        let mut scheduler = Scheduler::new(InMemoryStore::from_cards([card.clone()]), config);
+
+        let error = scheduler
+            .record_review_u8(card.id, 5, naive_date(2023, 1, 1))
+            .expect_err("grades above 4 are rejected");
+        assert!(matches!(error, SchedulerError::InvalidGrade { grade: 5 }));
+        assert!(!scheduler.did_review_on(card.owner_id, naive_date(2023, 1, 1)));
+
+        let outcome = scheduler
+            .record_review_u8(card.id, 3, naive_date(2023, 1, 1))
+            .expect("grade 3 is accepted");
+        assert_eq!(outcome.grade, ReviewGrade::Good);
+        assert_eq!(outcome.card.state.reviews, 1);
+
+        assert!(matches!(
+            scheduler.record_review_u8(Uuid::new_v4(), 1, naive_date(2023, 1, 1)),
+            Err(SchedulerError::CardNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn cram_returns_the_whole_line_and_review_ahead_keeps_due_dates() {
+        let config = SchedulerConfig::default();
+        let owner = Uuid::new_v4();
+        let today = naive_date(2023, 1, 1);
+        let opening = |prefix: &str, stage: CardState, interval_days: u32| {
+            let mut card = new_card(
+                owner,
+                CardKind::Opening(SchedulerOpeningCard::new(prefix)),
+                today + chrono::Days::new(u64::from(interval_days)),
+                &config,
+            );
+            card.state.stage = stage;
+            card.state.interval_days = interval_days;
+            card
+        };
+        let due_today = opening("e4-c5", CardState::Review, 0);
+        let next_month = opening("e4-c5-Nf3", CardState::Review, 30);
+        let unlearned = opening("e4-c5-Nf3-d6", CardState::New, 1);
+        let other_line = opening("d4-d5", CardState::Review, 30);
+        let mut retired = opening("e4-c5-Nc3", CardState::Review, 90);
+        retired.state.stage = CardState::Retired;
+        let tactic = new_card(
+            owner,
+            CardKind::Tactic(SchedulerTacticCard::new()),
+            today,
+            &config,
+        );
+        let mut scheduler = Scheduler::new(
+            InMemoryStore::from_cards([
+                due_today.clone(),
+                next_month.clone(),
+                unlearned.clone(),
+                other_line,
+                retired.clone(),
+                tactic,
+            ]),
+            config,
+        );
+
+        let crammed = scheduler.cram(owner, "e4-c5");
+        let ids: Vec<Uuid> = crammed.iter().map(|card| card.id).collect();
+        assert_eq!(
+            ids,
+            vec![due_today.id, retired.id, next_month.id, unlearned.id]
+        );
+        assert!(scheduler.cram(Uuid::new_v4(), "e4-c5").is_empty());
+
+        for card in &crammed {
+            let _ = scheduler
+                .review_ahead(card.id, ReviewGrade::Easy, today)
+                .expect("crammed cards can be practised");
+        }
+        let store = scheduler.into_store();
+        for card in crammed {
+            let stored = store.get_card(card.id).expect("card stored");
+            assert_eq!(stored.state.due, card.state.due);
+            assert_eq!(stored.state.stage, card.state.stage);
+            assert_eq!(stored.state.interval_days, card.state.interval_days);
+        }
+    }
+
+    #[test]
+    fn health_check_reports_a_corrupted_card_without_touching_it() {
+        let config = SchedulerConfig::default();
+        let owner = Uuid::new_v4();
+        let healthy = card_with_interval(owner, CardState::Review, 6);
+        let mut corrupted = card_with_interval(owner, CardState::Review, 0);
+        corrupted.state.ease_factor = config.ease_bounds.maximum + 1.0;
+        let mut retired = card_with_interval(owner, CardState::Retired, 120);
+        retired.state.ease_factor = config.ease_bounds.minimum - 0.1;
+        let scheduler = Scheduler::new(
+            InMemoryStore::from_cards([healthy, corrupted.clone(), retired.clone()]),
+            config.clone(),
+        );
+
+        let warnings = scheduler.health_check(owner);
+
+        assert_eq!(
+            warnings,
+            vec![
+                SchedulerWarning::ZeroInterval {
+                    card_id: corrupted.id,
+                    stage: CardState::Review,
+                },
+                SchedulerWarning::EaseOutOfBounds {
+                    card_id: corrupted.id,
+                    ease_factor: config.ease_bounds.maximum + 1.0,
+                    minimum: config.ease_bounds.minimum,
+                    maximum: config.ease_bounds.maximum,
+                },
+                SchedulerWarning::EaseOutOfBounds {
+                    card_id: retired.id,
+                    ease_factor: config.ease_bounds.minimum - 0.1,
+                    minimum: config.ease_bounds.minimum,
+                    maximum: config.ease_bounds.maximum,
+                },
+            ]
+        );
+        assert!(scheduler.health_check(Uuid::new_v4()).is_empty());
+        let stored = scheduler
+            .into_store()
+            .get_card(corrupted.id)
+            .expect("card stored");
+        assert_eq!(stored, corrupted);
+    }
+
+    #[test]
+    fn review_ahead_rejects_missing_cards() {
+        let config = SchedulerConfig::default();
+        let owner = Uuid::new_v4();
+        let card = new_card(
+            owner,
+            CardKind::Tactic(SchedulerTacticCard::new()),
+            naive_date(2023, 1, 1),
+            &config,
+        );
+        let mut scheduler = Scheduler::new(InMemoryStore::from_cards([card]), config);
+        let missing_id = Uuid::new_v4();
+
+        let error = scheduler
+            .review_ahead(missing_id, ReviewGrade::Good, naive_date(2023, 1, 1))
+            .expect_err("missing cards should surface an error");
+
+        assert!(matches!(error, SchedulerError::CardNotFound { id } if id == missing_id));
+        assert!(!scheduler.did_review_on(owner, naive_date(2023, 1, 1)));
+        let store = scheduler.into_store();
+        assert!(store.active_days(owner).is_empty());
+        assert!(store.review_history(owner).is_empty());
+    }
+
+    #[test]
+    fn activity_lives_in_the_store_and_survives_a_new_scheduler() {
+        let config = SchedulerConfig::default();
+        let owner = Uuid::new_v4();
+        let card = new_card(
+            owner,
+            CardKind::Tactic(SchedulerTacticCard::new()),
+            naive_date(2023, 1, 1),
+            &config,
+        );
+        let mut scheduler =
+            Scheduler::new(InMemoryStore::from_cards([card.clone()]), config.clone());
+        let _ = scheduler
+            .review(card.id, ReviewGrade::Good, naive_date(2023, 1, 1))
+            .expect("card exists");
+        let _ = scheduler
+            .review_ahead(card.id, ReviewGrade::Good, naive_date(2023, 1, 2))
+            .expect("card exists");
+
+        let mut store = scheduler.into_store();
+        assert_eq!(
+            store.active_days(owner).into_iter().collect::<Vec<_>>(),
+            vec![naive_date(2023, 1, 1), naive_date(2023, 1, 2)]
+        );
+        let reloaded = Scheduler::new(store, config);
+        assert!(reloaded.did_review_on(owner, naive_date(2023, 1, 2)));
+        assert_eq!(reloaded.current_streak(owner, naive_date(2023, 1, 3)), 2);
+
+        store = reloaded.into_store();
+        store.clear();
+        assert!(store.active_days(owner).is_empty());
+    }
+
+    #[test]
+    fn reviews_dated_before_the_last_review_are_rejected_without_side_effects() {
+        let config = SchedulerConfig::default();
+        let owner = Uuid::new_v4();
+        let card = new_card(
+            owner,
+            CardKind::Tactic(SchedulerTacticCard::new()),
+            naive_date(2023, 1, 1),
+            &config,
+        );
+        let mut scheduler = Scheduler::new(InMemoryStore::from_cards([card.clone()]), config);
+        let reviewed = scheduler
+            .review(card.id, ReviewGrade::Good, naive_date(2023, 1, 10))
+            .expect("first review is accepted")
+            .card;
+
+        let err = scheduler
+            .review(card.id, ReviewGrade::Good, naive_date(2023, 1, 9))
+            .expect_err("review before the last review is rejected");
+        assert!(matches!(
+            err,
+            SchedulerError::InvalidDate { id, reviewed_on, earliest }
+                if id == card.id
+                    && reviewed_on == naive_date(2023, 1, 9)
+                    && earliest == naive_date(2023, 1, 10)
+        ));
+        assert!(matches!(
+            scheduler.review_ahead(card.id, ReviewGrade::Good, naive_date(2023, 1, 9)),
+            Err(SchedulerError::InvalidDate { .. })
+        ));
+        assert!(!scheduler.did_review_on(owner, naive_date(2023, 1, 9)));
+
+        let same_day = scheduler
+            .review(card.id, ReviewGrade::Again, naive_date(2023, 1, 10))
+            .expect("same-day review is accepted");
+        assert_eq!(same_day.previous_due, reviewed.state.due);
+        assert_eq!(same_day.card.state.reviews, 2);
+    }
+
+    #[test]
+    fn newly_due_since_returns_cards_due_after_the_previous_check_up_to_now() {
+        let config = SchedulerConfig::default();
+        let owner = Uuid::new_v4();
+        let card_due_on = |day: u32, stage: CardState| {
+            let mut card = new_card(
+                owner,
+                CardKind::Tactic(SchedulerTacticCard::new()),
+                naive_date(2023, 1, day),
+                &config,
+            );
+            card.state.stage = stage;
+            card
+        };
+        let already_due = card_due_on(3, CardState::Review);
+        let turned_due = card_due_on(4, CardState::Review);
+        let due_exactly_now = card_due_on(5, CardState::Learning);
+        let still_new = card_due_on(5, CardState::New);
+        let not_yet_due = card_due_on(6, CardState::Review);
+        let scheduler = Scheduler::new(
+            InMemoryStore::from_cards([
+                already_due,
+                turned_due.clone(),
+                due_exactly_now.clone(),
+                still_new,
+                not_yet_due,
+            ]),
+            config.clone(),
+        );
+
+        let ids: Vec<Uuid> = scheduler
+            .newly_due_since(owner, naive_date(2023, 1, 3), naive_date(2023, 1, 5))
+            .iter()
+            .map(|card| card.id)
+            .collect();
+
+        assert_eq!(ids, vec![turned_due.id, due_exactly_now.id]);
+        assert!(
+            scheduler
+                .newly_due_since(owner, naive_date(2023, 1, 5), naive_date(2023, 1, 5))
+                .is_empty()
+        );
+    }
+
     #[test]
     fn build_queue_delegates_to_helper() {
         let store = InMemoryStore::new();
@@ -117,6 +920,107 @@ mod tests {
             .review(missing_id, ReviewGrade::Good, naive_date(2023, 1, 1))
             .expect_err("missing cards should surface an error");
 
-        assert!(matches!(error, SchedulerError::CardNotFound(id) if id == missing_id));
+        assert!(matches!(error, SchedulerError::CardNotFound { id } if id == missing_id));
+    }
+
+    #[test]
+    fn review_against_empty_queue_is_rejected_without_side_effects() {
+        let owner = Uuid::new_v4();
+        let config = SchedulerConfig::default();
+        let today = naive_date(2023, 1, 1);
+        let mut future = new_card(
+            owner,
+            CardKind::Tactic(SchedulerTacticCard::new()),
+            naive_date(2023, 1, 10),
+            &config,
+        );
+        future.state.stage = CardState::Review;
+        let future_id = future.id;
+        let mut scheduler = Scheduler::new(InMemoryStore::from_cards([future]), config);
+
+        assert!(scheduler.build_queue(owner, today).is_empty());
+        let stale_id = Uuid::new_v4();
+        let error = scheduler
+            .review(stale_id, ReviewGrade::Again, today)
+            .expect_err("grading an empty queue is a caller error");
+
+        assert!(matches!(error, SchedulerError::CardNotFound { id } if id == stale_id));
+        let store = scheduler.into_store();
+        let untouched = store.get_card(future_id).expect("card remains stored");
+        assert_eq!(untouched.state.due, naive_date(2023, 1, 10));
+        assert_eq!(untouched.state.reviews, 0);
+    }
+
+    #[test]
+    fn promotions_by_review_count_toward_the_daily_new_card_cap() {
+        let config = SchedulerConfig {
+            max_new_per_day: Some(2),
+            ..SchedulerConfig::default()
+        };
+        let owner = Uuid::new_v4();
+        let today = naive_date(2023, 1, 1);
+        let promoted = new_card(
+            owner,
+            CardKind::Tactic(SchedulerTacticCard::new()),
+            today,
+            &config,
+        );
+        let promoted_id = promoted.id;
+        let openings = ["a3", "b3"].map(|prefix| {
+            new_card(
+                owner,
+                CardKind::Opening(SchedulerOpeningCard::new(prefix)),
+                today,
+                &config,
+            )
+        });
+        let mut store = InMemoryStore::from_cards(openings);
+        store.upsert_card(promoted);
+        let mut scheduler = Scheduler::new(store, config);
+
+        scheduler
+            .review(promoted_id, ReviewGrade::Good, today)
+            .expect("card exists");
+        let queue = scheduler.build_queue(owner, today);
+
+        assert_eq!(scheduler.cards_introduced_on(owner, today), 2);
+        let unlocked: Vec<_> = queue
+            .iter()
+            .filter(|card| matches!(card.kind, CardKind::Opening(_)))
+            .collect();
+        assert_eq!(unlocked.len(), 1);
+        let _ = scheduler.build_queue(owner, today);
+        assert_eq!(scheduler.cards_introduced_on(owner, today), 2);
+    }
+
+    #[test]
+    fn reviews_maintain_the_owner_streak() {
+        let config = SchedulerConfig::default();
+        let owner = Uuid::new_v4();
+        let mut card = new_card(
+            owner,
+            CardKind::Tactic(SchedulerTacticCard::new()),
+            naive_date(2023, 1, 1),
+            &config,
+        );
+        card.state.stage = CardState::Review;
+        let card_id = card.id;
+        let mut scheduler = Scheduler::new(InMemoryStore::from_cards([card]), config);
+
+        for day in [1, 2, 3] {
+            scheduler
+                .review(card_id, ReviewGrade::Good, naive_date(2023, 1, day))
+                .expect("card exists");
+        }
+        assert!(scheduler.did_review_on(owner, naive_date(2023, 1, 2)));
+        assert_eq!(scheduler.current_streak(owner, naive_date(2023, 1, 4)), 3);
+
+        scheduler
+            .review(card_id, ReviewGrade::Good, naive_date(2023, 1, 6))
+            .expect("card exists");
+        assert_eq!(scheduler.current_streak(owner, naive_date(2023, 1, 6)), 1);
+
+        let _ = scheduler.review(Uuid::new_v4(), ReviewGrade::Good, naive_date(2023, 1, 7));
+        assert!(!scheduler.did_review_on(owner, naive_date(2023, 1, 7)));
     }
 }
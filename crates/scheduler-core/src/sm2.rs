@@ -1,9 +1,8 @@
 //! SM-2 scheduling logic extracted into focused helper functions.
 
 use chrono::{Duration, NaiveDate};
-use num_traits::ToPrimitive;
 
-use crate::config::SchedulerConfig;
+use crate::config::{RoundingPolicy, SchedulerConfig};
 use crate::domain::{Card, CardState};
 use review_domain::ReviewGrade;
 
@@ -16,20 +15,39 @@ pub(super) fn apply_sm2(
     let previous_reviews = card.state.reviews;
     let previous_interval = card.state.interval_days.max(1);
     let ease = update_ease(card.state.ease_factor, grade, config);
-    let interval = interval_for_grade(previous_reviews, previous_interval, grade, ease);
+    let interval = interval_for_grade(
+        previous_reviews,
+        previous_interval,
+        grade,
+        ease,
+        config.interval_rounding,
+    );
     finalize_review(card, interval, ease, today, grade);
 }
 
+/// Due date each review grade would give `card` if it were graded `today`, in
+/// `Again`, `Hard`, `Good`, `Easy` order. The card itself is left untouched.
+#[must_use]
+pub fn preview_intervals(
+    card: &Card,
+    config: &SchedulerConfig,
+    today: NaiveDate,
+) -> [(ReviewGrade, NaiveDate); 4] {
+    [
+        ReviewGrade::Again,
+        ReviewGrade::Hard,
+        ReviewGrade::Good,
+        ReviewGrade::Easy,
+    ]
+    .map(|grade| {
+        let mut preview = card.clone();
+        apply_sm2(&mut preview, grade, config, today);
+        (grade, preview.state.due)
+    })
+}
+
 pub(super) fn update_ease(current: f32, grade: ReviewGrade, config: &SchedulerConfig) -> f32 {
-    let quality = match grade {
-        ReviewGrade::Again => 0.0,
-        ReviewGrade::Hard => 3.0,
-        ReviewGrade::Good => 4.0,
-        ReviewGrade::Easy => 5.0,
-    };
-    let delta = 0.1 - (5.0 - quality) * (0.08 + (5.0 - quality) * 0.02);
-    let next = current + delta;
-    next.clamp(config.ease_minimum, config.ease_maximum)
+    config.ease_bounds.next_ease_factor(current, grade)
 }
 
 fn interval_for_grade(
@@ -37,50 +55,58 @@ fn interval_for_grade(
     previous_interval: u32,
     grade: ReviewGrade,
     ease: f32,
+    rounding: RoundingPolicy,
 ) -> u32 {
     match grade {
         ReviewGrade::Again => 1,
-        ReviewGrade::Hard => hard_interval(previous_reviews, previous_interval),
-        ReviewGrade::Good => good_interval(previous_reviews, previous_interval, ease),
-        ReviewGrade::Easy => easy_interval(previous_reviews, previous_interval, ease),
+        ReviewGrade::Hard => hard_interval(previous_reviews, previous_interval, rounding),
+        ReviewGrade::Good => good_interval(previous_reviews, previous_interval, ease, rounding),
+        ReviewGrade::Easy => easy_interval(previous_reviews, previous_interval, ease, rounding),
     }
 }
 
-fn hard_interval(previous_reviews: u32, previous_interval: u32) -> u32 {
+fn hard_interval(previous_reviews: u32, previous_interval: u32, rounding: RoundingPolicy) -> u32 {
     match previous_reviews {
         0 => 1,
         1 => 4,
-        _ => scaled_interval(previous_interval, 1.2),
+        _ => scaled_interval(previous_interval, 1.2, rounding),
     }
 }
 
-fn good_interval(previous_reviews: u32, previous_interval: u32, ease: f32) -> u32 {
+fn good_interval(
+    previous_reviews: u32,
+    previous_interval: u32,
+    ease: f32,
+    rounding: RoundingPolicy,
+) -> u32 {
     match previous_reviews {
         0 => 1,
         1 => 6,
-        _ => scaled_interval(previous_interval, f64::from(ease)),
+        _ => scaled_interval(previous_interval, f64::from(ease), rounding),
     }
 }
 
-fn easy_interval(previous_reviews: u32, previous_interval: u32, ease: f32) -> u32 {
+fn easy_interval(
+    previous_reviews: u32,
+    previous_interval: u32,
+    ease: f32,
+    rounding: RoundingPolicy,
+) -> u32 {
     match previous_reviews {
         0 => 1,
         1 => 6,
-        _ => scaled_interval(previous_interval, f64::from(ease) * 1.3),
+        _ => scaled_interval(previous_interval, f64::from(ease) * 1.3, rounding),
     }
 }
 
-fn scaled_interval(previous_interval: u32, multiplier: f64) -> u32 {
+/// The single place a fractional interval becomes whole days, under `rounding`.
+fn scaled_interval(previous_interval: u32, multiplier: f64, rounding: RoundingPolicy) -> u32 {
     let product = f64::from(previous_interval) * multiplier;
-    let rounded = if product.is_finite() {
-        product.round()
+    if product.is_finite() {
+        rounding.apply(product).max(1)
     } else {
-        1.0
-    };
-    let clamped = rounded.clamp(1.0, f64::from(u32::MAX));
-    clamped
-        .to_u32()
-        .expect("clamped value should always fit in u32")
+        1
+    }
 }
 
 fn finalize_review(
@@ -119,6 +145,7 @@ mod tests {
     use super::*;
     use crate::config::SchedulerConfig;
     use crate::domain::{CardKind, CardState, SchedulerTacticCard, new_card};
+    use review_domain::EaseBounds;
 
     fn naive_date(year: i32, month: u32, day: u32) -> NaiveDate {
         NaiveDate::from_ymd_opt(year, month, day).expect("valid date")
@@ -136,42 +163,120 @@ mod tests {
         card
     }
 
+    #[test]
+    fn preview_intervals_matches_applying_each_grade() {
+        let config = SchedulerConfig::default();
+        let today = naive_date(2023, 1, 1);
+        let mut card = sample_card(CardState::Review);
+        card.state.reviews = 3;
+        card.state.interval_days = 10;
+        let before = card.clone();
+
+        let preview = preview_intervals(&card, &config, today);
+
+        assert_eq!(card, before);
+        for (grade, due) in preview {
+            let mut graded = card.clone();
+            apply_sm2(&mut graded, grade, &config, today);
+            assert_eq!(due, graded.state.due, "{grade:?}");
+        }
+        let dues: Vec<_> = preview.iter().map(|(_, due)| *due).collect();
+        assert!(dues.is_sorted());
+        assert_eq!(preview[0], (ReviewGrade::Again, naive_date(2023, 1, 2)));
+    }
+
     #[test]
     fn update_ease_clamps_values() {
         let config = SchedulerConfig {
             initial_ease_factor: 2.0,
-            ease_minimum: 1.4,
-            ease_maximum: 2.3,
+            ease_bounds: EaseBounds {
+                minimum: 1.4,
+                maximum: 2.3,
+            },
             learning_steps_minutes: vec![],
+            queue_layout: crate::config::QueueLayout::default(),
+            new_card_due: review_domain::NewCardDue::Today,
+            max_new_per_day: None,
+            mastery_interval_days: 180,
+            interval_rounding: RoundingPolicy::default(),
         };
         assert!((update_ease(2.5, ReviewGrade::Hard, &config) - 2.3).abs() < f32::EPSILON);
         assert!((update_ease(1.0, ReviewGrade::Again, &config) - 1.4).abs() < f32::EPSILON);
     }
 
+    const ROUND: RoundingPolicy = RoundingPolicy::Round;
+
     #[test]
     fn hard_interval_handles_first_reviews() {
-        assert_eq!(hard_interval(0, 10), 1);
-        assert_eq!(hard_interval(1, 10), 4);
-        assert_eq!(hard_interval(5, 10), 12);
+        assert_eq!(hard_interval(0, 10, ROUND), 1);
+        assert_eq!(hard_interval(1, 10, ROUND), 4);
+        assert_eq!(hard_interval(5, 10, ROUND), 12);
     }
 
     #[test]
     fn good_interval_handles_first_reviews() {
-        assert_eq!(good_interval(0, 1, 2.0), 1);
-        assert_eq!(good_interval(1, 1, 2.0), 6);
-        assert_eq!(good_interval(2, 10, 2.5), 25);
+        assert_eq!(good_interval(0, 1, 2.0, ROUND), 1);
+        assert_eq!(good_interval(1, 1, 2.0, ROUND), 6);
+        assert_eq!(good_interval(2, 10, 2.5, ROUND), 25);
     }
 
     #[test]
     fn easy_interval_handles_first_reviews() {
-        assert_eq!(easy_interval(0, 1, 2.0), 1);
-        assert_eq!(easy_interval(1, 1, 2.0), 6);
-        assert_eq!(easy_interval(3, 10, 2.0), 26);
+        assert_eq!(easy_interval(0, 1, 2.0, ROUND), 1);
+        assert_eq!(easy_interval(1, 1, 2.0, ROUND), 6);
+        assert_eq!(easy_interval(3, 10, 2.0, ROUND), 26);
     }
 
     #[test]
     fn scaled_interval_handles_non_finite_product() {
-        assert_eq!(scaled_interval(5, f64::INFINITY), 1);
+        assert_eq!(scaled_interval(5, f64::INFINITY, ROUND), 1);
+    }
+
+    #[test]
+    fn scaled_interval_applies_each_rounding_policy() {
+        for policy in [
+            RoundingPolicy::Round,
+            RoundingPolicy::Floor,
+            RoundingPolicy::Ceil,
+        ] {
+            assert_eq!(good_interval(2, 6, 2.5, policy), 15, "{policy:?}");
+            assert_eq!(good_interval(2, 10, 2.3, policy), 23, "{policy:?}");
+        }
+        // 7 * 2.5 = 17.5 and 7 * 1.2 = 8.4.
+        assert_eq!(good_interval(2, 7, 2.5, RoundingPolicy::Round), 18);
+        assert_eq!(good_interval(2, 7, 2.5, RoundingPolicy::Floor), 17);
+        assert_eq!(good_interval(2, 7, 2.5, RoundingPolicy::Ceil), 18);
+        assert_eq!(hard_interval(2, 7, RoundingPolicy::Round), 8);
+        assert_eq!(hard_interval(2, 7, RoundingPolicy::Floor), 8);
+        assert_eq!(hard_interval(2, 7, RoundingPolicy::Ceil), 9);
+    }
+
+    #[test]
+    fn apply_sm2_uses_the_configured_rounding_policy() {
+        let mut card = sample_card(CardState::Review);
+        card.state.reviews = 2;
+        card.state.interval_days = 7;
+        card.state.ease_factor = 2.5;
+        let today = naive_date(2023, 1, 1);
+
+        let intervals: Vec<_> = [
+            RoundingPolicy::Round,
+            RoundingPolicy::Floor,
+            RoundingPolicy::Ceil,
+        ]
+        .into_iter()
+        .map(|interval_rounding| {
+            let config = SchedulerConfig {
+                interval_rounding,
+                ..SchedulerConfig::default()
+            };
+            let mut graded = card.clone();
+            apply_sm2(&mut graded, ReviewGrade::Hard, &config, today);
+            graded.state.interval_days
+        })
+        .collect();
+
+        assert_eq!(intervals, vec![8, 8, 9]);
     }
 
     #[test]
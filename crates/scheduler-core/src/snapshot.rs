@@ -0,0 +1,402 @@
+//! Daily review snapshots summarising a learner's progress for planning surfaces.
+
+use std::collections::BTreeSet;
+
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::activity::streak_ending;
+use crate::config::SchedulerConfig;
+use crate::domain::CardKind;
+use crate::store::{SchedulerStore, candidate_ordering};
+use review_domain::ReviewGrade;
+
+/// A single graded review recorded for an owner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReviewHistoryEntry {
+    /// Identifier of the card that was reviewed.
+    pub card_id: Uuid,
+    /// Day on which the review was recorded.
+    pub reviewed_on: NaiveDate,
+    /// Grade awarded for the review.
+    pub grade: ReviewGrade,
+}
+
+/// Opening line waiting to be unlocked, with the day the queue is expected to unlock it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpcomingUnlock {
+    /// Identifier of the candidate card.
+    pub card_id: Uuid,
+    /// Parent line prefix the card belongs to.
+    pub parent_prefix: String,
+    /// Day the unlock is expected to happen if the queue is built every day.
+    pub scheduled_for: NaiveDate,
+}
+
+/// Aggregate counts consumed by the review planner for a single owner and day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReviewSnapshot {
+    /// Cards due today, including those already reviewed today.
+    pub due_cards: usize,
+    /// Distinct cards reviewed today.
+    pub completed_cards: usize,
+    /// Share of today's reviews not graded [`ReviewGrade::Again`], in `0.0..=1.0`.
+    pub accuracy_rate: f64,
+    /// Consecutive days with at least one review, ending today or yesterday, over the store's
+    /// [`active_days`](SchedulerStore::active_days) and the supplied history; counted the
+    /// same way as [`ActivityLog::current_streak`](crate::activity::ActivityLog::current_streak).
+    pub streak_length: u32,
+    /// Opening candidates waiting to be unlocked, in unlock order; see [`upcoming_unlocks`].
+    pub upcoming_unlocks: Vec<UpcomingUnlock>,
+}
+
+impl ReviewSnapshot {
+    /// Builds a snapshot from the store and the owner's review history.
+    ///
+    /// `history` must only contain reviews for `owner_id`. Cards reviewed today are counted
+    /// as due even though the store has already rescheduled them, so `due_cards -
+    /// completed_cards` is what remains. With no reviews today the accuracy is `1.0`.
+    ///
+    /// Upcoming unlocks are projected with [`SchedulerConfig::default`]; use
+    /// [`from_store_with_config`](Self::from_store_with_config) when queues are built with
+    /// another configuration.
+    #[must_use]
+    pub fn from_store<S: SchedulerStore + ?Sized>(
+        store: &S,
+        owner_id: Uuid,
+        today: NaiveDate,
+        history: &[ReviewHistoryEntry],
+    ) -> Self {
+        Self::from_store_with_config(store, &SchedulerConfig::default(), owner_id, today, history)
+    }
+
+    /// Builds a snapshot like [`from_store`](Self::from_store), projecting upcoming unlocks
+    /// with the `config` used to build the owner's queues.
+    #[must_use]
+    pub fn from_store_with_config<S: SchedulerStore + ?Sized>(
+        store: &S,
+        config: &SchedulerConfig,
+        owner_id: Uuid,
+        today: NaiveDate,
+        history: &[ReviewHistoryEntry],
+    ) -> Self {
+        let todays_reviews: Vec<&ReviewHistoryEntry> = history
+            .iter()
+            .filter(|entry| entry.reviewed_on == today)
+            .collect();
+        let completed: BTreeSet<Uuid> = todays_reviews.iter().map(|entry| entry.card_id).collect();
+        let mut due: BTreeSet<Uuid> = store
+            .due_cards(owner_id, today)
+            .into_iter()
+            .map(|card| card.id)
+            .collect();
+        due.extend(completed.iter().copied());
+
+        Self {
+            due_cards: due.len(),
+            completed_cards: completed.len(),
+            accuracy_rate: accuracy_rate(&todays_reviews),
+            streak_length: streak_length(store, owner_id, history, today),
+            upcoming_unlocks: upcoming_unlocks(store, config, owner_id, today),
+        }
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn accuracy_rate(reviews: &[&ReviewHistoryEntry]) -> f64 {
+    if reviews.is_empty() {
+        return 1.0;
+    }
+    let correct = reviews
+        .iter()
+        .filter(|entry| entry.grade != ReviewGrade::Again)
+        .count();
+    correct as f64 / reviews.len() as f64
+}
+
+fn streak_length<S: SchedulerStore + ?Sized>(
+    store: &S,
+    owner_id: Uuid,
+    history: &[ReviewHistoryEntry],
+    today: NaiveDate,
+) -> u32 {
+    let mut days = store.active_days(owner_id);
+    days.extend(history.iter().map(|entry| entry.reviewed_on));
+    streak_ending(&days, today)
+}
+
+/// Projects when each opening candidate unlocks if the queue is built every day.
+///
+/// Mirrors the queue's rules: at most one card per parent prefix per day, candidates taken in
+/// [`candidate_ordering`], and no more than [`SchedulerConfig::max_new_per_day`] introductions
+/// a day. Today's budget and prefixes start from what was already introduced and unlocked
+/// today. Candidates that can never unlock because the daily cap is zero are left out.
+fn upcoming_unlocks<S: SchedulerStore + ?Sized>(
+    store: &S,
+    config: &SchedulerConfig,
+    owner_id: Uuid,
+    today: NaiveDate,
+) -> Vec<UpcomingUnlock> {
+    let mut candidates = store.unlock_candidates(owner_id);
+    candidates.sort_by(candidate_ordering);
+    let mut pending: Vec<(Uuid, String)> = candidates
+        .into_iter()
+        .filter_map(|card| match card.kind {
+            CardKind::Opening(opening) => Some((card.id, opening.parent_prefix)),
+            CardKind::Tactic(_) => None,
+        })
+        .collect();
+
+    let mut unlocks = Vec::with_capacity(pending.len());
+    let mut day = today;
+    let mut used_prefixes: BTreeSet<String> = store
+        .unlocked_on(owner_id, today)
+        .into_iter()
+        .filter_map(|record| record.detail.parent_prefix)
+        .collect();
+    let mut remaining = config
+        .max_new_per_day
+        .map(|cap| cap.saturating_sub(store.cards_introduced_on(owner_id, today)));
+    while !pending.is_empty() {
+        let before = unlocks.len();
+        let mut waiting = Vec::new();
+        for (card_id, parent_prefix) in pending {
+            if remaining == Some(0) || used_prefixes.contains(&parent_prefix) {
+                waiting.push((card_id, parent_prefix));
+                continue;
+            }
+            used_prefixes.insert(parent_prefix.clone());
+            if let Some(left) = remaining.as_mut() {
+                *left -= 1;
+            }
+            unlocks.push(UpcomingUnlock {
+                card_id,
+                parent_prefix,
+                scheduled_for: day,
+            });
+        }
+        if unlocks.len() == before && day > today {
+            break;
+        }
+        pending = waiting;
+        let Some(next_day) = day.succ_opt() else {
+            break;
+        };
+        day = next_day;
+        used_prefixes.clear();
+        remaining = config.max_new_per_day;
+    }
+    unlocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::SchedulerConfig;
+    use crate::domain::{CardState, SchedulerOpeningCard, SchedulerTacticCard, new_card};
+    use crate::queue::{QueueSelection, build_queue_for_day};
+    use crate::store::InMemoryStore;
+
+    fn naive_date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).expect("valid date")
+    }
+
+    fn entry(card_id: Uuid, reviewed_on: NaiveDate, grade: ReviewGrade) -> ReviewHistoryEntry {
+        ReviewHistoryEntry {
+            card_id,
+            reviewed_on,
+            grade,
+        }
+    }
+
+    #[test]
+    fn from_store_counts_due_completed_and_unlocks() {
+        let config = SchedulerConfig::default();
+        let owner = Uuid::new_v4();
+        let today = naive_date(2024, 1, 10);
+        let mut due = new_card(
+            owner,
+            CardKind::Tactic(SchedulerTacticCard::new()),
+            today,
+            &config,
+        );
+        due.state.stage = CardState::Review;
+        let mut reviewed = due.clone();
+        reviewed.id = Uuid::new_v4();
+        reviewed.state.due = naive_date(2024, 1, 12);
+        let d4 = new_card(
+            owner,
+            CardKind::Opening(SchedulerOpeningCard::new("d4")),
+            today,
+            &config,
+        );
+        let e4 = new_card(
+            owner,
+            CardKind::Opening(SchedulerOpeningCard::new("e4")),
+            today,
+            &config,
+        );
+        let store = InMemoryStore::from_cards([due, reviewed.clone(), e4.clone(), d4.clone()]);
+        let history = [
+            entry(reviewed.id, today, ReviewGrade::Again),
+            entry(reviewed.id, today, ReviewGrade::Good),
+            entry(reviewed.id, naive_date(2024, 1, 9), ReviewGrade::Good),
+        ];
+
+        let snapshot = ReviewSnapshot::from_store(&store, owner, today, &history);
+
+        assert_eq!(snapshot.due_cards, 2);
+        assert_eq!(snapshot.completed_cards, 1);
+        assert!((snapshot.accuracy_rate - 0.5).abs() < f64::EPSILON);
+        assert_eq!(snapshot.streak_length, 2);
+        let prefixes: Vec<&str> = snapshot
+            .upcoming_unlocks
+            .iter()
+            .map(|unlock| unlock.parent_prefix.as_str())
+            .collect();
+        assert_eq!(prefixes, vec!["d4", "e4"]);
+        assert_eq!(snapshot.upcoming_unlocks[0].card_id, d4.id);
+        assert_eq!(snapshot.upcoming_unlocks[0].scheduled_for, today);
+    }
+
+    #[test]
+    fn from_store_defaults_for_empty_history() {
+        let store = InMemoryStore::new();
+        let snapshot =
+            ReviewSnapshot::from_store(&store, Uuid::new_v4(), naive_date(2024, 1, 10), &[]);
+
+        assert_eq!(snapshot.due_cards, 0);
+        assert_eq!(snapshot.completed_cards, 0);
+        assert!((snapshot.accuracy_rate - 1.0).abs() < f64::EPSILON);
+        assert_eq!(snapshot.streak_length, 0);
+        assert!(snapshot.upcoming_unlocks.is_empty());
+    }
+
+    #[test]
+    fn upcoming_unlocks_take_one_card_per_prefix_per_day_within_the_cap() {
+        let config = SchedulerConfig {
+            max_new_per_day: Some(2),
+            ..SchedulerConfig::default()
+        };
+        let owner = Uuid::new_v4();
+        let today = naive_date(2024, 1, 10);
+        let opening = |prefix: &str| {
+            new_card(
+                owner,
+                CardKind::Opening(SchedulerOpeningCard::new(prefix)),
+                today,
+                &config,
+            )
+        };
+        let cards = [opening("e4"), opening("e4"), opening("c4"), opening("d4")];
+        let mut store = InMemoryStore::from_cards(cards.clone());
+        let mut e4_ids = [cards[0].id, cards[1].id];
+        e4_ids.sort();
+
+        let unlocks = upcoming_unlocks(&store, &config, owner, today);
+        let projected: Vec<(&str, NaiveDate)> = unlocks
+            .iter()
+            .map(|unlock| (unlock.parent_prefix.as_str(), unlock.scheduled_for))
+            .collect();
+        assert_eq!(
+            projected,
+            vec![
+                ("c4", today),
+                ("d4", today),
+                ("e4", naive_date(2024, 1, 11)),
+                ("e4", naive_date(2024, 1, 12)),
+            ]
+        );
+        assert_eq!(unlocks[2].card_id, e4_ids[0]);
+        assert_eq!(unlocks[3].card_id, e4_ids[1]);
+
+        let queue = build_queue_for_day(&mut store, &config, owner, today, QueueSelection::All);
+        let mut unlocked_today: Vec<Uuid> = queue.iter().map(|card| card.id).collect();
+        unlocked_today.sort();
+        let mut projected_today = vec![unlocks[0].card_id, unlocks[1].card_id];
+        projected_today.sort();
+        assert_eq!(unlocked_today, projected_today);
+
+        let blocked = SchedulerConfig {
+            max_new_per_day: Some(0),
+            ..config
+        };
+        assert!(upcoming_unlocks(&store, &blocked, owner, today).is_empty());
+    }
+
+    #[test]
+    fn from_store_with_config_projects_unlocks_under_that_config() {
+        let owner = Uuid::new_v4();
+        let today = naive_date(2024, 1, 10);
+        let config = SchedulerConfig {
+            max_new_per_day: Some(1),
+            ..SchedulerConfig::default()
+        };
+        let cards = ["c4", "d4"].map(|prefix| {
+            new_card(
+                owner,
+                CardKind::Opening(SchedulerOpeningCard::new(prefix)),
+                today,
+                &config,
+            )
+        });
+        let store = InMemoryStore::from_cards(cards);
+
+        let defaults = ReviewSnapshot::from_store(&store, owner, today, &[]);
+        let capped = ReviewSnapshot::from_store_with_config(&store, &config, owner, today, &[]);
+
+        let days = |snapshot: &ReviewSnapshot| -> Vec<NaiveDate> {
+            snapshot
+                .upcoming_unlocks
+                .iter()
+                .map(|unlock| unlock.scheduled_for)
+                .collect()
+        };
+        assert_eq!(days(&defaults), vec![today, today]);
+        assert_eq!(days(&capped), vec![today, naive_date(2024, 1, 11)]);
+    }
+
+    #[test]
+    fn streak_survives_until_today_is_reviewed() {
+        let card = Uuid::new_v4();
+        let history = [
+            entry(card, naive_date(2024, 1, 7), ReviewGrade::Good),
+            entry(card, naive_date(2024, 1, 8), ReviewGrade::Good),
+            entry(card, naive_date(2024, 1, 9), ReviewGrade::Hard),
+        ];
+
+        let store = InMemoryStore::new();
+        let owner = Uuid::new_v4();
+
+        assert_eq!(
+            streak_length(&store, owner, &history, naive_date(2024, 1, 10)),
+            3
+        );
+        assert_eq!(
+            streak_length(&store, owner, &history, naive_date(2024, 1, 11)),
+            0
+        );
+    }
+
+    #[test]
+    fn streak_includes_activity_recorded_in_the_store() {
+        let owner = Uuid::new_v4();
+        let mut store = InMemoryStore::new();
+        for day in [6, 7] {
+            store.record_activity(owner, naive_date(2024, 1, day));
+        }
+        store.record_activity(Uuid::new_v4(), naive_date(2024, 1, 8));
+        let history = [entry(
+            Uuid::new_v4(),
+            naive_date(2024, 1, 8),
+            ReviewGrade::Good,
+        )];
+
+        let from_store = ReviewSnapshot::from_store(&store, owner, naive_date(2024, 1, 8), &[]);
+        let combined = ReviewSnapshot::from_store(&store, owner, naive_date(2024, 1, 8), &history);
+
+        assert_eq!(from_store.streak_length, 2);
+        assert_eq!(combined.streak_length, 3);
+    }
+}
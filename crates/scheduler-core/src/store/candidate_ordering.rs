@@ -5,6 +5,11 @@ use crate::{Card, CardKind};
 /// and then by their UUID to ensure a stable order.
 /// Tactic cards are ordered last, sorted by their UUID.
 ///
+/// This is a total order: openings compare by `(parent_prefix, id)` and tactics by `id`, so
+/// two openings sharing a prefix always sort by id (`Uuid::from_u128(1)` before
+/// `Uuid::from_u128(2)`), and only cards of the same kind with the same prefix and id
+/// compare `Equal`. Store iteration order never leaks into the unlock order.
+///
 /// # Examples
 /// ```
 /// use uuid::Uuid;
@@ -1,18 +1,32 @@
 use review_domain::StudyStage;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 use uuid::Uuid;
 
 use chrono::NaiveDate;
 
 use super::SchedulerStore;
+use crate::activity::ActivityLog;
 use crate::store::candidate_ordering;
-use crate::{Card, UnlockRecord};
+use crate::{Card, ReviewHistoryEntry, ReviewOutcome, SchedulerError, UnlockRecord};
 
 /// Reference in-memory implementation of [`SchedulerStore`] used in tests.
 #[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InMemoryStore {
     cards: BTreeMap<Uuid, Card>,
     unlock_log: Vec<UnlockRecord>,
+    /// `(owner_id, card_id, unlocked_on)` of every entry in `unlock_log`, so duplicate checks
+    /// do not scan the log. Rebuilt from the log by [`InMemoryStore::from_json`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    unlock_index: HashSet<(Uuid, Uuid, NaiveDate)>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    introductions: BTreeMap<Uuid, BTreeMap<NaiveDate, BTreeSet<Uuid>>>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    review_history: BTreeMap<Uuid, Vec<ReviewHistoryEntry>>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    applied_keys: BTreeMap<Uuid, BTreeMap<String, ReviewOutcome>>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    activity: ActivityLog,
 }
 
 impl InMemoryStore {
@@ -21,6 +35,57 @@ impl InMemoryStore {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Construct a store pre-seeded with the provided cards.
+    #[must_use]
+    pub fn from_cards<I>(cards: I) -> Self
+    where
+        I: IntoIterator<Item = Card>,
+    {
+        let mut store = Self::new();
+        store.extend(cards);
+        store
+    }
+
+    /// Insert or update every card from the iterator through
+    /// [`upsert_card`](SchedulerStore::upsert_card), returning the store for chaining.
+    pub fn extend<I>(&mut self, cards: I) -> &mut Self
+    where
+        I: IntoIterator<Item = Card>,
+    {
+        for card in cards {
+            self.upsert_card(card);
+        }
+        self
+    }
+
+    /// Removes every card, unlock, introduction, review, idempotency key, and active day.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Serializes every card, the unlock log, and the review history, keys, and activity to
+    /// JSON so a simulation can be checkpointed.
+    ///
+    /// # Errors
+    ///
+    /// Returns the underlying [`serde_json::Error`] if serialization fails.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Restores a store previously written by [`InMemoryStore::to_json`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the underlying [`serde_json::Error`] when `json` is not a serialized store.
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        let mut store: Self = serde_json::from_str(json)?;
+        store.unlock_index = store.unlock_log.iter().map(unlock_key).collect();
+        Ok(store)
+    }
 }
 
 impl SchedulerStore for InMemoryStore {
@@ -39,14 +104,34 @@ impl SchedulerStore for InMemoryStore {
             .filter(|card| {
                 card.owner_id == owner_id
                     && card.state.due <= today
-                    && !matches!(card.state.stage, StudyStage::New)
+                    && !matches!(card.state.stage, StudyStage::New | StudyStage::Retired)
             })
             .cloned()
             .collect();
-        due.sort_by(|a, b| (a.state.due, a.id).cmp(&(b.state.due, b.id)));
+        due.sort_by_key(|card| (card.state.due, card.id));
         due
     }
 
+    fn cards_for_owner(&self, owner_id: Uuid) -> Vec<Card> {
+        let mut cards: Vec<Card> = self
+            .cards
+            .values()
+            .filter(|card| card.owner_id == owner_id)
+            .cloned()
+            .collect();
+        cards.sort_by_key(|card| (card.state.due, card.id));
+        cards
+    }
+
+    fn owners(&self) -> Vec<Uuid> {
+        self.cards
+            .values()
+            .map(|card| card.owner_id)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
     fn unlock_candidates(&self, owner_id: Uuid) -> Vec<Card> {
         let mut candidates: Vec<Card> = self
             .cards
@@ -58,8 +143,16 @@ impl SchedulerStore for InMemoryStore {
         candidates
     }
 
-    fn record_unlock(&mut self, record: UnlockRecord) {
+    fn record_unlock(&mut self, record: UnlockRecord) -> Result<(), SchedulerError> {
+        if !self.unlock_index.insert(unlock_key(&record)) {
+            return Err(SchedulerError::DuplicateUnlock {
+                owner_id: record.owner_id,
+                card_id: record.detail.card_id,
+                day: record.unlocked_on,
+            });
+        }
         self.unlock_log.push(record);
+        Ok(())
     }
 
     fn unlocked_on(&self, owner_id: Uuid, day: NaiveDate) -> Vec<UnlockRecord> {
@@ -69,6 +162,64 @@ impl SchedulerStore for InMemoryStore {
             .cloned()
             .collect()
     }
+
+    fn record_introduction(&mut self, owner_id: Uuid, card_id: Uuid, day: NaiveDate) {
+        self.introductions
+            .entry(owner_id)
+            .or_default()
+            .entry(day)
+            .or_default()
+            .insert(card_id);
+    }
+
+    fn cards_introduced_on(&self, owner_id: Uuid, day: NaiveDate) -> usize {
+        self.introductions
+            .get(&owner_id)
+            .and_then(|days| days.get(&day))
+            .map_or(0, BTreeSet::len)
+    }
+
+    fn record_review(&mut self, owner_id: Uuid, entry: ReviewHistoryEntry) {
+        self.review_history.entry(owner_id).or_default().push(entry);
+    }
+
+    fn review_history(&self, owner_id: Uuid) -> Vec<ReviewHistoryEntry> {
+        self.review_history
+            .get(&owner_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn record_activity(&mut self, owner_id: Uuid, day: NaiveDate) {
+        self.activity.record(owner_id, day);
+    }
+
+    fn active_days(&self, owner_id: Uuid) -> BTreeSet<NaiveDate> {
+        self.activity.active_days(owner_id)
+    }
+
+    fn record_applied_review(
+        &mut self,
+        card_id: Uuid,
+        idempotency_key: &str,
+        outcome: ReviewOutcome,
+    ) {
+        self.applied_keys
+            .entry(card_id)
+            .or_default()
+            .insert(idempotency_key.to_string(), outcome);
+    }
+
+    fn applied_review(&self, card_id: Uuid, idempotency_key: &str) -> Option<ReviewOutcome> {
+        self.applied_keys
+            .get(&card_id)?
+            .get(idempotency_key)
+            .cloned()
+    }
+}
+
+fn unlock_key(record: &UnlockRecord) -> (Uuid, Uuid, NaiveDate) {
+    (record.owner_id, record.detail.card_id, record.unlocked_on)
 }
 
 #[cfg(test)]
@@ -93,17 +244,204 @@ mod tests {
     fn make_unlock_record(owner_id: Uuid, card_id: Uuid, unlocked_on: NaiveDate) -> UnlockRecord {
         UnlockRecord {
             owner_id,
-            detail: SchedulerUnlockDetail {
-                card_id,
-                parent_prefix: None,
-            },
+            detail: SchedulerUnlockDetail::new(card_id, None),
             unlocked_on,
         }
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json_round_trip_preserves_queries() {
+        let owner_id = Uuid::new_v4();
+        let other_owner = Uuid::new_v4();
+        let today = NaiveDate::from_ymd_opt(2024, 3, 10).expect("valid date");
+        let mut store = InMemoryStore::new();
+        for (offset, stage) in [
+            (-2, StudyStage::Learning),
+            (0, StudyStage::Review),
+            (3, StudyStage::Relearning),
+            (0, StudyStage::New),
+        ] {
+            let mut card = make_card(Uuid::new_v4(), owner_id);
+            card.state.stage = stage;
+            card.state.ease_factor = 2.36;
+            card.state.due = today + chrono::Duration::days(offset);
+            store.upsert_card(card);
+        }
+        let mut opening = make_card(Uuid::new_v4(), owner_id);
+        opening.state.stage = StudyStage::New;
+        opening.kind = CardKind::Opening(crate::SchedulerOpeningCard::new("e4"));
+        let opening_id = opening.id;
+        store.upsert_card(opening);
+        store.upsert_card(make_card(Uuid::new_v4(), other_owner));
+        let unlock = UnlockRecord {
+            owner_id,
+            detail: SchedulerUnlockDetail::new(opening_id, Some("e4".into()))
+                .with_reason(crate::UnlockReason::DailyQuota),
+            unlocked_on: today,
+        };
+        store
+            .record_unlock(unlock.clone())
+            .expect("unlock recorded");
+
+        let json = store.to_json().expect("store serializes");
+        let mut restored = InMemoryStore::from_json(&json).expect("store deserializes");
+
+        for owner in [owner_id, other_owner] {
+            assert_eq!(
+                restored.due_cards(owner, today),
+                store.due_cards(owner, today)
+            );
+            assert_eq!(
+                restored.unlock_candidates(owner),
+                store.unlock_candidates(owner)
+            );
+            assert_eq!(
+                restored.unlocked_on(owner, today),
+                store.unlocked_on(owner, today)
+            );
+        }
+        assert_eq!(restored.cards, store.cards);
+        assert!(matches!(
+            restored.record_unlock(unlock),
+            Err(SchedulerError::DuplicateUnlock { .. })
+        ));
+        assert!(InMemoryStore::from_json("{\"cards\": 3}").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn checkpoints_without_unlock_reasons_default_to_manual() {
+        let owner_id = Uuid::new_v4();
+        let today = NaiveDate::from_ymd_opt(2024, 3, 10).expect("valid date");
+        let mut store = InMemoryStore::new();
+        let mut opening = make_card(Uuid::new_v4(), owner_id);
+        opening.state.stage = StudyStage::New;
+        opening.kind = CardKind::Opening(crate::SchedulerOpeningCard::new("e4"));
+        let opening_id = opening.id;
+        store.upsert_card(opening);
+        store
+            .record_unlock(make_unlock_record(owner_id, opening_id, today))
+            .expect("unlock recorded");
+
+        let mut checkpoint: serde_json::Value =
+            serde_json::from_str(&store.to_json().expect("store serializes")).expect("valid json");
+        let detail = checkpoint["unlock_log"][0]["detail"]
+            .as_object_mut()
+            .expect("unlock detail object");
+        assert!(detail.remove("reason").is_some());
+
+        let restored =
+            InMemoryStore::from_json(&checkpoint.to_string()).expect("old checkpoint loads");
+        let unlocks = restored.unlocked_on(owner_id, today);
+        assert_eq!(unlocks.len(), 1);
+        assert_eq!(unlocks[0].detail.card_id, opening_id);
+        assert_eq!(unlocks[0].detail.reason, crate::UnlockReason::Manual);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json_round_trip_keeps_review_history_keys_and_activity() {
+        let owner_id = Uuid::new_v4();
+        let today = NaiveDate::from_ymd_opt(2024, 3, 10).expect("valid date");
+        let card = make_card(Uuid::new_v4(), owner_id);
+        let mut store = InMemoryStore::from_cards([card.clone()]);
+        let entry = ReviewHistoryEntry {
+            card_id: card.id,
+            reviewed_on: today,
+            grade: review_domain::ReviewGrade::Good,
+        };
+        let outcome = ReviewOutcome {
+            card: card.clone(),
+            previous_due: today,
+            grade: review_domain::ReviewGrade::Good,
+            new_interval_days: 1,
+            ease_delta: 0.0,
+        };
+        store.record_review(owner_id, entry);
+        store.record_applied_review(card.id, "attempt-1", outcome.clone());
+        store.record_activity(owner_id, today);
+
+        let mut restored = InMemoryStore::from_json(&store.to_json().expect("store serializes"))
+            .expect("store deserializes");
+        assert_eq!(restored.review_history(owner_id), vec![entry]);
+        assert_eq!(restored.applied_review(card.id, "attempt-1"), Some(outcome));
+        assert_eq!(restored.applied_review(card.id, "attempt-2"), None);
+        assert!(restored.active_days(owner_id).contains(&today));
+
+        restored.clear();
+        assert!(restored.review_history(owner_id).is_empty());
+        assert!(restored.active_days(owner_id).is_empty());
+        assert_eq!(restored.applied_review(card.id, "attempt-1"), None);
+        assert_eq!(restored.get_card(card.id), None);
+    }
+
+    #[test]
+    fn cards_introduced_on_counts_distinct_cards_per_owner_and_day() {
+        let mut store = InMemoryStore::new();
+        let owner_id = Uuid::new_v4();
+        let card_id = Uuid::new_v4();
+        let day = NaiveDate::from_ymd_opt(2024, 3, 10).expect("valid date");
+        let next_day = day.succ_opt().expect("valid date");
+
+        store.record_introduction(owner_id, card_id, day);
+        store.record_introduction(owner_id, card_id, day);
+        store.record_introduction(owner_id, Uuid::new_v4(), day);
+        store.record_introduction(owner_id, Uuid::new_v4(), next_day);
+
+        assert_eq!(store.cards_introduced_on(owner_id, day), 2);
+        assert_eq!(store.cards_introduced_on(owner_id, next_day), 1);
+        assert_eq!(store.cards_introduced_on(Uuid::new_v4(), day), 0);
+    }
+
+    #[test]
+    fn every_read_is_scoped_to_the_queried_owner() {
+        let mut store = InMemoryStore::new();
+        let owners = [Uuid::new_v4(), Uuid::new_v4()];
+        let shared_card_id = Uuid::new_v4();
+        let day = NaiveDate::from_ymd_opt(2024, 3, 10).expect("valid date");
+        let mut owned = Vec::new();
+        for owner_id in owners {
+            let due = make_card(Uuid::new_v4(), owner_id);
+            let mut fresh = make_card(Uuid::new_v4(), owner_id);
+            fresh.state.stage = StudyStage::New;
+            store.extend([due.clone(), fresh.clone()]);
+            let unlock = make_unlock_record(owner_id, shared_card_id, day);
+            store
+                .record_unlock(unlock.clone())
+                .expect("unlock recorded");
+            store.record_introduction(owner_id, shared_card_id, day);
+            owned.push((owner_id, due, fresh, unlock));
+        }
+
+        for (owner_id, due, fresh, unlock) in owned {
+            let today = due.state.due;
+            assert_eq!(store.due_cards(owner_id, today), vec![due.clone()]);
+            assert_eq!(
+                store.cards_due_between(owner_id, today, today),
+                vec![due.clone()]
+            );
+            assert_eq!(store.unlock_candidates(owner_id), vec![fresh.clone()]);
+            assert_eq!(store.unlocked_on(owner_id, day), vec![unlock]);
+            assert_eq!(store.cards_introduced_on(owner_id, day), 1);
+            let mut all = vec![due, fresh];
+            all.sort_by_key(|card| (card.state.due, card.id));
+            assert_eq!(store.cards_for_owner(owner_id), all);
+        }
+        let mut expected_owners = owners.to_vec();
+        expected_owners.sort();
+        assert_eq!(store.owners(), expected_owners);
+        let stranger = Uuid::new_v4();
+        assert!(store.due_cards(stranger, NaiveDate::MAX).is_empty());
+        assert!(store.unlock_candidates(stranger).is_empty());
+        assert!(store.cards_for_owner(stranger).is_empty());
+        assert!(store.unlocked_on(stranger, day).is_empty());
+    }
+
     #[test]
     fn test_new_store_is_empty() {
         let store = InMemoryStore::new();
+        assert!(store.owners().is_empty());
         assert!(store.cards.is_empty());
         assert!(store.unlock_log.is_empty());
     }
@@ -126,9 +464,38 @@ mod tests {
         assert_eq!(store.get_card(card_id), Some(updated_card));
     }
 
+    #[test]
+    fn test_from_cards_seeds_every_card() {
+        let owner_id = Uuid::new_v4();
+        let first = make_card(Uuid::new_v4(), owner_id);
+        let second = make_card(Uuid::new_v4(), owner_id);
+
+        let store = InMemoryStore::from_cards([first.clone(), second.clone()]);
+
+        assert_eq!(store.cards.len(), 2);
+        assert_eq!(store.get_card(first.id), Some(first));
+        assert_eq!(store.get_card(second.id), Some(second));
+        assert!(store.unlock_log.is_empty());
+    }
+
+    #[test]
+    fn test_extend_upserts_and_chains() {
+        let owner_id = Uuid::new_v4();
+        let card = make_card(Uuid::new_v4(), owner_id);
+        let mut updated = card.clone();
+        updated.state.stage = StudyStage::Review;
+        let other = make_card(Uuid::new_v4(), owner_id);
+
+        let mut store = InMemoryStore::from_cards([card]);
+        store.extend([updated.clone()]).extend(vec![other.clone()]);
+
+        assert_eq!(store.cards.len(), 2);
+        assert_eq!(store.get_card(updated.id), Some(updated));
+        assert_eq!(store.get_card(other.id), Some(other));
+    }
+
     #[test]
     fn test_due_cards_filters_and_sorts() {
-        let mut store = InMemoryStore::new();
         let owner_id = Uuid::new_v4();
         let other_owner = Uuid::new_v4();
         let today = NaiveDate::from_ymd_opt(2024, 6, 10).unwrap();
@@ -144,22 +511,55 @@ mod tests {
         let mut card_due_early = make_card(Uuid::new_v4(), owner_id);
         card_due_early.state.due = today.pred_opt().unwrap();
 
-        store.upsert_card(card_due.clone());
-        store.upsert_card(card_future);
-        store.upsert_card(card_new);
-        store.upsert_card(card_other_owner);
-        store.upsert_card(card_due_early.clone());
+        let store = InMemoryStore::from_cards([
+            card_due.clone(),
+            card_future,
+            card_new,
+            card_other_owner,
+            card_due_early.clone(),
+        ]);
 
         let mut due = store.due_cards(owner_id, today);
         let mut expected = vec![card_due_early, card_due];
-        due.sort_by(|a, b| (a.state.due, a.id).cmp(&(b.state.due, b.id)));
-        expected.sort_by(|a, b| (a.state.due, a.id).cmp(&(b.state.due, b.id)));
+        due.sort_by_key(|card| (card.state.due, card.id));
+        expected.sort_by_key(|card| (card.state.due, card.id));
         assert_eq!(due, expected);
     }
 
+    #[test]
+    fn test_cards_due_between_is_inclusive_and_sorted() {
+        let owner_id = Uuid::new_v4();
+        let day = |d| NaiveDate::from_ymd_opt(2024, 6, d).unwrap();
+        let cards: Vec<Card> = [9, 12, 10, 13, 11]
+            .into_iter()
+            .map(|d| {
+                let mut card = make_card(Uuid::new_v4(), owner_id);
+                card.state.due = day(d);
+                card
+            })
+            .collect();
+        let mut new_card = make_card(Uuid::new_v4(), owner_id);
+        new_card.state.stage = StudyStage::New;
+        new_card.state.due = day(11);
+        let mut other_owner = make_card(Uuid::new_v4(), Uuid::new_v4());
+        other_owner.state.due = day(11);
+        let store = InMemoryStore::from_cards(cards.into_iter().chain([new_card, other_owner]));
+
+        let dues: Vec<NaiveDate> = store
+            .cards_due_between(owner_id, day(10), day(12))
+            .iter()
+            .map(|card| card.state.due)
+            .collect();
+        assert_eq!(dues, vec![day(10), day(11), day(12)]);
+        assert!(
+            store
+                .cards_due_between(owner_id, day(12), day(10))
+                .is_empty()
+        );
+    }
+
     #[test]
     fn test_unlock_candidates_filters_and_sorts() {
-        let mut store = InMemoryStore::new();
         let owner_id = Uuid::new_v4();
         let other_owner = Uuid::new_v4();
 
@@ -172,10 +572,12 @@ mod tests {
         let mut card_new_other = make_card(Uuid::new_v4(), other_owner);
         card_new_other.state.stage = StudyStage::New;
 
-        store.upsert_card(card_new1.clone());
-        store.upsert_card(card_new2.clone());
-        store.upsert_card(card_learning);
-        store.upsert_card(card_new_other);
+        let store = InMemoryStore::from_cards([
+            card_new1.clone(),
+            card_new2.clone(),
+            card_learning,
+            card_new_other,
+        ]);
 
         let mut expected = vec![card_new1, card_new2];
         expected.sort_by(candidate_ordering);
@@ -199,10 +601,14 @@ mod tests {
         let record3 = make_unlock_record(owner_id, card_id1, other_day);
         let record4 = make_unlock_record(other_owner, card_id1, day);
 
-        store.record_unlock(record1.clone());
-        store.record_unlock(record2.clone());
-        store.record_unlock(record3);
-        store.record_unlock(record4);
+        store
+            .record_unlock(record1.clone())
+            .expect("unlock recorded");
+        store
+            .record_unlock(record2.clone())
+            .expect("unlock recorded");
+        store.record_unlock(record3).expect("unlock recorded");
+        store.record_unlock(record4).expect("unlock recorded");
 
         let unlocked = store.unlocked_on(owner_id, day);
         assert_eq!(unlocked, vec![record1, record2]);
@@ -283,9 +689,53 @@ mod tests {
         let card_id = Uuid::new_v4();
         let day = NaiveDate::from_ymd_opt(2024, 6, 10).unwrap();
         let record = make_unlock_record(owner_id, card_id, day);
-        store.record_unlock(record.clone());
+        store
+            .record_unlock(record.clone())
+            .expect("unlock recorded");
+        assert_eq!(store.unlocked_on(owner_id, day), vec![record]);
+    }
+
+    #[test]
+    fn record_unlock_rejects_same_owner_card_and_day() {
+        let mut store = InMemoryStore::new();
+        let owner_id = Uuid::new_v4();
+        let card_id = Uuid::new_v4();
+        let day = NaiveDate::from_ymd_opt(2024, 6, 10).unwrap();
+        let record = make_unlock_record(owner_id, card_id, day);
+        store.record_unlock(record.clone()).expect("first unlock");
+
+        let err = store
+            .record_unlock(record.clone())
+            .expect_err("duplicate rejected");
+        assert!(matches!(
+            err,
+            SchedulerError::DuplicateUnlock { owner_id: o, card_id: c, day: d }
+                if o == owner_id && c == card_id && d == day
+        ));
         assert_eq!(store.unlocked_on(owner_id, day), vec![record]);
     }
+
+    #[test]
+    fn record_unlocks_stops_at_first_duplicate() {
+        let mut store = InMemoryStore::new();
+        let owner_id = Uuid::new_v4();
+        let day = NaiveDate::from_ymd_opt(2024, 6, 10).unwrap();
+        let first = make_unlock_record(owner_id, Uuid::new_v4(), day);
+        let second = make_unlock_record(owner_id, Uuid::new_v4(), day);
+        let third = make_unlock_record(owner_id, Uuid::new_v4(), day);
+
+        store
+            .record_unlocks(vec![first.clone(), second.clone()])
+            .expect("distinct unlocks recorded");
+        let result = store.record_unlocks(vec![third.clone(), first.clone(), second.clone()]);
+
+        assert!(matches!(
+            result,
+            Err(SchedulerError::DuplicateUnlock { .. })
+        ));
+        assert_eq!(store.unlocked_on(owner_id, day), vec![first, second, third]);
+    }
+
     //     /// Record a newly unlocked card.
     //     fn record_unlock(&mut self, record: UnlockRecord);
 
@@ -296,7 +746,9 @@ mod tests {
         let card_id = Uuid::new_v4();
         let day = NaiveDate::from_ymd_opt(2024, 6, 10).unwrap();
         let record = make_unlock_record(owner_id, card_id, day);
-        store.record_unlock(record.clone());
+        store
+            .record_unlock(record.clone())
+            .expect("unlock recorded");
         assert_eq!(store.unlocked_on(owner_id, day), vec![record]);
     }
     //     /// Retrieve unlock events that occurred on the provided day.
@@ -310,7 +762,6 @@ mod tests {
     // })
     #[test]
     fn test_correctly_filters_cards_when_more_than_one_owner() {
-        let mut store = InMemoryStore::new();
         let owner1 = Uuid::new_v4();
         let owner2 = Uuid::new_v4();
         let today = NaiveDate::from_ymd_opt(2024, 6, 10).unwrap();
@@ -318,8 +769,7 @@ mod tests {
         let card1 = make_card(Uuid::new_v4(), owner1);
         let card2 = make_card(Uuid::new_v4(), owner2);
 
-        store.upsert_card(card1.clone());
-        store.upsert_card(card2.clone());
+        let store = InMemoryStore::from_cards([card1.clone(), card2.clone()]);
 
         assert_eq!(store.due_cards(owner1, today), vec![card1]);
         assert_eq!(store.due_cards(owner2, today), vec![card2]);
@@ -327,7 +777,6 @@ mod tests {
 
     #[test]
     fn test_correctly_filters_cards_when_dates_before_after_and_equal_to_today() {
-        let mut store = InMemoryStore::new();
         let owner_id = Uuid::new_v4();
         let today = NaiveDate::from_ymd_opt(2024, 6, 10).unwrap();
 
@@ -337,20 +786,17 @@ mod tests {
         let mut card_past = make_card(Uuid::new_v4(), owner_id);
         card_past.state.due = today.pred_opt().unwrap();
 
-        store.upsert_card(card_due.clone());
-        store.upsert_card(card_future);
-        store.upsert_card(card_past.clone());
+        let store = InMemoryStore::from_cards([card_due.clone(), card_future, card_past.clone()]);
 
         let mut actual = store.due_cards(owner_id, today);
         let mut expected = vec![card_past, card_due];
-        actual.sort_by(|a, b| (a.state.due, a.id).cmp(&(b.state.due, b.id)));
-        expected.sort_by(|a, b| (a.state.due, a.id).cmp(&(b.state.due, b.id)));
+        actual.sort_by_key(|card| (card.state.due, card.id));
+        expected.sort_by_key(|card| (card.state.due, card.id));
         assert_eq!(actual, expected);
     }
 
     #[test]
     fn test_correctly_filters_cards_when_new_and_not_new() {
-        let mut store = InMemoryStore::new();
         let owner_id = Uuid::new_v4();
         let today = NaiveDate::from_ymd_opt(2024, 6, 10).unwrap();
 
@@ -359,13 +805,12 @@ mod tests {
         let mut card_learning = make_card(Uuid::new_v4(), owner_id);
         card_learning.state.stage = StudyStage::Learning;
 
-        store.upsert_card(card_new);
-        store.upsert_card(card_learning.clone());
+        let store = InMemoryStore::from_cards([card_new, card_learning.clone()]);
 
         let mut actual = store.due_cards(owner_id, today);
         let mut expected = vec![card_learning];
-        actual.sort_by(|a, b| (a.state.due, a.id).cmp(&(b.state.due, b.id)));
-        expected.sort_by(|a, b| (a.state.due, a.id).cmp(&(b.state.due, b.id)));
+        actual.sort_by_key(|card| (card.state.due, card.id));
+        expected.sort_by_key(|card| (card.state.due, card.id));
         assert_eq!(actual, expected);
     }
 }
@@ -71,13 +71,12 @@ mod tests {
         let owner = Uuid::new_v4();
         let record = UnlockRecord {
             owner_id: owner,
-            detail: SchedulerUnlockDetail {
-                card_id: Uuid::new_v4(),
-                parent_prefix: Some("e4".into()),
-            },
+            detail: SchedulerUnlockDetail::new(Uuid::new_v4(), Some("e4".into())),
             unlocked_on: naive_date(2023, 1, 1),
         };
-        store.record_unlock(record.clone());
+        store
+            .record_unlock(record.clone())
+            .expect("unlock recorded");
         let logs = store.unlocked_on(owner, naive_date(2023, 1, 1));
         assert_eq!(logs, vec![record]);
     }
@@ -139,6 +138,41 @@ mod tests {
         assert_eq!(tactic_ids, expected_ids);
     }
 
+    #[test]
+    fn unlock_candidates_break_prefix_ties_by_id() {
+        let mut store = InMemoryStore::new();
+        let owner = Uuid::new_v4();
+        let config = SchedulerConfig::default();
+        let opening_with_id = |id: u128| {
+            let mut card = new_card(
+                owner,
+                CardKind::Opening(SchedulerOpeningCard::new("e4")),
+                naive_date(2023, 1, 1),
+                &config,
+            );
+            card.id = Uuid::from_u128(id);
+            card
+        };
+        for id in [3, 1, 2] {
+            store.upsert_card(opening_with_id(id));
+        }
+
+        let ids: Vec<_> = store
+            .unlock_candidates(owner)
+            .iter()
+            .map(|card| card.id)
+            .collect();
+
+        assert_eq!(
+            ids,
+            vec![Uuid::from_u128(1), Uuid::from_u128(2), Uuid::from_u128(3)]
+        );
+        assert_eq!(
+            super::candidate_ordering(&opening_with_id(2), &opening_with_id(1)),
+            Ordering::Greater
+        );
+    }
+
     #[test]
     fn candidate_ordering_handles_mixed_kinds() {
         let owner = Uuid::new_v4();
@@ -1,5 +1,8 @@
-use crate::domain::{Card, UnlockRecord};
+use crate::domain::{Card, ReviewOutcome, UnlockRecord};
+use crate::errors::SchedulerError;
+use crate::snapshot::ReviewHistoryEntry;
 use chrono::NaiveDate;
+use std::collections::BTreeSet;
 use uuid::Uuid;
 
 /// Storage abstraction required by the scheduler to retrieve and persist cards.
@@ -8,14 +11,107 @@ pub trait SchedulerStore {
     fn get_card(&self, id: Uuid) -> Option<Card>;
     /// Insert or update a card in the backing store.
     fn upsert_card(&mut self, card: Card);
-    /// Retrieve cards due for review on the given day.
+    /// Retrieve cards due for review on the given day, leaving out `New` and `Retired` cards.
     fn due_cards(&self, owner_id: Uuid, today: NaiveDate) -> Vec<Card>;
+    /// Retrieve the cards [`due_cards`](Self::due_cards) would return whose due date lies in
+    /// `start..=end`, sorted by due date and id. An empty range yields no cards.
+    ///
+    /// The default filters `due_cards(owner_id, end)`; stores with a date index can override it.
+    fn cards_due_between(&self, owner_id: Uuid, start: NaiveDate, end: NaiveDate) -> Vec<Card> {
+        let mut cards: Vec<Card> = self
+            .due_cards(owner_id, end)
+            .into_iter()
+            .filter(|card| card.state.due >= start)
+            .collect();
+        cards.sort_by_key(|card| (card.state.due, card.id));
+        cards
+    }
+    /// Every card owned by `owner_id` in any stage, `New` and `Retired` included, sorted by
+    /// due date and id.
+    ///
+    /// The default combines `due_cards(owner_id, NaiveDate::MAX)` with
+    /// [`unlock_candidates`](Self::unlock_candidates), so it misses any stage those two leave
+    /// out, such as `Retired`; stores that can list every card should override it.
+    fn cards_for_owner(&self, owner_id: Uuid) -> Vec<Card> {
+        let mut cards = self.due_cards(owner_id, NaiveDate::MAX);
+        cards.extend(self.unlock_candidates(owner_id));
+        cards.sort_by_key(|card| (card.state.due, card.id));
+        cards.dedup_by_key(|card| card.id);
+        cards
+    }
+    /// Distinct ids of every owner holding at least one card, sorted ascending.
+    ///
+    /// Meant for admin tooling that runs per-owner jobs across the whole store. Every other
+    /// query is keyed by owner, so the default has nothing to scan and lists no owners;
+    /// stores that can enumerate their cards should override it.
+    fn owners(&self) -> Vec<Uuid> {
+        Vec::new()
+    }
     /// Fetch cards eligible to be unlocked for future study.
     fn unlock_candidates(&self, owner_id: Uuid) -> Vec<Card>;
     /// Record a newly unlocked card.
-    fn record_unlock(&mut self, record: UnlockRecord);
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SchedulerError::DuplicateUnlock`] when the same owner already unlocked the
+    /// same card on the same day; the log is left unchanged.
+    fn record_unlock(&mut self, record: UnlockRecord) -> Result<(), SchedulerError>;
+    /// Record several unlocks in order through [`record_unlock`](Self::record_unlock).
+    ///
+    /// # Errors
+    ///
+    /// Stops at the first duplicate and returns its [`SchedulerError::DuplicateUnlock`];
+    /// records before it stay recorded and records after it are not attempted.
+    fn record_unlocks(&mut self, records: Vec<UnlockRecord>) -> Result<(), SchedulerError> {
+        records
+            .into_iter()
+            .try_for_each(|record| self.record_unlock(record))
+    }
     /// Retrieve unlock events that occurred on the provided day.
     fn unlocked_on(&self, owner_id: Uuid, day: NaiveDate) -> Vec<UnlockRecord>;
+    /// Record that `card_id` left the `New` stage on `day`, by unlock or by review.
+    ///
+    /// The default ignores the event, so [`cards_introduced_on`](Self::cards_introduced_on)
+    /// falls back to counting unlocks.
+    fn record_introduction(&mut self, _owner_id: Uuid, _card_id: Uuid, _day: NaiveDate) {}
+    /// Count the distinct cards `owner_id` introduced on `day`.
+    ///
+    /// The default counts the [`unlocked_on`](Self::unlocked_on) records.
+    fn cards_introduced_on(&self, owner_id: Uuid, day: NaiveDate) -> usize {
+        self.unlocked_on(owner_id, day).len()
+    }
+    /// Append a graded review to `owner_id`'s history. The default discards it.
+    fn record_review(&mut self, _owner_id: Uuid, _entry: ReviewHistoryEntry) {}
+    /// `owner_id`'s recorded reviews, oldest first. The default has none.
+    fn review_history(&self, _owner_id: Uuid) -> Vec<ReviewHistoryEntry> {
+        Vec::new()
+    }
+    /// Mark `day` as one on which `owner_id` reviewed. The default ignores it, so
+    /// [`active_days`](Self::active_days) falls back to the review history.
+    fn record_activity(&mut self, _owner_id: Uuid, _day: NaiveDate) {}
+    /// Days on which `owner_id` recorded at least one review, used for streaks.
+    ///
+    /// The default collects the days of [`review_history`](Self::review_history).
+    fn active_days(&self, owner_id: Uuid) -> BTreeSet<NaiveDate> {
+        self.review_history(owner_id)
+            .into_iter()
+            .map(|entry| entry.reviewed_on)
+            .collect()
+    }
+    /// Remember the outcome of the review applied to `card_id` under `idempotency_key`, so a
+    /// resubmission can be answered from [`applied_review`](Self::applied_review). The
+    /// default forgets it, which makes keyed reviews behave like unkeyed ones.
+    fn record_applied_review(
+        &mut self,
+        _card_id: Uuid,
+        _idempotency_key: &str,
+        _outcome: ReviewOutcome,
+    ) {
+    }
+    /// The outcome recorded for `idempotency_key` on `card_id`, if that key was applied.
+    fn applied_review(&self, _card_id: Uuid, _idempotency_key: &str) -> Option<ReviewOutcome> {
+        None
+    }
 }
 
 #[cfg(test)]
@@ -65,10 +161,7 @@ mod tests {
             UnlockRecord {
                 owner_id: dr.owner_id,
                 unlocked_on: dr.day,
-                detail: crate::domain::SchedulerUnlockDetail {
-                    card_id: dr.card_id,
-                    parent_prefix: None,
-                },
+                detail: crate::domain::SchedulerUnlockDetail::new(dr.card_id, None),
             }
         }
     }
@@ -113,8 +206,9 @@ mod tests {
                 .collect()
         }
 
-        fn record_unlock(&mut self, record: UnlockRecord) {
+        fn record_unlock(&mut self, record: UnlockRecord) -> Result<(), SchedulerError> {
             self.unlocks.push(record);
+            Ok(())
         }
 
         fn unlocked_on(&self, owner_id: Uuid, day: NaiveDate) -> Vec<UnlockRecord> {
@@ -185,6 +279,23 @@ mod tests {
         assert!(!due.contains(&card4));
     }
 
+    #[test]
+    fn default_cards_for_owner_merges_due_and_candidate_cards_once() {
+        let mut store = InMemorySchedulerStore::new();
+        let owner = Uuid::new_v4();
+        let later = make_card(owner, NaiveDate::from_ymd_opt(2024, 6, 2).unwrap());
+        let earlier = make_card(owner, NaiveDate::from_ymd_opt(2024, 6, 1).unwrap());
+        for card in [&later, &earlier] {
+            store.upsert_card(card.clone());
+        }
+        store.upsert_card(make_card(
+            Uuid::new_v4(),
+            NaiveDate::from_ymd_opt(2024, 6, 1).unwrap(),
+        ));
+
+        assert_eq!(store.cards_for_owner(owner), vec![earlier, later]);
+    }
+
     #[test]
     fn test_unlock_candidates_returns_all_for_owner() {
         let mut store = InMemorySchedulerStore::new();
@@ -218,9 +329,15 @@ mod tests {
         let unlock2 = make_unlock(card_id2, owner_id, day1);
         let unlock3 = make_unlock(card_id1, owner_id, day2);
 
-        store.record_unlock(unlock1.clone());
-        store.record_unlock(unlock2.clone());
-        store.record_unlock(unlock3.clone());
+        store
+            .record_unlock(unlock1.clone())
+            .expect("unlock recorded");
+        store
+            .record_unlock(unlock2.clone())
+            .expect("unlock recorded");
+        store
+            .record_unlock(unlock3.clone())
+            .expect("unlock recorded");
 
         let unlocked_day1 = store.unlocked_on(owner_id, day1);
         assert_eq!(unlocked_day1.len(), 2);
@@ -243,8 +360,12 @@ mod tests {
         let unlock1 = make_unlock(card_id, owner1, day);
         let unlock2 = make_unlock(card_id, owner2, day);
 
-        store.record_unlock(unlock1.clone());
-        store.record_unlock(unlock2.clone());
+        store
+            .record_unlock(unlock1.clone())
+            .expect("unlock recorded");
+        store
+            .record_unlock(unlock2.clone())
+            .expect("unlock recorded");
 
         let unlocked_owner1 = store.unlocked_on(owner1, day);
         assert_eq!(unlocked_owner1, vec![unlock1]);
@@ -252,6 +373,32 @@ mod tests {
         assert_eq!(unlocked_owner2, vec![unlock2]);
     }
 
+    #[test]
+    fn test_cards_introduced_on_defaults_to_unlock_count() {
+        let mut store = InMemorySchedulerStore::new();
+        let owner_id = Uuid::new_v4();
+        let day = NaiveDate::from_ymd_opt(2024, 6, 10).unwrap();
+
+        store
+            .record_unlock(make_unlock(Uuid::new_v4(), owner_id, day))
+            .expect("unlock recorded");
+        store.record_introduction(owner_id, Uuid::new_v4(), day);
+
+        assert_eq!(store.cards_introduced_on(owner_id, day), 1);
+        assert_eq!(store.cards_introduced_on(Uuid::new_v4(), day), 0);
+    }
+
+    #[test]
+    fn default_owners_lists_nobody_even_with_cards() {
+        let mut store = InMemorySchedulerStore::new();
+        store.upsert_card(make_card(
+            Uuid::new_v4(),
+            NaiveDate::from_ymd_opt(2024, 6, 10).unwrap(),
+        ));
+
+        assert!(store.owners().is_empty());
+    }
+
     #[test]
     fn test_edge_cases_empty_store() {
         let store = InMemorySchedulerStore::new();
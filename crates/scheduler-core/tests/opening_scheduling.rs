@@ -1,8 +1,8 @@
 use chrono::NaiveDate;
 use scheduler_core::domain::SchedulerOpeningCard;
 use scheduler_core::{
-    Card, CardKind, CardState, ReviewGrade, Scheduler, SchedulerConfig, SchedulerStore,
-    UnlockRecord, build_queue_for_day, new_card,
+    Card, CardKind, CardState, QueueSelection, ReviewGrade, Scheduler, SchedulerConfig,
+    SchedulerError, SchedulerStore, UnlockRecord, build_queue_for_day, new_card,
 };
 use std::collections::{BTreeMap, BTreeSet};
 use uuid::Uuid;
@@ -59,7 +59,7 @@ impl SchedulerStore for TimedStore {
             })
             .cloned()
             .collect();
-        due.sort_by(|a, b| (a.state.due, a.id).cmp(&(b.state.due, b.id)));
+        due.sort_by_key(|card| (card.state.due, card.id));
         due
     }
 
@@ -86,8 +86,9 @@ impl SchedulerStore for TimedStore {
         candidates
     }
 
-    fn record_unlock(&mut self, record: UnlockRecord) {
+    fn record_unlock(&mut self, record: UnlockRecord) -> Result<(), SchedulerError> {
         self.unlock_log.push(record);
+        Ok(())
     }
 
     fn unlocked_on(&self, owner_id: Uuid, day: NaiveDate) -> Vec<UnlockRecord> {
@@ -122,7 +123,7 @@ fn shared_first_move_is_unlocked_only_once() {
     store.insert_with_availability(first_line.clone(), day1);
     store.insert_with_availability(alternate_line.clone(), day1);
 
-    let queue = build_queue_for_day(&mut store, &config, owner, day1);
+    let queue = build_queue_for_day(&mut store, &config, owner, day1, QueueSelection::All);
 
     assert_eq!(queue.len(), 1, "only one opening should unlock per prefix");
     let unlocked = queue.first().expect("queue should contain a card");
@@ -168,7 +169,7 @@ fn responses_unlock_after_first_move_review() {
     store.insert_with_availability(scandinavian.clone(), day2);
     store.insert_with_availability(open_game.clone(), day2);
 
-    let day_one_queue = build_queue_for_day(&mut store, &config, owner, day1);
+    let day_one_queue = build_queue_for_day(&mut store, &config, owner, day1, QueueSelection::All);
     assert_eq!(day_one_queue.len(), 1);
     assert_eq!(day_one_queue[0].id, first_move_id);
     assert_eq!(day_one_queue[0].state.stage, CardState::Learning);
@@ -180,7 +181,7 @@ fn responses_unlock_after_first_move_review() {
     let mut store = scheduler.into_store();
 
     store.set_day(day2);
-    let day_two_queue = build_queue_for_day(&mut store, &config, owner, day2);
+    let day_two_queue = build_queue_for_day(&mut store, &config, owner, day2, QueueSelection::All);
 
     assert_eq!(
         day_two_queue.len(),
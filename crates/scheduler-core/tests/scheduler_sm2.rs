@@ -2,8 +2,8 @@ use chrono::NaiveDate;
 use scheduler_core::SchedulerStore;
 use scheduler_core::domain::{SchedulerOpeningCard, SchedulerTacticCard};
 use scheduler_core::{
-    CardKind, CardState, InMemoryStore, ReviewGrade, Scheduler, SchedulerConfig,
-    build_queue_for_day, new_card,
+    CardKind, CardState, EaseBounds, InMemoryStore, QueueSelection, ReviewGrade, Scheduler,
+    SchedulerConfig, build_queue_for_day, new_card,
 };
 use uuid::Uuid;
 
@@ -83,7 +83,10 @@ fn sm2_good_review_promotes_new_card() {
 fn sm2_again_resets_interval_and_ease() {
     let mut store = InMemoryStore::new();
     let config = SchedulerConfig {
-        ease_minimum: 1.5,
+        ease_bounds: EaseBounds {
+            minimum: 1.5,
+            ..EaseBounds::default()
+        },
         ..SchedulerConfig::default()
     };
     let owner = Uuid::new_v4();
@@ -115,7 +118,7 @@ fn sm2_again_resets_interval_and_ease() {
         today.succ_opt().expect("successor date should exist")
     );
     assert_eq!(outcome.card.state.lapses, 1);
-    assert!(outcome.card.state.ease_factor >= config.ease_minimum);
+    assert!(outcome.card.state.ease_factor >= config.ease_bounds.minimum);
     assert!(outcome.card.state.ease_factor < 2.4);
 }
 
@@ -182,7 +185,7 @@ fn unlocks_one_opening_per_prefix_per_day() {
     assert_ne!(prefixes[0], prefixes[1], "prefixes must be unique");
 
     let mut store = scheduler.into_store();
-    let second_queue = build_queue_for_day(&mut store, &config, owner, today);
+    let second_queue = build_queue_for_day(&mut store, &config, owner, today, QueueSelection::All);
     assert_eq!(second_queue.len(), 3, "no additional unlocks on same day");
 }
 
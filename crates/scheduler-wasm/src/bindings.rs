@@ -1,7 +1,8 @@
 use std::convert::TryFrom;
+use std::fmt::Display;
 
 use chrono::NaiveDate;
-use scheduler_core::SchedulerConfig;
+use scheduler_core::{ReviewGrade, SchedulerConfig};
 use serde_wasm_bindgen::{from_value, to_value};
 use uuid::Uuid;
 use wasm_bindgen::prelude::*;
@@ -18,16 +19,16 @@ pub struct WasmScheduler {
 #[wasm_bindgen]
 impl WasmScheduler {
     /// Constructs a scheduler using the supplied configuration override.
+    ///
+    /// Passing `undefined` or `null` uses the default configuration.
     #[wasm_bindgen(constructor)]
-    pub fn new(config: Option<JsValue>) -> Result<WasmScheduler, JsValue> {
-        let config = match config {
-            Some(value) => {
-                let patch: SchedulerConfigPatch =
-                    from_value(value).map_err(|err| JsValue::from_str(&err.to_string()))?;
-                Ok(patch.apply(SchedulerConfig::default()))
-            }
-            None => Ok(SchedulerConfig::default()),
-        }?;
+    pub fn new(config: JsValue) -> Result<WasmScheduler, JsValue> {
+        let config = if config.is_undefined() || config.is_null() {
+            SchedulerConfig::default()
+        } else {
+            let patch: SchedulerConfigPatch = from_value(config).map_err(js_error)?;
+            patch.apply(SchedulerConfig::default())
+        };
         Ok(Self {
             facade: SchedulerFacade::new(config),
         })
@@ -36,8 +37,7 @@ impl WasmScheduler {
     /// Returns the active scheduler configuration.
     #[wasm_bindgen(js_name = "currentConfig")]
     pub fn current_config(&self) -> Result<JsValue, JsValue> {
-        to_value(&SchedulerConfigDto::from(self.facade.config()))
-            .map_err(|err| JsValue::from_str(&err.to_string()))
+        to_value(&SchedulerConfigDto::from(self.facade.config())).map_err(js_error)
     }
 
     /// Builds the queue for the provided owner and reports the number of cards.
@@ -46,15 +46,41 @@ impl WasmScheduler {
         let owner_id = parse_owner_id(owner_id)?;
         let today = parse_iso_date(iso_date)?;
         let length = self.facade.queue_length(owner_id, today);
-        u32::try_from(length).map_err(|_| JsValue::from_str("queue length exceeds u32"))
+        u32::try_from(length).map_err(|_| js_error("queue length exceeds u32"))
+    }
+
+    /// Grades a card and returns its next due date as an ISO string.
+    ///
+    /// Grades use the 0-4 scale: 0 and 1 map to `Again`, 2 to `Hard`, 3 to `Good`, and
+    /// 4 to `Easy`. Invalid input and scheduler failures throw a JavaScript `Error`.
+    #[wasm_bindgen(js_name = "reviewCard")]
+    pub fn review_card(
+        &mut self,
+        card_id: &str,
+        grade: u8,
+        iso_date: &str,
+    ) -> Result<String, JsValue> {
+        let card_id = parse_card_id(card_id)?;
+        let grade = parse_grade(grade)?;
+        let today = parse_iso_date(iso_date)?;
+        let outcome = self
+            .facade
+            .review(card_id, grade, today)
+            .map_err(js_error)?;
+        Ok(outcome.card.state.due.format("%Y-%m-%d").to_string())
     }
 }
 
 /// Provides the default scheduler configuration for bootstrapping the wasm module.
 #[wasm_bindgen(js_name = "defaultConfig")]
 pub fn default_config() -> Result<JsValue, JsValue> {
-    to_value(&SchedulerConfigDto::from(&SchedulerConfig::default()))
-        .map_err(|err| JsValue::from_str(&err.to_string()))
+    to_value(&SchedulerConfigDto::from(&SchedulerConfig::default())).map_err(js_error)
+}
+
+/// Installs the console panic hook when the module is instantiated.
+#[wasm_bindgen(start)]
+pub fn start() {
+    init_panic_hook();
 }
 
 /// Installs the console panic hook so Rust panics surface in the developer console.
@@ -63,11 +89,25 @@ pub fn init_panic_hook() {
     console_error_panic_hook::set_once();
 }
 
+/// Wraps a displayable error in a JavaScript `Error` so callers can `catch` it.
+fn js_error(err: impl Display) -> JsValue {
+    js_sys::Error::new(&err.to_string()).into()
+}
+
 fn parse_owner_id(value: &str) -> Result<Uuid, JsValue> {
-    Uuid::parse_str(value).map_err(|err| JsValue::from_str(&format!("invalid owner id: {err}")))
+    Uuid::parse_str(value).map_err(|err| js_error(format!("invalid owner id: {err}")))
+}
+
+fn parse_card_id(value: &str) -> Result<Uuid, JsValue> {
+    Uuid::parse_str(value).map_err(|err| js_error(format!("invalid card id: {err}")))
 }
 
 fn parse_iso_date(value: &str) -> Result<NaiveDate, JsValue> {
     NaiveDate::parse_from_str(value, "%Y-%m-%d")
-        .map_err(|err| JsValue::from_str(&format!("invalid ISO date: {err}")))
+        .map_err(|err| js_error(format!("invalid ISO date: {err}")))
+}
+
+fn parse_grade(value: u8) -> Result<ReviewGrade, JsValue> {
+    ReviewGrade::from_u8(value)
+        .map_err(|_| js_error(format!("invalid grade: {value} (expected 0-4)")))
 }
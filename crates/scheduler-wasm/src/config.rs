@@ -14,8 +14,8 @@ impl From<&SchedulerConfig> for SchedulerConfigDto {
     fn from(config: &SchedulerConfig) -> Self {
         Self {
             initial_ease_factor: config.initial_ease_factor,
-            ease_minimum: config.ease_minimum,
-            ease_maximum: config.ease_maximum,
+            ease_minimum: config.ease_bounds.minimum,
+            ease_maximum: config.ease_bounds.maximum,
             learning_steps_minutes: config.learning_steps_minutes.clone(),
         }
     }
@@ -39,10 +39,10 @@ impl SchedulerConfigPatch {
             base.initial_ease_factor = initial_ease_factor;
         }
         if let Some(ease_minimum) = self.ease_minimum {
-            base.ease_minimum = ease_minimum;
+            base.ease_bounds.minimum = ease_minimum;
         }
         if let Some(ease_maximum) = self.ease_maximum {
-            base.ease_maximum = ease_maximum;
+            base.ease_bounds.maximum = ease_maximum;
         }
         if let Some(learning_steps_minutes) = self.learning_steps_minutes {
             base.learning_steps_minutes = learning_steps_minutes;
@@ -60,9 +60,13 @@ mod tests {
     fn baseline() -> SchedulerConfig {
         SchedulerConfig {
             initial_ease_factor: 2.5,
-            ease_minimum: 1.3,
-            ease_maximum: 2.8,
+            ease_bounds: scheduler_core::EaseBounds::default(),
             learning_steps_minutes: vec![1, 10],
+            queue_layout: scheduler_core::QueueLayout::default(),
+            new_card_due: scheduler_core::NewCardDue::Today,
+            max_new_per_day: None,
+            mastery_interval_days: 180,
+            interval_rounding: scheduler_core::RoundingPolicy::Round,
         }
     }
 
@@ -78,8 +82,8 @@ mod tests {
             dto.initial_ease_factor,
             config.initial_ease_factor
         ));
-        assert!(approx_eq(dto.ease_minimum, config.ease_minimum));
-        assert!(approx_eq(dto.ease_maximum, config.ease_maximum));
+        assert!(approx_eq(dto.ease_minimum, config.ease_bounds.minimum));
+        assert!(approx_eq(dto.ease_maximum, config.ease_bounds.maximum));
         assert_eq!(dto.learning_steps_minutes, config.learning_steps_minutes);
     }
 
@@ -93,8 +97,8 @@ mod tests {
         };
         let patched = patch.apply(baseline());
         assert!(approx_eq(patched.initial_ease_factor, 2.8));
-        assert!(approx_eq(patched.ease_minimum, 1.3));
-        assert!(approx_eq(patched.ease_maximum, 3.0));
+        assert!(approx_eq(patched.ease_bounds.minimum, 1.3));
+        assert!(approx_eq(patched.ease_bounds.maximum, 3.0));
         assert_eq!(patched.learning_steps_minutes, vec![1, 5, 10]);
     }
 }
@@ -1,5 +1,7 @@
 use chrono::NaiveDate;
-use scheduler_core::{InMemoryStore, Scheduler, SchedulerConfig};
+use scheduler_core::{
+    InMemoryStore, ReviewGrade, ReviewOutcome, Scheduler, SchedulerConfig, SchedulerError,
+};
 use uuid::Uuid;
 
 /// Core scheduler wrapper shared between Rust unit tests and the wasm bindings.
@@ -31,6 +33,21 @@ impl SchedulerFacade {
     pub fn queue_length(&mut self, owner_id: Uuid, today: NaiveDate) -> usize {
         self.inner.build_queue(owner_id, today).len()
     }
+
+    /// Applies a review grade to the identified card.
+    ///
+    /// # Errors
+    ///
+    /// Propagates [`SchedulerError`] from the underlying scheduler, for example when the
+    /// card does not exist in the store.
+    pub fn review(
+        &mut self,
+        card_id: Uuid,
+        grade: ReviewGrade,
+        today: NaiveDate,
+    ) -> Result<ReviewOutcome, SchedulerError> {
+        self.inner.review(card_id, grade, today)
+    }
 }
 
 #[cfg(test)]
@@ -48,4 +65,16 @@ mod tests {
         let today = naive_date(2024, 1, 1);
         assert_eq!(facade.queue_length(owner_id, today), 0);
     }
+
+    #[test]
+    fn review_surfaces_scheduler_errors_instead_of_panicking() {
+        let mut facade = SchedulerFacade::new(SchedulerConfig::default());
+        let card_id = Uuid::nil();
+
+        let error = facade
+            .review(card_id, ReviewGrade::Good, naive_date(2024, 1, 1))
+            .expect_err("missing cards should surface an error");
+
+        assert_eq!(error.to_string(), format!("card not found: {card_id}"));
+    }
 }
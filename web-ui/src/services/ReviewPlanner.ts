@@ -35,9 +35,25 @@ export type ReviewOverview = {
   upcomingUnlocks: UpcomingUnlock[];
 };
 
-type Recommendation = ReviewOverview['recommendation'];
+export type Recommendation = ReviewOverview['recommendation'];
 
-type RecommendationContext = {
+/** Stable identifiers for planner recommendations, used as keys for localized strings. */
+export const RecommendationKind = {
+  CatchUpBacklog: 'catch-up-backlog',
+  SplitReviewBlocks: 'split-review-blocks',
+  StabilizeAccuracy: 'stabilize-accuracy',
+  FinishInOneSprint: 'finish-in-one-sprint',
+  RebuildConfidence: 'rebuild-confidence',
+  FinalFocusBlock: 'final-focus-block',
+  ExpandRepertoire: 'expand-repertoire',
+  KeepMomentum: 'keep-momentum',
+} as const;
+
+export type RecommendationKind = (typeof RecommendationKind)[keyof typeof RecommendationKind];
+
+export type RecommendationTemplates = Record<RecommendationKind, Recommendation>;
+
+export type RecommendationContext = {
   backlog: BacklogPressure;
   accuracy: AccuracyRisk;
   streakLength: number;
@@ -48,68 +64,103 @@ type RecommendationRule = {
   backlog?: BacklogPressure;
   accuracy?: AccuracyRisk;
   predicate?: (context: RecommendationContext) => boolean;
-  recommendation: Recommendation;
+  kind: RecommendationKind;
 };
 
 const RECOMMENDATION_RULES: RecommendationRule[] = [
+  { backlog: 'high', kind: RecommendationKind.CatchUpBacklog },
+  { backlog: 'moderate', kind: RecommendationKind.SplitReviewBlocks },
+  { backlog: 'low', accuracy: 'critical', kind: RecommendationKind.StabilizeAccuracy },
+  { backlog: 'low', kind: RecommendationKind.FinishInOneSprint },
+  { accuracy: 'critical', kind: RecommendationKind.RebuildConfidence },
+  { accuracy: 'watch', kind: RecommendationKind.FinalFocusBlock },
   {
-    backlog: 'high',
-    recommendation: {
-      primaryAction: 'Catch up on overdue reviews',
-      secondaryAction: 'Reinforce accuracy with short tactics drills',
-    },
+    predicate: (context) => context.streakLength >= 10 && context.remaining === 0,
+    kind: RecommendationKind.ExpandRepertoire,
   },
-  {
-    backlog: 'moderate',
-    recommendation: {
-      primaryAction: "Work through today's reviews in two focused blocks",
-      secondaryAction: 'Log any mistakes immediately to revisit tomorrow',
-    },
+];
+
+const RECOMMENDATION_FALLBACK: RecommendationKind = RecommendationKind.KeepMomentum;
+
+export const DEFAULT_RECOMMENDATION_TEMPLATES: RecommendationTemplates = {
+  [RecommendationKind.CatchUpBacklog]: {
+    primaryAction: 'Catch up on overdue reviews',
+    secondaryAction: 'Reinforce accuracy with short tactics drills',
   },
-  {
-    backlog: 'low',
-    accuracy: 'critical',
-    recommendation: {
-      primaryAction: 'Stabilize accuracy with quick refresh drills',
-      secondaryAction: 'Tag the weakest lines for focused review',
-    },
+  [RecommendationKind.SplitReviewBlocks]: {
+    primaryAction: "Work through today's reviews in two focused blocks",
+    secondaryAction: 'Log any mistakes immediately to revisit tomorrow',
   },
-  {
-    backlog: 'low',
-    recommendation: {
-      primaryAction: 'Complete the remaining reviews in a single sprint',
-      secondaryAction: "Do a light skim of yesterday's problem areas",
-    },
+  [RecommendationKind.StabilizeAccuracy]: {
+    primaryAction: 'Stabilize accuracy with quick refresh drills',
+    secondaryAction: 'Tag the weakest lines for focused review',
   },
-  {
-    accuracy: 'critical',
-    recommendation: {
-      primaryAction: 'Rebuild confidence on the weakest variations',
-      secondaryAction: 'Schedule a tactics-only session for reinforcement',
-    },
+  [RecommendationKind.FinishInOneSprint]: {
+    primaryAction: 'Complete the remaining reviews in a single sprint',
+    secondaryAction: "Do a light skim of yesterday's problem areas",
   },
-  {
-    accuracy: 'watch',
-    recommendation: {
-      primaryAction: 'Finish the day with one more focused review block',
-      secondaryAction: 'Revisit the last set of inaccuracies to lock them in',
-    },
+  [RecommendationKind.RebuildConfidence]: {
+    primaryAction: 'Rebuild confidence on the weakest variations',
+    secondaryAction: 'Schedule a tactics-only session for reinforcement',
   },
-  {
-    predicate: (context) => context.streakLength >= 10 && context.remaining === 0,
-    recommendation: {
-      primaryAction: 'Add one new line to your repertoire',
-      secondaryAction: 'Review high-value mistakes from the past week',
-    },
+  [RecommendationKind.FinalFocusBlock]: {
+    primaryAction: 'Finish the day with one more focused review block',
+    secondaryAction: 'Revisit the last set of inaccuracies to lock them in',
   },
-];
+  [RecommendationKind.ExpandRepertoire]: {
+    primaryAction: 'Add one new line to your repertoire',
+    secondaryAction: 'Review high-value mistakes from the past week',
+  },
+  [RecommendationKind.KeepMomentum]: {
+    primaryAction: "Plan tomorrow's unlock and keep the momentum",
+    secondaryAction: "Share today's success in your training journal",
+  },
+};
+
+/** Kinds of invalid input that {@link ReviewPlanner.buildOverview} rejects. */
+export type ReviewPlannerErrorKind = 'InvalidUnlockDate';
 
-const RECOMMENDATION_FALLBACK: Recommendation = {
-  primaryAction: "Plan tomorrow's unlock and keep the momentum",
-  secondaryAction: "Share today's success in your training journal",
+export class ReviewPlannerError extends Error {
+  public readonly kind: ReviewPlannerErrorKind;
+
+  constructor(kind: ReviewPlannerErrorKind, message: string) {
+    super(message);
+    this.name = 'ReviewPlannerError';
+    this.kind = kind;
+  }
+}
+
+const ISO_DATE_PATTERN = /^(\d{4})-(\d{2})-(\d{2})$/;
+
+/** Returns the UTC timestamp of an ISO `YYYY-MM-DD` calendar date, or `null` if invalid. */
+const parseUnlockDate = (value: string): number | null => {
+  const match = ISO_DATE_PATTERN.exec(value);
+  if (!match) {
+    return null;
+  }
+
+  const [year, month, day] = match.slice(1).map(Number);
+  const timestamp = Date.UTC(year, month - 1, day);
+  const parsed = new Date(timestamp);
+  const roundTrips =
+    parsed.getUTCFullYear() === year &&
+    parsed.getUTCMonth() === month - 1 &&
+    parsed.getUTCDate() === day;
+
+  return roundTrips ? timestamp : null;
 };
 
 export class ReviewPlanner {
+  private readonly templates: RecommendationTemplates;
+
+  /**
+   * @param templates Localized strings keyed by {@link RecommendationKind}. Kinds that are
+   * not provided fall back to the English defaults.
+   */
+  constructor(templates: Partial<RecommendationTemplates> = {}) {
+    this.templates = { ...DEFAULT_RECOMMENDATION_TEMPLATES, ...templates };
+  }
+
   public buildOverview(snapshot: ReviewSnapshot): ReviewOverview {
     this.assertSnapshot(snapshot);
 
@@ -119,7 +170,7 @@ export class ReviewPlanner {
 
     const backlogPressure = this.assessBacklog(remaining);
     const accuracyRisk = this.assessAccuracy(snapshot.accuracyRate);
-    const recommendation = this.deriveRecommendation({
+    const recommendationKind = this.deriveRecommendation({
       backlog: backlogPressure,
       accuracy: accuracyRisk,
       streakLength: snapshot.streakLength,
@@ -138,11 +189,47 @@ export class ReviewPlanner {
         backlogPressure,
         accuracyRisk,
       },
-      recommendation,
-      upcomingUnlocks: [...snapshot.upcomingUnlocks],
+      recommendation: { ...this.templates[recommendationKind] },
+      upcomingUnlocks: this.sortUnlocks(snapshot.upcomingUnlocks),
     };
   }
 
+  /**
+   * Returns the overview as if `additional` more reviews were completed today. The count is
+   * clamped to the reviews still remaining, so the projection never overshoots the due total.
+   */
+  public projectAfterCompleting(snapshot: ReviewSnapshot, additional: number): ReviewOverview {
+    this.assertSnapshot(snapshot);
+
+    if (!Number.isInteger(additional) || additional < 0) {
+      throw new Error('Additional reviews must be a non-negative integer');
+    }
+
+    const remaining = Math.max(snapshot.dueCards - snapshot.completedCards, 0);
+
+    return this.buildOverview({
+      ...snapshot,
+      completedCards: snapshot.completedCards + Math.min(additional, remaining),
+    });
+  }
+
+  /** Orders unlocks by date, keeping the input order for unlocks on the same day. */
+  private sortUnlocks(unlocks: UpcomingUnlock[]): UpcomingUnlock[] {
+    return unlocks
+      .map((unlock) => {
+        const timestamp = parseUnlockDate(unlock.scheduledFor);
+        if (timestamp === null) {
+          throw new ReviewPlannerError(
+            'InvalidUnlockDate',
+            `Unlock ${unlock.id} has an invalid date: ${unlock.scheduledFor}`,
+          );
+        }
+        return { unlock, timestamp };
+      })
+      .sort((left, right) => left.timestamp - right.timestamp)
+      .map(({ unlock }) => unlock);
+  }
+
   private assertSnapshot(snapshot: ReviewSnapshot): void {
     if (snapshot.dueCards < 0 || snapshot.completedCards < 0) {
       throw new Error('Review counts cannot be negative');
@@ -181,7 +268,8 @@ export class ReviewPlanner {
     return 'critical';
   }
 
-  private deriveRecommendation(input: RecommendationContext): Recommendation {
+  /** Returns the kind of recommendation that applies, so callers can localize it. */
+  public deriveRecommendation(input: RecommendationContext): RecommendationKind {
     const matchingRule = RECOMMENDATION_RULES.find((rule) => {
       if (rule.backlog && rule.backlog !== input.backlog) {
         return false;
@@ -198,6 +286,6 @@ export class ReviewPlanner {
       return true;
     });
 
-    return matchingRule?.recommendation ?? RECOMMENDATION_FALLBACK;
+    return matchingRule?.kind ?? RECOMMENDATION_FALLBACK;
   }
 }
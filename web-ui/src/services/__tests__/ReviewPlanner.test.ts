@@ -1,6 +1,12 @@
 import { describe, expect, it } from 'vitest';
 
-import { ReviewPlanner, type ReviewSnapshot } from '../ReviewPlanner';
+import {
+  DEFAULT_RECOMMENDATION_TEMPLATES,
+  RecommendationKind,
+  ReviewPlanner,
+  ReviewPlannerError,
+  type ReviewSnapshot,
+} from '../ReviewPlanner';
 
 describe('ReviewPlanner', () => {
   const baseSnapshot: ReviewSnapshot = {
@@ -187,6 +193,80 @@ describe('ReviewPlanner', () => {
     );
   });
 
+  it('exposes the recommendation kind for localized lookups', () => {
+    const planner = new ReviewPlanner();
+
+    expect(
+      planner.deriveRecommendation({
+        backlog: 'high',
+        accuracy: 'critical',
+        streakLength: 3,
+        remaining: 15,
+      }),
+    ).toBe(RecommendationKind.CatchUpBacklog);
+    expect(
+      planner.deriveRecommendation({
+        backlog: 'cleared',
+        accuracy: 'stable',
+        streakLength: 4,
+        remaining: 0,
+      }),
+    ).toBe(RecommendationKind.KeepMomentum);
+  });
+
+  it('uses provided templates and falls back to English for missing kinds', () => {
+    const planner = new ReviewPlanner({
+      [RecommendationKind.CatchUpBacklog]: {
+        primaryAction: 'Rattrapez les révisions en retard',
+        secondaryAction: 'Renforcez la précision avec de courts exercices tactiques',
+      },
+    });
+
+    const localized = planner.buildOverview(baseSnapshot);
+    const fallback = planner.buildOverview({
+      ...baseSnapshot,
+      dueCards: 5,
+      completedCards: 5,
+      accuracyRate: 0.95,
+      streakLength: 4,
+    });
+
+    expect(localized.recommendation.primaryAction).toBe('Rattrapez les révisions en retard');
+    expect(fallback.recommendation).toEqual(
+      DEFAULT_RECOMMENDATION_TEMPLATES[RecommendationKind.KeepMomentum],
+    );
+  });
+
+  it('orders upcoming unlocks by date without mutating the snapshot', () => {
+    const planner = new ReviewPlanner();
+    const unlocks = [
+      { id: 'late', move: 'c4', idea: 'English', scheduledFor: '2024-02-01' },
+      { id: 'early', move: 'd4', idea: 'Queen pawn', scheduledFor: '2024-01-09' },
+      { id: 'tie', move: 'Nf3', idea: 'Reti', scheduledFor: '2024-02-01' },
+    ];
+
+    const overview = planner.buildOverview({ ...baseSnapshot, upcomingUnlocks: unlocks });
+
+    expect(overview.upcomingUnlocks.map((unlock) => unlock.id)).toEqual(['early', 'late', 'tie']);
+    expect(overview.upcomingUnlocks[0].scheduledFor).toBe('2024-01-09');
+    expect(unlocks.map((unlock) => unlock.id)).toEqual(['late', 'early', 'tie']);
+  });
+
+  it('rejects unlocks whose date is not an ISO calendar date', () => {
+    const planner = new ReviewPlanner();
+
+    for (const scheduledFor of ['2024-02-30', 'next week', '2024-1-5']) {
+      const build = () =>
+        planner.buildOverview({
+          ...baseSnapshot,
+          upcomingUnlocks: [{ id: 'bad', move: 'e4', idea: 'Center', scheduledFor }],
+        });
+
+      expect(build).toThrowError(ReviewPlannerError);
+      expect(build).toThrowError(`Unlock bad has an invalid date: ${scheduledFor}`);
+    }
+  });
+
   it('raises when provided with invalid snapshot data', () => {
     const planner = new ReviewPlanner();
 
@@ -204,4 +284,47 @@ describe('ReviewPlanner', () => {
       }),
     ).toThrowError('Accuracy must be between 0 and 1');
   });
+
+  it('projects the overview after completing more reviews', () => {
+    const planner = new ReviewPlanner();
+
+    const overview = planner.projectAfterCompleting(baseSnapshot, 5);
+
+    expect(overview.progress.completedToday).toBe(10);
+    expect(overview.progress.remaining).toBe(10);
+    expect(overview.progress.completionRate).toBeCloseTo(0.5);
+    expect(overview.tension.backlogPressure).toBe('moderate');
+    expect(overview.recommendation).toEqual(
+      DEFAULT_RECOMMENDATION_TEMPLATES[RecommendationKind.SplitReviewBlocks],
+    );
+    expect(baseSnapshot.completedCards).toBe(5);
+  });
+
+  it('clamps projected reviews to the remaining backlog', () => {
+    const planner = new ReviewPlanner();
+
+    const overview = planner.projectAfterCompleting(baseSnapshot, 100);
+
+    expect(overview.progress.completedToday).toBe(20);
+    expect(overview.progress.remaining).toBe(0);
+    expect(overview.progress.completionRate).toBe(1);
+    expect(overview.tension.backlogPressure).toBe('cleared');
+    expect(planner.projectAfterCompleting(baseSnapshot, 0)).toEqual(
+      planner.buildOverview(baseSnapshot),
+    );
+  });
+
+  it('rejects invalid projection inputs', () => {
+    const planner = new ReviewPlanner();
+
+    expect(() => planner.projectAfterCompleting(baseSnapshot, -1)).toThrowError(
+      'Additional reviews must be a non-negative integer',
+    );
+    expect(() => planner.projectAfterCompleting(baseSnapshot, 1.5)).toThrowError(
+      'Additional reviews must be a non-negative integer',
+    );
+    expect(() =>
+      planner.projectAfterCompleting({ ...baseSnapshot, accuracyRate: 1.2 }, 1),
+    ).toThrowError('Accuracy must be between 0 and 1');
+  });
 });